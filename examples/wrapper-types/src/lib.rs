@@ -29,16 +29,34 @@ impl UniffiCustomTypeWrapper for Handle {
     }
 }
 
+// A `Url` is passed as a string too, but `uniffi.toml` tells the bindings to turn that
+// string into the foreign language's URL type.
+pub type Url = url::Url;
+
+impl UniffiCustomTypeWrapper for Url {
+    type Wrapped = String;
+
+    fn wrap(val: Self::Wrapped) -> uniffi::Result<Self> {
+        Ok(Url::parse(&val)?)
+    }
+
+    fn unwrap(obj: Self) -> Self::Wrapped {
+        obj.into()
+    }
+}
+
 // And a little struct and function that ties them together.
 pub struct WrappedTypesDemo {
     json: serde_json::Value,
     handle: Handle,
+    url: Url,
 }
 
 pub fn get_wrapped_types_demo(v: Option<WrappedTypesDemo>) -> WrappedTypesDemo {
     v.unwrap_or_else(|| WrappedTypesDemo {
         json: serde_json::json!({"demo": "string"}),
         handle: Handle(123),
+        url: Url::parse("http://example.com/").unwrap(),
     })
 }
 
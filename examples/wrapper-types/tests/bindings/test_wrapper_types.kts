@@ -0,0 +1,17 @@
+import java.net.URL
+import uniffi.wrapper_types.*
+
+val demo = getWrappedTypesDemo(null)
+assert(demo.json == """{"demo":"string"}""")
+assert(demo.handle == 123L)
+
+// `uniffi.toml` turns the `Url` into a `java.net.URL`.
+assert(demo.url.toString() == "http://example.com/")
+assert(demo.url.host == "example.com")
+
+// Wrapped types are aliases for their primitive type, or for the configured type.
+val json: JsonObject = """{"foo":"bar"}"""
+val handle: Handle = 456L
+val url: Url = URL("https://mozilla.org/")
+val demo2 = WrappedTypesDemo(json, handle, url)
+assert(getWrappedTypesDemo(demo2) == demo2)
@@ -1,10 +1,15 @@
+import urllib.parse
 from wrapper_types import *
 
 val = get_wrapped_types_demo(None)
 assert(val.json == '{"demo":"string"}')
 assert(val.handle == 123)
 
-val = WrappedTypesDemo('{"foo":"bar"}', 456)
+# `uniffi.toml` turns the `Url` into the result of `urllib.parse.urlparse()`.
+assert(val.url.geturl() == "http://example.com/")
+assert(val.url.hostname == "example.com")
+
+val = WrappedTypesDemo('{"foo":"bar"}', 456, urllib.parse.urlparse("https://mozilla.org/"))
 val2 = get_wrapped_types_demo(val)
 print(val2)
 assert(val == val2)
@@ -0,0 +1,17 @@
+import Foundation
+import wrapper_types
+
+let demo = getWrappedTypesDemo(demo: nil)
+assert(demo.json == "{\"demo\":\"string\"}")
+assert(demo.handle == 123)
+
+// `uniffi.toml` turns the `Url` into a `URL`.
+assert(demo.url == URL(string: "http://example.com/")!)
+assert(demo.url.host == "example.com")
+
+// Wrapped types are aliases for their primitive type, or for the configured type.
+let json: JsonObject = "{\"foo\":\"bar\"}"
+let handle: Handle = 456
+let url: Url = URL(string: "https://mozilla.org/")!
+let demo2 = WrappedTypesDemo(json: json, handle: handle, url: url)
+assert(getWrappedTypesDemo(demo: demo2) == demo2)
@@ -72,4 +72,63 @@ impl RustStringifier {
     }
 }
 
+#[derive(Debug, thiserror::Error)]
+pub enum SimpleError {
+    #[error("Bad argument")]
+    BadArgument,
+    #[error("Unexpected error")]
+    UnexpectedError,
+}
+
+// Unexpected errors from the foreign language are mapped onto a dedicated variant.
+impl From<uniffi::UnexpectedUniFFICallbackError> for SimpleError {
+    fn from(_: uniffi::UnexpectedUniFFICallbackError) -> SimpleError {
+        SimpleError::UnexpectedError
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ComplexError {
+    #[error("Really bad argument: {code}")]
+    ReallyBadArgument { code: i32 },
+    #[error("Unexpected error: {reason}")]
+    UnexpectedErrorWithReason { reason: String },
+}
+
+impl From<uniffi::UnexpectedUniFFICallbackError> for ComplexError {
+    fn from(e: uniffi::UnexpectedUniFFICallbackError) -> ComplexError {
+        ComplexError::UnexpectedErrorWithReason { reason: e.reason }
+    }
+}
+
+trait ForeignValidator {
+    fn validate_string(&self, value: String) -> Result<String, SimpleError>;
+    fn validate_code(&self, code: i32) -> Result<i32, ComplexError>;
+}
+
+#[derive(Debug, Default)]
+pub struct RustValidator;
+
+impl RustValidator {
+    fn new() -> Self {
+        RustValidator
+    }
+
+    fn validate_string(
+        &self,
+        callback: Box<dyn ForeignValidator>,
+        value: String,
+    ) -> Result<String, SimpleError> {
+        callback.validate_string(value)
+    }
+
+    fn validate_code(
+        &self,
+        callback: Box<dyn ForeignValidator>,
+        code: i32,
+    ) -> Result<i32, ComplexError> {
+        callback.validate_code(code)
+    }
+}
+
 include!(concat!(env!("OUT_DIR"), "/callbacks.uniffi.rs"));
@@ -71,3 +71,55 @@ listOf(1, 2).forEach { v ->
     assert(expected == observed) { "callback is sent on construction: $expected != $observed" }
 }
 rustStringifier.destroy()
+
+// 3. Callback methods can throw the errors they declare, which are passed back to Rust.
+// Anything else they throw is reported as an unexpected error.
+
+class KotlinValidator: ForeignValidator {
+    override fun validateString(value: String): String =
+        when (value) {
+            "bad" -> throw SimpleException.BadArgument()
+            "unexpected" -> throw RuntimeException("something unexpected")
+            else -> value
+        }
+
+    override fun validateCode(code: Int): Int =
+        when {
+            code < 0 -> throw ComplexException.ReallyBadArgument(code)
+            code == 0 -> throw IllegalStateException("zero")
+            else -> code
+        }
+}
+
+val rustValidator = RustValidator()
+val kotlinValidator = KotlinValidator()
+
+assert(rustValidator.validateString(kotlinValidator, "good") == "good")
+try {
+    rustValidator.validateString(kotlinValidator, "bad")
+    throw RuntimeException("Should have thrown a SimpleException.BadArgument exception!")
+} catch (e: SimpleException.BadArgument) {
+    // It's okay!
+}
+try {
+    rustValidator.validateString(kotlinValidator, "unexpected")
+    throw RuntimeException("Should have thrown a SimpleException.UnexpectedException exception!")
+} catch (e: SimpleException.UnexpectedException) {
+    // It's okay!
+}
+
+assert(rustValidator.validateCode(kotlinValidator, 42) == 42)
+try {
+    rustValidator.validateCode(kotlinValidator, -1)
+    throw RuntimeException("Should have thrown a ComplexException.ReallyBadArgument exception!")
+} catch (e: ComplexException.ReallyBadArgument) {
+    assert(e.code == -1)
+}
+try {
+    rustValidator.validateCode(kotlinValidator, 0)
+    throw RuntimeException("Should have thrown a ComplexException.UnexpectedErrorWithReason exception!")
+} catch (e: ComplexException.UnexpectedErrorWithReason) {
+    assert(e.reason.contains("zero")) { "unexpected reason: ${e.reason}" }
+}
+
+rustValidator.destroy()
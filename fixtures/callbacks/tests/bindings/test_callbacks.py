@@ -0,0 +1,113 @@
+# This Source Code Form is subject to the terms of the Mozilla Public
+# License, v. 2.0. If a copy of the MPL was not distributed with this
+# file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+from callbacks import *
+
+# 1. Pass in the callback as arguments.
+# Make the callback methods use multiple aruments, with a variety of types, and
+# with a variety of return types.
+rust_getters = RustGetters()
+
+class PythonGetters(ForeignGetters):
+    def get_bool(self, v, arg2):
+        return v ^ arg2
+
+    def get_string(self, v, arg2):
+        return "1234567890123" if arg2 else v
+
+    def get_option(self, v, arg2):
+        return v.upper() if arg2 and v is not None else v
+
+    def get_list(self, v, arg2):
+        return v if arg2 else []
+
+callback = PythonGetters()
+
+for v in [True, False]:
+    expected = callback.get_bool(v, True)
+    observed = rust_getters.get_bool(callback, v, True)
+    assert expected == observed, f"roundtripping through callback: {expected} != {observed}"
+
+for v in [[1, 2], [0, 1]]:
+    expected = callback.get_list(v, True)
+    observed = rust_getters.get_list(callback, v, True)
+    assert expected == observed, f"roundtripping through callback: {expected} != {observed}"
+
+for v in ["Hello", "world"]:
+    expected = callback.get_string(v, True)
+    observed = rust_getters.get_string(callback, v, True)
+    assert expected == observed, f"roundtripping through callback: {expected} != {observed}"
+
+for v in ["Some", None]:
+    expected = callback.get_option(v, False)
+    observed = rust_getters.get_option(callback, v, False)
+    assert expected == observed, f"roundtripping through callback: {expected} != {observed}"
+
+assert rust_getters.get_string_optional_callback(callback, "TestString", False) == "TestString"
+assert rust_getters.get_string_optional_callback(None, "TestString", False) is None
+
+# 2. Pass the callback in as a constructor argument, to be stored on the Object struct.
+# This is crucial if we want to configure a system at startup,
+# then use it without passing callbacks all the time.
+
+class StoredPythonStringifier(StoredForeignStringifier):
+    def from_simple_type(self, value):
+        return f"python: {value}"
+
+    def from_complex_type(self, values):
+        return f"python: {values}"
+
+python_stringifier = StoredPythonStringifier()
+rust_stringifier = RustStringifier(python_stringifier)
+for v in [1, 2]:
+    expected = python_stringifier.from_simple_type(v)
+    observed = rust_stringifier.from_simple_type(v)
+    assert expected == observed, f"callback is sent on construction: {expected} != {observed}"
+
+# 3. Callback methods can raise the errors they declare, which are passed back to Rust.
+# Anything else they raise is reported as an unexpected error.
+
+class PythonValidator(ForeignValidator):
+    def validate_string(self, value):
+        if value == "bad":
+            raise SimpleError.BadArgument()
+        if value == "unexpected":
+            raise RuntimeError("something unexpected")
+        return value
+
+    def validate_code(self, code):
+        if code < 0:
+            raise ComplexError.ReallyBadArgument(code)
+        if code == 0:
+            raise ValueError("zero")
+        return code
+
+rust_validator = RustValidator()
+python_validator = PythonValidator()
+
+assert rust_validator.validate_string(python_validator, "good") == "good"
+try:
+    rust_validator.validate_string(python_validator, "bad")
+    raise AssertionError("Should have raised SimpleError.BadArgument")
+except SimpleError.BadArgument:
+    pass
+
+try:
+    rust_validator.validate_string(python_validator, "unexpected")
+    raise AssertionError("Should have raised SimpleError.UnexpectedError")
+except SimpleError.UnexpectedError:
+    pass
+
+assert rust_validator.validate_code(python_validator, 42) == 42
+try:
+    rust_validator.validate_code(python_validator, -1)
+    raise AssertionError("Should have raised ComplexError.ReallyBadArgument")
+except ComplexError.ReallyBadArgument as e:
+    assert e.code == -1
+
+try:
+    rust_validator.validate_code(python_validator, 0)
+    raise AssertionError("Should have raised ComplexError.UnexpectedErrorWithReason")
+except ComplexError.UnexpectedErrorWithReason as e:
+    assert "zero" in e.reason, f"unexpected reason: {e.reason}"
@@ -0,0 +1,118 @@
+# frozen_string_literal: true
+
+require 'test/unit'
+require 'callbacks'
+
+include Test::Unit::Assertions
+include Callbacks
+
+# 1. Pass in the callback as arguments.
+# Make the callback methods use multiple aruments, with a variety of types, and
+# with a variety of return types.
+class RubyGetters
+  include ForeignGetters
+
+  def get_bool(v, arg2)
+    v ^ arg2
+  end
+
+  def get_string(v, arg2)
+    arg2 ? '1234567890123' : v
+  end
+
+  def get_option(v, arg2)
+    arg2 && !v.nil? ? v.upcase : v
+  end
+
+  def get_list(v, arg2)
+    arg2 ? v : []
+  end
+end
+
+rust_getters = RustGetters.new
+callback = RubyGetters.new
+
+[true, false].each do |v|
+  assert_equal callback.get_bool(v, true), rust_getters.get_bool(callback, v, true)
+end
+
+[[1, 2], [0, 1]].each do |v|
+  assert_equal callback.get_list(v, true), rust_getters.get_list(callback, v, true)
+end
+
+%w[Hello world].each do |v|
+  assert_equal callback.get_string(v, true), rust_getters.get_string(callback, v, true)
+end
+
+['Some', nil].each do |v|
+  assert_equal callback.get_option(v, false), rust_getters.get_option(callback, v, false)
+end
+
+assert_equal 'TestString', rust_getters.get_string_optional_callback(callback, 'TestString', false)
+assert_nil rust_getters.get_string_optional_callback(nil, 'TestString', false)
+
+# 2. Pass the callback in as a constructor argument, to be stored on the Object struct.
+# This is crucial if we want to configure a system at startup,
+# then use it without passing callbacks all the time.
+class StoredRubyStringifier
+  include StoredForeignStringifier
+
+  def from_simple_type(value)
+    "ruby: #{value}"
+  end
+
+  def from_complex_type(values)
+    "ruby: #{values}"
+  end
+end
+
+ruby_stringifier = StoredRubyStringifier.new
+rust_stringifier = RustStringifier.new ruby_stringifier
+[1, 2].each do |v|
+  assert_equal ruby_stringifier.from_simple_type(v), rust_stringifier.from_simple_type(v)
+end
+
+# 3. Callback methods can raise the errors they declare, which are passed back to Rust.
+# Anything else they raise is reported as an unexpected error.
+class RubyValidator
+  include ForeignValidator
+
+  def validate_string(value)
+    raise SimpleError::BadArgument if value == 'bad'
+    raise 'something unexpected' if value == 'unexpected'
+
+    value
+  end
+
+  def validate_code(code)
+    raise ComplexError::ReallyBadArgument.new(code) if code.negative?
+    raise ArgumentError, 'zero' if code.zero?
+
+    code
+  end
+end
+
+rust_validator = RustValidator.new
+ruby_validator = RubyValidator.new
+
+assert_equal 'good', rust_validator.validate_string(ruby_validator, 'good')
+
+assert_raise SimpleError::BadArgument do
+  rust_validator.validate_string(ruby_validator, 'bad')
+end
+
+assert_raise SimpleError::UnexpectedError do
+  rust_validator.validate_string(ruby_validator, 'unexpected')
+end
+
+assert_equal 42, rust_validator.validate_code(ruby_validator, 42)
+
+err = assert_raise ComplexError::ReallyBadArgument do
+  rust_validator.validate_code(ruby_validator, -1)
+end
+assert_equal(-1, err.code)
+
+err = assert_raise ComplexError::UnexpectedErrorWithReason do
+  rust_validator.validate_code(ruby_validator, 0)
+end
+assert_match(/zero/, err.reason)
@@ -1,4 +1,8 @@
 uniffi_macros::build_foreign_language_testcases!(
     "src/callbacks.udl",
-    ["tests/bindings/test_callbacks.kts"]
+    [
+        "tests/bindings/test_callbacks.kts",
+        "tests/bindings/test_callbacks.py",
+        "tests/bindings/test_callbacks.rb",
+    ]
 );
@@ -2,6 +2,9 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt;
+use std::hash::{Hash, Hasher};
 use std::sync::atomic::{AtomicBool, AtomicI32, Ordering};
 use std::sync::{Arc, Mutex, RwLock};
 
@@ -33,6 +36,12 @@ impl From<InternalCoverallError> for CoverallError {
     }
 }
 
+#[derive(Debug, thiserror::Error)]
+pub enum CoverallFlatError {
+    #[error("Too many variants: {num}")]
+    TooManyVariants { num: i16 },
+}
+
 #[derive(Debug, thiserror::Error)]
 pub enum ComplexError {
     #[error("OsError: {code} ({extended_code})")]
@@ -70,6 +79,35 @@ pub enum MaybeSimpleDict {
     Nah,
 }
 
+// Recursive types need to box their references to themselves.
+#[derive(Debug, Clone)]
+pub enum Expr {
+    Num { n: i64 },
+    Add { left: Box<Expr>, right: Box<Expr> },
+    Neg { inner: Box<Expr> },
+}
+
+#[derive(Debug, Clone)]
+pub struct ListNode {
+    value: u32,
+    tail: Option<Box<ListNode>>,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Priority {
+    Low = 10,
+    Medium,
+    High = 20,
+}
+
+bitflags::bitflags! {
+    pub struct Permissions: u8 {
+        const READ = 1;
+        const WRITE = 2;
+        const EXECUTE = 4;
+    }
+}
+
 fn create_some_dict() -> SimpleDict {
     SimpleDict {
         text: "text".to_string(),
@@ -118,10 +156,128 @@ fn create_none_dict() -> SimpleDict {
     }
 }
 
+const MAX_HOLES: u32 = 500;
+const MIN_TEMPERATURE: i16 = -16;
+const STRETCHINESS: f64 = 0.25;
+const WATERPROOF: bool = false;
+const CARE_INSTRUCTIONS: &str = "Machine wash {cold}; line dry";
+
 fn get_num_alive() -> u64 {
     *NUM_ALIVE.read().unwrap()
 }
 
+fn num_alive() -> u64 {
+    get_num_alive()
+}
+
+fn throw_flat_error() -> std::result::Result<(), CoverallFlatError> {
+    Err(CoverallFlatError::TooManyVariants { num: 99 })
+}
+
+fn count_colors(colors: Vec<Color>) -> HashMap<Color, u32> {
+    let mut counts = HashMap::new();
+    for color in colors {
+        *counts.entry(color).or_default() += 1;
+    }
+    counts
+}
+
+fn invert_names(names: HashMap<u64, String>) -> HashMap<String, u64> {
+    names.into_iter().map(|(k, v)| (v, k)).collect()
+}
+
+fn split_bytes(input: Vec<u8>, separator: u8) -> Vec<Vec<u8>> {
+    input
+        .split(|b| *b == separator)
+        .map(<[u8]>::to_vec)
+        .collect()
+}
+
+fn join_bytes(parts: Vec<Vec<u8>>, separator: u8) -> Vec<u8> {
+    parts.join(&separator)
+}
+
+fn widening_mul(a: u64, b: u64) -> u128 {
+    u128::from(a) * u128::from(b)
+}
+
+fn negate_i128(value: i128) -> i128 {
+    value.wrapping_neg()
+}
+
+fn min_max(values: Vec<u32>) -> Option<(u32, u32)> {
+    Some((*values.iter().min()?, *values.iter().max()?))
+}
+
+fn enumerate_colors(colors: Vec<Color>) -> Vec<(u32, Color)> {
+    (0..).zip(colors).collect()
+}
+
+fn set_union(a: HashSet<u32>, b: HashSet<u32>) -> HashSet<u32> {
+    a.union(&b).copied().collect()
+}
+
+fn count_words(words: Vec<String>) -> BTreeMap<String, u32> {
+    let mut counts = BTreeMap::new();
+    for word in words {
+        *counts.entry(word).or_default() += 1;
+    }
+    counts
+}
+
+fn evaluate(expr: Expr) -> i64 {
+    match expr {
+        Expr::Num { n } => n,
+        Expr::Add { left, right } => evaluate(*left) + evaluate(*right),
+        Expr::Neg { inner } => -evaluate(*inner),
+    }
+}
+
+fn negate_expr(expr: Expr) -> Expr {
+    Expr::Neg {
+        inner: Box::new(expr),
+    }
+}
+
+fn list_from(values: Vec<u32>) -> Option<ListNode> {
+    values.into_iter().rev().fold(None, |tail, value| {
+        Some(ListNode {
+            value,
+            tail: tail.map(Box::new),
+        })
+    })
+}
+
+fn list_values(mut head: Option<ListNode>) -> Vec<u32> {
+    let mut values = Vec::new();
+    while let Some(node) = head {
+        values.push(node.value);
+        head = node.tail.map(|tail| *tail);
+    }
+    values
+}
+
+fn raise_priority(priority: Priority) -> Priority {
+    match priority {
+        Priority::Low => Priority::Medium,
+        Priority::Medium | Priority::High => Priority::High,
+    }
+}
+
+fn combine_permissions(permissions: Vec<Permissions>) -> Permissions {
+    permissions
+        .into_iter()
+        .fold(Permissions::empty(), |all, p| all | p)
+}
+
+fn frequent_words(counts: BTreeMap<String, u32>, min_count: u32) -> HashSet<String> {
+    counts
+        .into_iter()
+        .filter(|(_, count)| *count >= min_count)
+        .map(|(word, _)| word)
+        .collect()
+}
+
 type Result<T, E = CoverallError> = std::result::Result<T, E>;
 type ComplexResult<T, E = ComplexError> = std::result::Result<T, E>;
 
@@ -142,6 +298,10 @@ impl Coveralls {
         }
     }
 
+    fn is_valid_name(name: String) -> bool {
+        !name.is_empty()
+    }
+
     fn fallible_new(name: String, should_fail: bool) -> Result<Self> {
         if should_fail {
             Err(CoverallError::TooManyHoles)
@@ -158,6 +318,10 @@ impl Coveralls {
         self.name.clone()
     }
 
+    fn name(&self) -> String {
+        self.get_name()
+    }
+
     fn panicing_new(message: String) -> Self {
         panic!("{}", message);
     }
@@ -234,26 +398,69 @@ impl Drop for Coveralls {
         *NUM_ALIVE.write().unwrap() -= 1;
     }
 }
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Color {
     Red,
     Blue,
     Green,
 }
 
-#[derive(Debug)]
 struct Patch {
     color: Color,
+    width: Mutex<u16>,
+}
+
+impl fmt::Debug for Patch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Patch")
+            .field("color", &self.color)
+            .field("width", &self.width())
+            .finish()
+    }
+}
+
+impl fmt::Display for Patch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?} patch, {}mm wide", self.color, self.width())
+    }
+}
+
+impl PartialEq for Patch {
+    fn eq(&self, other: &Self) -> bool {
+        self.color == other.color && self.width() == other.width()
+    }
+}
+
+// Equal patches have the same color, so they get the same hash.
+impl Hash for Patch {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.color.hash(state);
+    }
 }
 
 impl Patch {
     fn new(color: Color) -> Self {
-        Self { color }
+        Self {
+            color,
+            width: Mutex::new(10),
+        }
     }
 
     fn get_color(&self) -> Color {
         self.color
     }
+
+    fn description(&self) -> String {
+        format!("A {:?} patch", self.color)
+    }
+
+    fn width(&self) -> u16 {
+        *self.width.lock().unwrap()
+    }
+
+    fn set_width(&self, width: u16) {
+        *self.width.lock().unwrap() = width;
+    }
 }
 
 // This is a small implementation of a counter that allows waiting on one thread,
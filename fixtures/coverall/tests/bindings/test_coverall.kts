@@ -8,6 +8,122 @@ import uniffi.coverall.*
 
 // TODO: use an actual test runner.
 
+// Deprecated functions and methods still work, but warn when compiled.
+assert(numAlive() == getNumAlive())
+Coveralls("test_deprecated").use { coveralls ->
+    assert(coveralls.name() == "test_deprecated")
+}
+
+// Maps can have keys of any type.
+assert(countColors(listOf(Color.RED, Color.BLUE, Color.RED)) == mapOf(Color.RED to 2.toUInt(), Color.BLUE to 1.toUInt()))
+assert(invertNames(mapOf(1.toULong() to "one", 2.toULong() to "two")) == mapOf("one" to 1.toULong(), "two" to 2.toULong()))
+
+// Bytes are passed as a `ByteArray`.
+splitBytes(byteArrayOf(1, 0, -1, 0), 0.toUByte()).let { parts ->
+    assert(parts.size == 3)
+    assert(parts[0].contentEquals(byteArrayOf(1)))
+    assert(parts[1].contentEquals(byteArrayOf(-1)))
+    assert(parts[2].isEmpty())
+}
+assert(joinBytes(listOf(byteArrayOf(1), byteArrayOf(), byteArrayOf(-1)), 0.toUByte()).contentEquals(byteArrayOf(1, 0, 0, -1)))
+
+// Tuples are passed as a `Pair` or `Triple`.
+assert(minMax(listOf(3u, 1u, 2u)) == Pair(1u, 3u))
+assert(minMax(listOf()) == null)
+assert(enumerateColors(listOf(Color.RED, Color.BLUE)) == listOf(Pair(0u, Color.RED), Pair(1u, Color.BLUE)))
+
+// Sets are passed as a `Set`, and ordered maps as a `SortedMap`.
+assert(setUnion(setOf(1u, 2u), setOf(2u, 3u)) == setOf(1u, 2u, 3u))
+val counts = countWords(listOf("b", "c", "a", "b"))
+assert(counts == mapOf("a" to 1u, "b" to 2u, "c" to 1u))
+assert(counts.keys.toList() == listOf("a", "b", "c"))
+assert(frequentWords(sortedMapOf("b" to 2u, "a" to 3u, "c" to 1u), 2u) == setOf("a", "b"))
+
+// Recursive records and enums refer to themselves directly.
+val expr = Expr.Add(Expr.Num(2L), Expr.Neg(Expr.Num(5L)))
+assert(evaluate(expr) == -3L)
+assert(negateExpr(expr) == Expr.Neg(expr))
+val head = listFrom(listOf(1u, 2u, 3u))!!
+assert(head.value == 1u && head.tail!!.tail!!.value == 3u && head.tail!!.tail!!.tail == null)
+assert(listValues(head) == listOf(1u, 2u, 3u))
+assert(listValues(ListNode(4u, ListNode(5u, null))) == listOf(4u, 5u))
+assert(listFrom(listOf()) == null)
+
+// Enum variants have the value they're passed to Rust as, and non-exhaustive enums have an `UNKNOWN` variant.
+assert(Priority.LOW.value == 10 && Priority.MEDIUM.value == 11 && Priority.HIGH.value == 20)
+assert(raisePriority(Priority.LOW) == Priority.MEDIUM)
+assert(raisePriority(Priority.MEDIUM) == Priority.HIGH)
+assert(Priority.UNKNOWN.value == null)
+try {
+    raisePriority(Priority.UNKNOWN)
+    throw RuntimeException("Should have thrown an IllegalArgumentException")
+} catch (e: IllegalArgumentException) {
+    // It's expected that we end up here.
+}
+
+// Flags are combined with `or`, and checked with `in`.
+assert(Permissions.EXECUTE.bits == 4.toUByte())
+assert(combinePermissions(listOf()) == Permissions(0u))
+val readWrite = combinePermissions(listOf(Permissions.READ, Permissions.WRITE, Permissions.READ))
+assert(readWrite == (Permissions.READ or Permissions.WRITE))
+assert(Permissions.WRITE in readWrite && Permissions.EXECUTE !in readWrite)
+assert((readWrite and Permissions.WRITE) == Permissions.WRITE)
+try {
+    // Rust rejects bits that aren't one of the flags.
+    combinePermissions(listOf(Permissions(8u)))
+    throw RuntimeException("Should have thrown an InternalException")
+} catch (e: InternalException) {
+    // It's expected that we end up here.
+}
+
+// 128-bit integers are passed as a `java.math.BigInteger`.
+val big = java.math.BigInteger.ONE.shiftLeft(100)
+assert(wideningMul(ULong.MAX_VALUE, ULong.MAX_VALUE) == java.math.BigInteger("340282366920938463426481119284349108225"))
+assert(negateI128(big) == big.negate())
+assert(negateI128(java.math.BigInteger.ONE.negate()) == java.math.BigInteger.ONE)
+try {
+    negateI128(java.math.BigInteger.ONE.shiftLeft(127))
+    throw RuntimeException("Should have thrown an IllegalArgumentException")
+} catch (e: IllegalArgumentException) {
+    // It's okay!
+}
+
+// Static methods are called on the class.
+assert(Coveralls.isValidName("test_static"))
+assert(!Coveralls.isValidName(""))
+
+// Objects can expose their Rust std trait impls.
+Patch(Color.RED).use { patch ->
+    Patch(Color.RED).use { other ->
+        assert(patch.toString() == "Red patch, 10mm wide")
+        assert(patch == other)
+        assert(patch.hashCode() == other.hashCode())
+        assert(setOf(patch, other).size == 1)
+        other.width = 20.toUShort()
+        assert(patch != other)
+    }
+    Patch(Color.BLUE).use { other ->
+        assert(patch != other)
+    }
+}
+
+// Interface attributes are properties.
+Patch(Color.RED).use { patch ->
+    assert(patch.description == "A Red patch")
+    assert(patch.width == 10.toUShort())
+    patch.width = 25.toUShort()
+    assert(patch.width == 25.toUShort())
+}
+
+// Test constants.
+val maxHoles: UInt = MAX_HOLES
+assert(maxHoles == 500u)
+val minTemperature: Short = MIN_TEMPERATURE
+assert(minTemperature == (-16).toShort())
+assert(STRETCHINESS == 0.25)
+assert(!WATERPROOF)
+assert(CARE_INSTRUCTIONS == "Machine wash {cold}; line dry")
+
 // Test some_dict().
 // N.B. we need to `use` here to clean up the contained `Coveralls` reference.
 createSomeDict().use { d ->
@@ -128,6 +244,14 @@ Coveralls("test_simple_errors").use { coveralls ->
     }
 }
 
+try {
+    throwFlatError()
+    throw RuntimeException("Expected function to throw exception")
+} catch(e: CoverallFlatException.TooManyVariants) {
+    // Expected result
+    assert(e.message == "Too many variants: 99")
+}
+
 Coveralls("test_complex_errors").use { coveralls ->
     assert(coveralls.maybeThrowComplex(0) == true)
 
@@ -98,6 +98,10 @@ class TestCoverall(unittest.TestCase):
         with self.assertRaisesRegex(InternalError, "expected panic: oh no"):
             coveralls.panic("expected panic: oh no")
 
+    def test_flat_errors_with_fields(self):
+        with self.assertRaisesRegex(CoverallFlatError.TooManyVariants, "Too many variants: 99"):
+            throw_flat_error()
+
     def test_complex_errors(self):
         coveralls = Coveralls("test_complex_errors")
 
@@ -173,6 +177,124 @@ class TestCoverall(unittest.TestCase):
         coveralls = None
         self.assertEqual(get_num_alive(), 0)
 
+    def test_constants(self):
+        self.assertEqual(MAX_HOLES, 500)
+        self.assertEqual(MIN_TEMPERATURE, -16)
+        self.assertEqual(STRETCHINESS, 0.25)
+        self.assertFalse(WATERPROOF)
+        self.assertEqual(CARE_INSTRUCTIONS, "Machine wash {cold}; line dry")
+
+    def test_deprecated(self):
+        with self.assertWarns(DeprecationWarning):
+            self.assertEqual(num_alive(), get_num_alive())
+        coveralls = Coveralls("test_deprecated")
+        with self.assertWarns(DeprecationWarning) as cm:
+            self.assertEqual(coveralls.name(), "test_deprecated")
+        self.assertEqual(str(cm.warning), "Use get_name() instead")
+        # The warning points at the caller, rather than the generated code.
+        self.assertEqual(cm.filename, __file__)
+
+    def test_maps(self):
+        self.assertEqual(count_colors([Color.RED, Color.BLUE, Color.RED]), {Color.RED: 2, Color.BLUE: 1})
+        self.assertEqual(count_colors([]), {})
+        self.assertEqual(invert_names({1: "one", 2: "two"}), {"one": 1, "two": 2})
+
+    def test_bytes(self):
+        self.assertEqual(split_bytes(b"\x01\x00\xff\x00", 0), [b"\x01", b"\xff", b""])
+        self.assertEqual(split_bytes(bytearray(b"abc"), 0), [b"abc"])
+        self.assertEqual(join_bytes([b"\x01", b"", b"\xff"], 0), b"\x01\x00\x00\xff")
+        self.assertEqual(join_bytes([], 0), b"")
+
+    def test_tuples(self):
+        self.assertEqual(min_max([3, 1, 2]), (1, 3))
+        self.assertEqual(min_max(iter([4])), (4, 4))
+        self.assertIsNone(min_max([]))
+        self.assertEqual(enumerate_colors([Color.RED, Color.BLUE]), [(0, Color.RED), (1, Color.BLUE)])
+
+    def test_sets_and_ordered_maps(self):
+        self.assertEqual(set_union({1, 2}, [2, 3]), {1, 2, 3})
+        counts = count_words(["b", "c", "a", "b"])
+        self.assertEqual(counts, {"a": 1, "b": 2, "c": 1})
+        # The entries arrive in key order.
+        self.assertEqual(list(counts), ["a", "b", "c"])
+        self.assertEqual(frequent_words({"b": 2, "a": 3, "c": 1}, 2), {"a", "b"})
+
+    def test_recursive_types(self):
+        expr = Expr.ADD(Expr.NUM(2), Expr.NEG(Expr.NUM(5)))
+        self.assertEqual(evaluate(expr), -3)
+        negated = negate_expr(expr)
+        self.assertEqual(negated, Expr.NEG(expr))
+        self.assertEqual(evaluate(negated), 3)
+        head = list_from([1, 2, 3])
+        self.assertEqual(head.value, 1)
+        self.assertEqual(head.tail.tail.value, 3)
+        self.assertIsNone(head.tail.tail.tail)
+        self.assertEqual(list_values(head), [1, 2, 3])
+        self.assertEqual(list_values(ListNode(4, ListNode(5, None))), [4, 5])
+        self.assertIsNone(list_from([]))
+
+    def test_enum_discriminants(self):
+        self.assertEqual(Priority.LOW.value, 10)
+        self.assertEqual(Priority.MEDIUM.value, 11)
+        self.assertEqual(Priority.HIGH.value, 20)
+        self.assertEqual(raise_priority(Priority.LOW), Priority.MEDIUM)
+        self.assertEqual(raise_priority(Priority.MEDIUM), Priority.HIGH)
+        with self.assertRaises(ValueError):
+            raise_priority(Priority.UNKNOWN)
+        # A value from a newer version of the library is read as `UNKNOWN`. The library
+        # can't send one, so we make it by hand.
+        from coverall import RustBuffer
+        with RustBuffer.allocWithBuilder() as builder:
+            builder._pack_into(4, ">i", 15)
+            rbuf = builder.finalize()
+        self.assertEqual(rbuf.consumeIntoTypePriority(), Priority.UNKNOWN)
+
+    def test_flags(self):
+        self.assertEqual(Permissions.EXECUTE.value, 4)
+        self.assertEqual(combine_permissions([]), Permissions(0))
+        read_write = combine_permissions([Permissions.READ, Permissions.WRITE, Permissions.READ])
+        self.assertEqual(read_write, Permissions.READ | Permissions.WRITE)
+        self.assertIn(Permissions.WRITE, read_write)
+        self.assertNotIn(Permissions.EXECUTE, read_write)
+        # Plain integers are converted to flags.
+        self.assertEqual(combine_permissions([read_write, 4]), Permissions(7))
+
+    def test_128_bit_integers(self):
+        self.assertEqual(widening_mul(2**64 - 1, 2**64 - 1), (2**64 - 1) ** 2)
+        self.assertEqual(widening_mul(0, 2**64 - 1), 0)
+        self.assertEqual(negate_i128(2**100), -(2**100))
+        self.assertEqual(negate_i128(-1), 1)
+        self.assertEqual(negate_i128(-(2**127)), -(2**127))
+        with self.assertRaises(ValueError):
+            negate_i128(2**127)
+
+    def test_static_methods(self):
+        self.assertTrue(Coveralls.is_valid_name("test_static_methods"))
+        self.assertFalse(Coveralls.is_valid_name(""))
+
+    def test_properties(self):
+        patch = Patch(Color.RED)
+        self.assertEqual(patch.description, "A Red patch")
+        self.assertEqual(patch.width, 10)
+        patch.width = 25
+        self.assertEqual(patch.width, 25)
+        with self.assertRaises(AttributeError):
+            patch.description = "A blue patch"
+
+    def test_traits(self):
+        patch = Patch(Color.RED)
+        other = Patch(Color.RED)
+        self.assertEqual(str(patch), "Red patch, 10mm wide")
+        self.assertEqual(repr(patch), "Patch { color: Red, width: 10 }")
+        self.assertEqual(patch, other)
+        self.assertEqual(hash(patch), hash(other))
+        self.assertEqual(len({patch, other}), 1)
+        other.width = 20
+        self.assertNotEqual(patch, other)
+        self.assertNotEqual(patch, Patch(Color.BLUE))
+        # Objects of other types are never equal.
+        self.assertNotEqual(patch, "Red patch, 10mm wide")
+
     def test_bad_objects(self):
         coveralls = Coveralls("test_bad_objects")
         patch = Patch(Color.RED)
@@ -4,6 +4,7 @@
 # License, v. 2.0. If a copy of the MPL was not distributed with this
 # file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
+require 'stringio'
 require 'test/unit'
 require 'coverall'
 
@@ -117,6 +118,13 @@ class TestCoverall < Test::Unit::TestCase
     end
   end
 
+  def test_flat_errors_with_fields
+    err = assert_raise Coverall::CoverallFlatError::TooManyVariants do
+      Coverall.throw_flat_error
+    end
+    assert_equal err.message, 'Too many variants: 99'
+  end
+
   def test_complex_errors
     coveralls = Coverall::Coveralls.new 'test_complex_errors'
     assert_equal coveralls.maybe_throw_complex(0), true
@@ -223,6 +231,132 @@ class TestCoverall < Test::Unit::TestCase
     assert_equal Coverall.get_num_alive(), 0
   end
 
+  def test_deprecated
+    coveralls = Coverall::Coveralls.new 'test_deprecated'
+    old_stderr = $stderr
+    $stderr = StringIO.new
+    begin
+      assert_equal Coverall.get_num_alive, Coverall.num_alive
+      assert_equal 'test_deprecated', coveralls.name
+      warnings = $stderr.string
+    ensure
+      $stderr = old_stderr
+    end
+    assert_match(/Deprecated: Use get_num_alive\(\) instead/, warnings)
+    assert_match(/Deprecated: Use get_name\(\) instead/, warnings)
+  end
+
+  def test_constants
+    assert_equal 500, Coverall::MAX_HOLES
+    assert_equal(-16, Coverall::MIN_TEMPERATURE)
+    assert_equal 0.25, Coverall::STRETCHINESS
+    assert_equal false, Coverall::WATERPROOF
+    assert_equal "Machine wash {cold}; line dry", Coverall::CARE_INSTRUCTIONS
+  end
+
+  def test_maps
+    red = Coverall::Color::RED
+    blue = Coverall::Color::BLUE
+    assert_equal({ red => 2, blue => 1 }, Coverall.count_colors([red, blue, red]))
+    assert_equal({}, Coverall.count_colors([]))
+    assert_equal({ 'one' => 1, 'two' => 2 }, Coverall.invert_names({ 1 => 'one', 2 => 'two' }))
+  end
+
+  def test_bytes
+    assert_equal ["\x01".b, "\xff".b, ''.b], Coverall.split_bytes("\x01\x00\xff\x00".b, 0)
+    assert_equal Encoding::BINARY, Coverall.join_bytes([], 0).encoding
+    assert_equal "\x01\x00\x00\xff".b, Coverall.join_bytes(["\x01".b, ''.b, "\xff".b], 0)
+  end
+
+  def test_tuples
+    assert_equal [1, 3], Coverall.min_max([3, 1, 2])
+    assert_nil Coverall.min_max([])
+    assert_equal [[0, Coverall::Color::RED], [1, Coverall::Color::BLUE]],
+                 Coverall.enumerate_colors([Coverall::Color::RED, Coverall::Color::BLUE])
+  end
+
+  def test_sets_and_ordered_maps
+    assert_equal Set[1, 2, 3], Coverall.set_union(Set[1, 2], [2, 3])
+    counts = Coverall.count_words(%w[b c a b])
+    assert_equal({ 'a' => 1, 'b' => 2, 'c' => 1 }, counts)
+    # The entries arrive in key order.
+    assert_equal %w[a b c], counts.keys
+    assert_equal Set['a', 'b'], Coverall.frequent_words({ 'b' => 2, 'a' => 3, 'c' => 1 }, 2)
+  end
+
+  def test_recursive_types
+    expr = Coverall::Expr::ADD.new(Coverall::Expr::NUM.new(2), Coverall::Expr::NEG.new(Coverall::Expr::NUM.new(5)))
+    assert_equal(-3, Coverall.evaluate(expr))
+    negated = Coverall.negate_expr(expr)
+    assert_equal Coverall::Expr::NEG.new(expr), negated
+    assert_equal 3, Coverall.evaluate(negated)
+    head = Coverall.list_from([1, 2, 3])
+    assert_equal 1, head.value
+    assert_equal 3, head.tail.tail.value
+    assert_nil head.tail.tail.tail
+    assert_equal [1, 2, 3], Coverall.list_values(head)
+    assert_equal [4, 5], Coverall.list_values(Coverall::ListNode.new(4, Coverall::ListNode.new(5, nil)))
+    assert_nil Coverall.list_from([])
+  end
+
+  def test_enum_discriminants
+    assert_equal 10, Coverall::Priority::LOW
+    assert_equal 11, Coverall::Priority::MEDIUM
+    assert_equal 20, Coverall::Priority::HIGH
+    assert_equal Coverall::Priority::MEDIUM, Coverall.raise_priority(Coverall::Priority::LOW)
+    assert_equal Coverall::Priority::HIGH, Coverall.raise_priority(Coverall::Priority::MEDIUM)
+    assert_raise ArgumentError do
+      Coverall.raise_priority(Coverall::Priority::UNKNOWN)
+    end
+  end
+
+  def test_flags
+    assert_equal 4, Coverall::Permissions::EXECUTE
+    assert_equal 0, Coverall.combine_permissions([])
+    read_write = Coverall.combine_permissions([Coverall::Permissions::READ, Coverall::Permissions::WRITE])
+    assert_equal Coverall::Permissions::READ | Coverall::Permissions::WRITE, read_write
+    assert_equal 0, read_write & Coverall::Permissions::EXECUTE
+  end
+
+  def test_128_bit_integers
+    assert_equal (2**64 - 1)**2, Coverall.widening_mul(2**64 - 1, 2**64 - 1)
+    assert_equal(-(2**100), Coverall.negate_i128(2**100))
+    assert_equal 1, Coverall.negate_i128(-1)
+    assert_equal(-(2**127), Coverall.negate_i128(-(2**127)))
+    assert_raise RangeError do
+      Coverall.negate_i128(2**127)
+    end
+  end
+
+  def test_static_methods
+    assert Coverall::Coveralls.is_valid_name('test_static_methods')
+    assert !Coverall::Coveralls.is_valid_name('')
+  end
+
+  def test_properties
+    patch = Coverall::Patch.new Coverall::Color::RED
+    assert_equal 'A Red patch', patch.description
+    assert_equal 10, patch.width
+    patch.width = 25
+    assert_equal 25, patch.width
+    assert_raise NoMethodError do
+      patch.description = 'A blue patch'
+    end
+  end
+
+  def test_traits
+    patch = Coverall::Patch.new Coverall::Color::RED
+    other = Coverall::Patch.new Coverall::Color::RED
+    assert_equal 'Red patch, 10mm wide', patch.to_s
+    assert_equal 'Patch { color: Red, width: 10 }', patch.inspect
+    assert_equal patch, other
+    assert_equal patch.hash, other.hash
+    assert_equal 1, [patch, other].uniq.length
+    other.width = 20
+    assert_not_equal patch, other
+    assert_not_equal patch, 'Red patch, 10mm wide'
+  end
+
   def test_bad_objects
     coveralls = Coverall::Coveralls.new "test_bad_objects"
     patch = Coverall::Patch.new Coverall::Color::RED
@@ -44,6 +44,98 @@ do {
     assert(d.coveralls!.getName() == "some_dict")
 }
 
+// Deprecated functions and methods still work, but warn when compiled.
+do {
+    assert(numAlive() == getNumAlive())
+    let coveralls = Coveralls(name: "test_deprecated")
+    assert(coveralls.name() == "test_deprecated")
+}
+
+// Maps can have keys of any type.
+assert(countColors(colors: [.red, .blue, .red]) == [.red: 2, .blue: 1])
+assert(invertNames(names: [1: "one", 2: "two"]) == ["one": 1, "two": 2])
+
+// Bytes are passed as `Data`.
+assert(splitBytes(input: Data([1, 0, 255, 0]), separator: 0) == [Data([1]), Data([255]), Data()])
+assert(joinBytes(parts: [Data([1]), Data(), Data([255])], separator: 0) == Data([1, 0, 0, 255]))
+
+// Tuples are passed as native tuples.
+assert(minMax(values: [3, 1, 2])! == (1, 3))
+assert(minMax(values: []) == nil)
+let enumerated = enumerateColors(colors: [.red, .blue])
+assert(enumerated.count == 2 && enumerated[0] == (0, Color.red) && enumerated[1] == (1, Color.blue))
+
+// Sets are passed as a `Set`, and ordered maps as a plain dictionary.
+assert(setUnion(a: [1, 2], b: [2, 3]) == [1, 2, 3])
+assert(countWords(words: ["b", "c", "a", "b"]) == ["a": 1, "b": 2, "c": 1])
+assert(frequentWords(counts: ["b": 2, "a": 3, "c": 1], minCount: 2) == ["a", "b"])
+
+// Recursive enums are `indirect`, and recursive record fields are stored indirectly.
+let expr = Expr.add(left: .num(n: 2), right: .neg(inner: .num(n: 5)))
+assert(evaluate(expr: expr) == -3)
+assert(negateExpr(expr: expr) == .neg(inner: expr))
+let head = listFrom(values: [1, 2, 3])!
+assert(head.value == 1 && head.tail!.tail!.value == 3 && head.tail!.tail!.tail == nil)
+assert(listValues(head: head) == [1, 2, 3])
+assert(listValues(head: ListNode(value: 4, tail: ListNode(value: 5, tail: nil))) == [4, 5])
+assert(listFrom(values: []) == nil)
+
+// Enum variants have the value they're passed to Rust as for their raw value.
+assert(Color.red.rawValue == 1 && Color(rawValue: 3) == .green)
+assert(Priority.low.rawValue == 10 && Priority.medium.rawValue == 11 && Priority.high.rawValue == 20)
+assert(Priority.unknown.rawValue == nil)
+assert(raisePriority(priority: .low) == .medium)
+assert(raisePriority(priority: .medium) == .high)
+
+// Flags are an `OptionSet`.
+assert(Permissions.execute.rawValue == 4)
+assert(combinePermissions(permissions: []) == [])
+let readWrite = combinePermissions(permissions: [.read, .write, .read])
+assert(readWrite == [.read, .write])
+assert(readWrite.contains(.write) && !readWrite.contains(.execute))
+
+// 128-bit integers are passed as a pair of 64-bit words.
+assert(wideningMul(a: UInt64.max, b: UInt64.max) == U128(high: UInt64.max - 1, low: 1))
+assert(negateI128(value: I128(-1)) == I128(1))
+assert(negateI128(value: I128(high: 1, low: 0)) == I128(high: -1, low: 0))
+
+// Static methods are called on the class.
+assert(Coveralls.isValidName(name: "test_static"))
+assert(!Coveralls.isValidName(name: ""))
+
+// Objects can expose their Rust std trait impls.
+do {
+    let patch = Patch(color: .red)
+    let other = Patch(color: .red)
+    assert(String(describing: patch) == "Red patch, 10mm wide")
+    assert(String(reflecting: patch) == "Patch { color: Red, width: 10 }")
+    assert(patch == other)
+    assert(Set([patch, other]).count == 1)
+    other.width = 20
+    assert(patch != other)
+    assert(patch != Patch(color: .blue))
+}
+
+// Interface attributes are properties.
+do {
+    let patch = Patch(color: .red)
+    assert(patch.description == "A Red patch")
+    assert(patch.width == 10)
+    patch.width = 25
+    assert(patch.width == 25)
+}
+
+// Test constants.
+do {
+    let maxHoles: UInt32 = coverall.maxHoles
+    assert(maxHoles == 500)
+    let minTemperature: Int16 = coverall.minTemperature
+    assert(minTemperature == -16)
+    assert(stretchiness.almostEquals(0.25))
+    assert(!waterproof)
+    assert(careInstructions == "Machine wash {cold}; line dry")
+}
+
 // Test arcs.
 do {
     let coveralls = Coveralls(name: "test_arcs")
@@ -99,6 +191,14 @@ do {
     // Note: Can't test coveralls.panic() because rust panics trigger a fatal error in swift
 }
 
+// Test flat errors whose Rust variants have fields
+do {
+    try throwFlatError()
+    fatalError("Should have thrown")
+} catch CoverallFlatError.TooManyVariants(let message) {
+    assert(message == "Too many variants: 99")
+}
+
 // Test complex errors
 do {
     let coveralls = Coveralls(name: "test_complex_errors")
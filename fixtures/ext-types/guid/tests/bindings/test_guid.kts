@@ -0,0 +1,13 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+import uniffi.ext_types_guid.*
+
+assert(getGuid(null) == "NewGuid")
+assert(getGuid("SomeGuid") == "SomeGuid")
+
+val helper = getGuidHelper(null)
+assert(helper.guid == "first-guid")
+assert(helper.guids == listOf("second-guid", "third-guid"))
+assert(helper.maybeGuid == null)
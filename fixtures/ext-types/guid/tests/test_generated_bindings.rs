@@ -1 +1,7 @@
-uniffi_macros::build_foreign_language_testcases!("src/guid.udl", ["tests/bindings/test_guid.py",]);
+uniffi_macros::build_foreign_language_testcases!(
+    "src/guid.udl",
+    [
+        "tests/bindings/test_guid.py",
+        "tests/bindings/test_guid.kts",
+    ]
+);
@@ -0,0 +1,20 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+import uniffi.imported_types_lib.*
+import uniffi.one.*
+
+val ct = getCombinedType(null)
+assert(ct.uot.sval == "hello")
+assert(ct.uots == listOf(UniffiOneType("first of many"), UniffiOneType("second of many")))
+assert(ct.maybeUot == null)
+assert(ct.guid == "a-guid")
+assert(ct.guids == listOf("b-guid", "c-guid"))
+assert(ct.json == """{"hello":"there"}""")
+assert(ct.handle == 123L)
+assert(ct.handles == listOf(1L, 2L, 3L))
+assert(ct.maybeHandle == 4L)
+
+val ct2 = getCombinedType(ct)
+assert(ct == ct2)
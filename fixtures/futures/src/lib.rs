@@ -0,0 +1,117 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+use std::thread;
+use std::time::Duration;
+
+#[derive(Debug, thiserror::Error)]
+pub enum FutureError {
+    #[error("The future failed")]
+    Failed,
+}
+
+/// A future that completes after a given delay, by waking itself from a separate thread.
+///
+/// This avoids depending on any particular async runtime, while still ensuring that
+/// the foreign language bindings have to deal with futures that are genuinely pending.
+struct TimerFuture {
+    shared_state: Arc<Mutex<TimerState>>,
+}
+
+struct TimerState {
+    completed: bool,
+    waker: Option<Waker>,
+}
+
+impl TimerFuture {
+    fn new(duration: Duration) -> Self {
+        let shared_state = Arc::new(Mutex::new(TimerState {
+            completed: false,
+            waker: None,
+        }));
+        let thread_shared_state = shared_state.clone();
+        thread::spawn(move || {
+            thread::sleep(duration);
+            let mut shared_state = thread_shared_state.lock().unwrap();
+            shared_state.completed = true;
+            if let Some(waker) = shared_state.waker.take() {
+                waker.wake()
+            }
+        });
+        Self { shared_state }
+    }
+}
+
+impl Future for TimerFuture {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        let mut shared_state = self.shared_state.lock().unwrap();
+        if shared_state.completed {
+            Poll::Ready(())
+        } else {
+            shared_state.waker = Some(cx.waker().clone());
+            Poll::Pending
+        }
+    }
+}
+
+async fn always_ready() -> bool {
+    true
+}
+
+async fn sleep(ms: u16) {
+    TimerFuture::new(Duration::from_millis(ms.into())).await;
+}
+
+async fn say_after(ms: u16, who: String) -> String {
+    sleep(ms).await;
+    format!("Hello, {}!", who)
+}
+
+async fn greet(who: &str) -> String {
+    sleep(1).await;
+    format!("Hello, {}!", who)
+}
+
+async fn fallible_sleep(ms: u16, fail: bool) -> Result<u16, FutureError> {
+    sleep(ms).await;
+    if fail {
+        Err(FutureError::Failed)
+    } else {
+        Ok(ms)
+    }
+}
+
+pub struct Megaphone;
+
+impl Megaphone {
+    fn new() -> Self {
+        Self
+    }
+
+    async fn say_after(&self, ms: u16, who: String) -> String {
+        say_after(ms, who).await.to_uppercase()
+    }
+
+    async fn fallible_say_after(
+        &self,
+        ms: u16,
+        who: String,
+        fail: bool,
+    ) -> Result<String, FutureError> {
+        fallible_sleep(ms, fail).await?;
+        Ok(self.say_after(0, who).await)
+    }
+
+    async fn announce_after(ms: u16, who: String) -> String {
+        format!("{} HAS ARRIVED", say_after(ms, who).await.to_uppercase())
+    }
+}
+
+include!(concat!(env!("OUT_DIR"), "/futures.uniffi.rs"));
@@ -0,0 +1,47 @@
+import uniffi.futures.*
+import java.util.concurrent.CountDownLatch
+import kotlin.coroutines.*
+
+// Run a suspending block to completion on the current thread, without depending on kotlinx.coroutines.
+fun <T> runSuspend(block: suspend () -> T): T {
+    val latch = CountDownLatch(1)
+    var result: Result<T>? = null
+    block.startCoroutine(Continuation(EmptyCoroutineContext) {
+        result = it
+        latch.countDown()
+    })
+    latch.await()
+    return result!!.getOrThrow()
+}
+
+runSuspend {
+    assert(alwaysReady())
+
+    sleep(10u)
+
+    val t0 = System.currentTimeMillis()
+    assert(sayAfter(100u, "Alice") == "Hello, Alice!")
+    assert(System.currentTimeMillis() - t0 >= 100)
+
+    assert(greet("Bob") == "Hello, Bob!")
+
+    assert(fallibleSleep(10u, false) == 10.toUShort())
+    try {
+        fallibleSleep(10u, true)
+        throw RuntimeException("Should have thrown a FutureException.Failed exception!")
+    } catch (e: FutureException.Failed) {
+        // It's okay!
+    }
+
+    val megaphone = Megaphone()
+    assert(megaphone.sayAfter(10u, "Alice") == "HELLO, ALICE!")
+    assert(megaphone.fallibleSayAfter(10u, "Bob", false) == "HELLO, BOB!")
+    try {
+        megaphone.fallibleSayAfter(10u, "Bob", true)
+        throw RuntimeException("Should have thrown a FutureException.Failed exception!")
+    } catch (e: FutureException.Failed) {
+        // It's okay!
+    }
+    megaphone.destroy()
+    assert(Megaphone.announceAfter(10u, "Carol") == "HELLO, CAROL! HAS ARRIVED")
+}
@@ -0,0 +1,51 @@
+import asyncio
+import time
+from futures import *
+
+async def test():
+    assert await always_ready() is True
+
+    assert await sleep(10) is None
+
+    t0 = time.monotonic()
+    assert await say_after(100, "Alice") == "Hello, Alice!"
+    assert time.monotonic() - t0 >= 0.1
+
+    assert await greet("Bob") == "Hello, Bob!"
+
+    # Pending calls should run concurrently.
+    t0 = time.monotonic()
+    results = await asyncio.gather(say_after(200, "Alice"), say_after(200, "Bob"))
+    assert results == ["Hello, Alice!", "Hello, Bob!"]
+    assert time.monotonic() - t0 < 0.4
+
+    assert await fallible_sleep(10, False) == 10
+    try:
+        await fallible_sleep(10, True)
+        assert(not("Should have thrown a FutureError.Failed exception!"))
+    except FutureError.Failed:
+        # It's okay!
+        pass
+
+    megaphone = Megaphone()
+    assert await megaphone.say_after(10, "Alice") == "HELLO, ALICE!"
+    assert await megaphone.fallible_say_after(10, "Bob", False) == "HELLO, BOB!"
+    try:
+        await megaphone.fallible_say_after(10, "Bob", True)
+        assert(not("Should have thrown a FutureError.Failed exception!"))
+    except FutureError.Failed:
+        pass
+    assert await Megaphone.announce_after(10, "Carol") == "HELLO, CAROL! HAS ARRIVED"
+
+    # Cancelling an in-flight call should not break later calls.
+    task = asyncio.ensure_future(say_after(1000, "Nobody"))
+    await asyncio.sleep(0.01)
+    task.cancel()
+    try:
+        await task
+        assert(not("Should have been cancelled!"))
+    except asyncio.CancelledError:
+        pass
+    assert await always_ready() is True
+
+asyncio.run(test())
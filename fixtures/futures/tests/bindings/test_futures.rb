@@ -0,0 +1,29 @@
+# frozen_string_literal: true
+
+require 'test/unit'
+require 'futures'
+
+include Test::Unit::Assertions
+
+# Ruby has no native async/await, so async functions block until they are complete.
+assert_equal Futures.always_ready, true
+assert_nil Futures.sleep(10)
+
+t0 = Time.now
+assert_equal Futures.say_after(100, 'Alice'), 'Hello, Alice!'
+assert Time.now - t0 >= 0.1
+
+assert_equal Futures.greet('Bob'), 'Hello, Bob!'
+
+assert_equal Futures.fallible_sleep(10, false), 10
+assert_raise Futures::FutureError::Failed do
+  Futures.fallible_sleep(10, true)
+end
+
+megaphone = Futures::Megaphone.new
+assert_equal megaphone.say_after(10, 'Alice'), 'HELLO, ALICE!'
+assert_equal megaphone.fallible_say_after(10, 'Bob', false), 'HELLO, BOB!'
+assert_raise Futures::FutureError::Failed do
+  megaphone.fallible_say_after(10, 'Bob', true)
+end
+assert_equal Futures::Megaphone.announce_after(10, 'Carol'), 'HELLO, CAROL! HAS ARRIVED'
@@ -0,0 +1,62 @@
+import Foundation
+import futures
+
+// Run an async block to completion, blocking the current thread.
+func runAsync(_ block: @escaping () async throws -> Void) {
+    let semaphore = DispatchSemaphore(value: 0)
+    Task {
+        do {
+            try await block()
+        } catch {
+            fatalError("Unexpected error: \(error)")
+        }
+        semaphore.signal()
+    }
+    semaphore.wait()
+}
+
+runAsync {
+    let ready = await alwaysReady()
+    assert(ready)
+
+    await sleep(ms: 10)
+
+    let t0 = Date()
+    let hello = await sayAfter(ms: 100, who: "Alice")
+    assert(hello == "Hello, Alice!")
+    assert(Date().timeIntervalSince(t0) >= 0.1)
+
+    let greeting = await greet(who: "Bob")
+    assert(greeting == "Hello, Bob!")
+
+    // Pending calls should run concurrently.
+    let t1 = Date()
+    async let alice = sayAfter(ms: 200, who: "Alice")
+    async let bob = sayAfter(ms: 200, who: "Bob")
+    let results = await [alice, bob]
+    assert(results == ["Hello, Alice!", "Hello, Bob!"])
+    assert(Date().timeIntervalSince(t1) < 0.4)
+
+    let slept = try await fallibleSleep(ms: 10, fail: false)
+    assert(slept == 10)
+    do {
+        _ = try await fallibleSleep(ms: 10, fail: true)
+        fatalError("Should have thrown a FutureError.Failed error!")
+    } catch FutureError.Failed {
+        // It's okay!
+    }
+
+    let megaphone = Megaphone()
+    let shout = await megaphone.sayAfter(ms: 10, who: "Alice")
+    assert(shout == "HELLO, ALICE!")
+    let fallibleShout = try await megaphone.fallibleSayAfter(ms: 10, who: "Bob", fail: false)
+    assert(fallibleShout == "HELLO, BOB!")
+    do {
+        _ = try await megaphone.fallibleSayAfter(ms: 10, who: "Bob", fail: true)
+        fatalError("Should have thrown a FutureError.Failed error!")
+    } catch FutureError.Failed {
+        // It's okay!
+    }
+    let announcement = await Megaphone.announceAfter(ms: 10, who: "Carol")
+    assert(announcement == "HELLO, CAROL! HAS ARRIVED")
+}
@@ -0,0 +1,9 @@
+uniffi_macros::build_foreign_language_testcases!(
+    "src/futures.udl",
+    [
+        "tests/bindings/test_futures.py",
+        "tests/bindings/test_futures.kts",
+        "tests/bindings/test_futures.swift",
+        "tests/bindings/test_futures.rb",
+    ]
+);
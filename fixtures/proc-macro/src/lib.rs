@@ -0,0 +1,85 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+// Declared in the UDL.
+fn greet(name: String) -> String {
+    format!("Hello, {}!", name)
+}
+
+#[uniffi::record]
+pub struct Point {
+    x: f64,
+    y: f64,
+}
+
+#[uniffi::record]
+pub struct Shape {
+    name: String,
+    points: Vec<Point>,
+    tags: HashMap<String, u32>,
+    label: Option<String>,
+}
+
+#[uniffi::export]
+fn make_point(x: f64, y: f64) -> Point {
+    Point { x, y }
+}
+
+#[uniffi::export]
+fn shape_len(shape: Shape) -> u32 {
+    shape.points.len() as u32
+}
+
+#[uniffi::export]
+fn translate(points: Vec<Point>, dx: f64, dy: f64) -> Vec<Point> {
+    points
+        .into_iter()
+        .map(|p| Point {
+            x: p.x + dx,
+            y: p.y + dy,
+        })
+        .collect()
+}
+
+#[uniffi::object]
+pub struct Counter {
+    count: Mutex<u32>,
+}
+
+#[uniffi::export]
+impl Counter {
+    fn new() -> Self {
+        Self::starting_at(0)
+    }
+
+    fn starting_at(count: u32) -> Self {
+        Self {
+            count: Mutex::new(count),
+        }
+    }
+
+    fn increment(&self, by: u32) -> u32 {
+        let mut count = self.count.lock().unwrap();
+        *count += by;
+        *count
+    }
+
+    fn get(&self) -> u32 {
+        *self.count.lock().unwrap()
+    }
+
+    fn fork(self: Arc<Self>) -> Arc<Self> {
+        Arc::new(Self::starting_at(self.get()))
+    }
+}
+
+#[uniffi::export]
+fn counter_value(counter: Arc<Counter>) -> u32 {
+    counter.get()
+}
+
+include!(concat!(env!("OUT_DIR"), "/proc-macro.uniffi.rs"));
@@ -0,0 +1,40 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+import uniffi.proc_macro.*
+
+// Declared in the UDL.
+assert(greet("World") == "Hello, World!")
+
+// Records and functions declared with procedural macros.
+val point = makePoint(1.0, 2.0)
+assert(point.x == 1.0)
+assert(point.y == 2.0)
+
+val shape = Shape("square", listOf(Point(0.0, 0.0), Point(1.0, 1.0)), mapOf("sides" to 4u), null)
+assert(shapeLen(shape) == 2u)
+
+val moved = translate(listOf(Point(0.0, 0.0), Point(1.0, 1.0)), 1.0, -1.0)
+assert(moved == listOf(Point(1.0, -1.0), Point(2.0, 0.0)))
+
+// Objects, their constructors and methods.
+val counter = Counter()
+assert(counter.get() == 0u)
+assert(counter.increment(2u) == 2u)
+assert(counter.increment(3u) == 5u)
+
+val other = Counter.startingAt(10u)
+assert(other.get() == 10u)
+
+val forked = counter.fork()
+assert(forked.get() == 5u)
+forked.increment(1u)
+assert(forked.get() == 6u)
+assert(counter.get() == 5u)
+
+assert(counterValue(forked) == 6u)
+
+counter.destroy()
+other.destroy()
+forked.destroy()
@@ -0,0 +1,36 @@
+# This Source Code Form is subject to the terms of the Mozilla Public
+# License, v. 2.0. If a copy of the MPL was not distributed with this
+# file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+from proc_macro import *
+
+# Declared in the UDL.
+assert greet("World") == "Hello, World!"
+
+# Records and functions declared with procedural macros.
+point = make_point(1.0, 2.0)
+assert point.x == 1.0
+assert point.y == 2.0
+
+shape = Shape("square", [Point(0.0, 0.0), Point(1.0, 1.0)], {"sides": 4}, None)
+assert shape_len(shape) == 2
+
+moved = translate([Point(0.0, 0.0), Point(1.0, 1.0)], 1.0, -1.0)
+assert moved == [Point(1.0, -1.0), Point(2.0, 0.0)]
+
+# Objects, their constructors and methods.
+counter = Counter()
+assert counter.get() == 0
+assert counter.increment(2) == 2
+assert counter.increment(3) == 5
+
+other = Counter.starting_at(10)
+assert other.get() == 10
+
+forked = counter.fork()
+assert forked.get() == 5
+forked.increment(1)
+assert forked.get() == 6
+assert counter.get() == 5
+
+assert counter_value(forked) == 6
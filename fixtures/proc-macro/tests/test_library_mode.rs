@@ -0,0 +1,64 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use anyhow::Result;
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+const LANGUAGES: &[&str] = &["kotlin", "python", "ruby", "swift"];
+
+// Bindings generated from just the compiled library should be exactly the same as those
+// generated from the UDL, including the items exported with procedural macros.
+#[test]
+fn test_library_mode_matches_udl() -> Result<()> {
+    let pkg_dir = env!("CARGO_MANIFEST_DIR");
+    let cdylib_file = PathBuf::from(uniffi::testing::ensure_compiled_cdylib(pkg_dir)?);
+    let out_dir = cdylib_file.parent().unwrap().join("test-library-mode");
+    let udl_out_dir = out_dir.join("udl");
+    let library_out_dir = out_dir.join("library");
+    let _ = fs::remove_dir_all(&out_dir);
+
+    uniffi_bindgen::generate_bindings(
+        Path::new(pkg_dir).join("src/proc-macro.udl").as_path(),
+        None,
+        LANGUAGES.to_vec(),
+        Some(udl_out_dir.as_path()),
+        Some(cdylib_file.as_path()),
+        false,
+    )?;
+    uniffi_bindgen::generate_bindings_from_library(
+        cdylib_file.as_path(),
+        None,
+        LANGUAGES.to_vec(),
+        library_out_dir.as_path(),
+        false,
+    )?;
+
+    let udl_files = read_files(&udl_out_dir)?;
+    assert!(udl_files.contains_key(Path::new("proc_macro.py")));
+    assert_eq!(udl_files, read_files(&library_out_dir)?);
+    Ok(())
+}
+
+fn read_files(dir: &Path) -> Result<BTreeMap<PathBuf, String>> {
+    let mut files = BTreeMap::new();
+    let mut dirs = vec![dir.to_path_buf()];
+    while let Some(current) = dirs.pop() {
+        for entry in fs::read_dir(current)? {
+            let path = entry?.path();
+            if path.is_dir() {
+                dirs.push(path);
+            } else {
+                files.insert(
+                    path.strip_prefix(dir)?.to_path_buf(),
+                    fs::read_to_string(&path)?,
+                );
+            }
+        }
+    }
+    Ok(files)
+}
@@ -0,0 +1,123 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+pub trait StorageBackend: Send + Sync {
+    fn name(&self) -> String;
+    fn put(&self, key: String, value: String) -> bool;
+    fn get(&self, key: String) -> Option<String>;
+    fn with_prefix(self: Arc<Self>, prefix: String) -> Arc<dyn StorageBackend>;
+}
+
+#[derive(Default)]
+struct MemoryBackend {
+    data: Mutex<HashMap<String, String>>,
+}
+
+impl StorageBackend for MemoryBackend {
+    fn name(&self) -> String {
+        "memory".to_string()
+    }
+
+    fn put(&self, key: String, value: String) -> bool {
+        self.data.lock().unwrap().insert(key, value);
+        true
+    }
+
+    fn get(&self, key: String) -> Option<String> {
+        self.data.lock().unwrap().get(&key).cloned()
+    }
+
+    fn with_prefix(self: Arc<Self>, prefix: String) -> Arc<dyn StorageBackend> {
+        Arc::new(PrefixedBackend {
+            prefix,
+            inner: self,
+        })
+    }
+}
+
+struct ReadonlyBackend {
+    inner: Arc<dyn StorageBackend>,
+}
+
+impl StorageBackend for ReadonlyBackend {
+    fn name(&self) -> String {
+        format!("readonly {}", self.inner.name())
+    }
+
+    fn put(&self, _key: String, _value: String) -> bool {
+        false
+    }
+
+    fn get(&self, key: String) -> Option<String> {
+        self.inner.get(key)
+    }
+
+    fn with_prefix(self: Arc<Self>, prefix: String) -> Arc<dyn StorageBackend> {
+        Arc::new(PrefixedBackend {
+            prefix,
+            inner: self,
+        })
+    }
+}
+
+struct PrefixedBackend {
+    prefix: String,
+    inner: Arc<dyn StorageBackend>,
+}
+
+impl StorageBackend for PrefixedBackend {
+    fn name(&self) -> String {
+        format!("{} prefixed with {}", self.inner.name(), self.prefix)
+    }
+
+    fn put(&self, key: String, value: String) -> bool {
+        self.inner.put(format!("{}{}", self.prefix, key), value)
+    }
+
+    fn get(&self, key: String) -> Option<String> {
+        self.inner.get(format!("{}{}", self.prefix, key))
+    }
+
+    fn with_prefix(self: Arc<Self>, prefix: String) -> Arc<dyn StorageBackend> {
+        Arc::new(PrefixedBackend {
+            prefix,
+            inner: self,
+        })
+    }
+}
+
+fn make_memory_backend() -> Arc<dyn StorageBackend> {
+    Arc::new(MemoryBackend::default())
+}
+
+fn make_readonly_backend(inner: Arc<dyn StorageBackend>) -> Arc<dyn StorageBackend> {
+    Arc::new(ReadonlyBackend { inner })
+}
+
+pub struct Store {
+    backend: Arc<dyn StorageBackend>,
+}
+
+impl Store {
+    fn new(backend: Arc<dyn StorageBackend>) -> Self {
+        Store { backend }
+    }
+
+    fn backend(&self) -> Arc<dyn StorageBackend> {
+        Arc::clone(&self.backend)
+    }
+
+    fn put(&self, key: String, value: String) -> bool {
+        self.backend.put(key, value)
+    }
+
+    fn get(&self, key: String) -> Option<String> {
+        self.backend.get(key)
+    }
+}
+
+include!(concat!(env!("OUT_DIR"), "/trait-interfaces.uniffi.rs"));
@@ -0,0 +1,32 @@
+# This Source Code Form is subject to the terms of the Mozilla Public
+# License, v. 2.0. If a copy of the MPL was not distributed with this
+# file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+from trait_interfaces import *
+
+# Each function returns a different Rust implementation of the same interface.
+memory = make_memory_backend()
+assert memory.name() == "memory"
+assert memory.put("key", "value")
+assert memory.get("key") == "value"
+assert memory.get("missing") is None
+
+readonly = make_readonly_backend(memory)
+assert isinstance(readonly, StorageBackend)
+assert readonly.name() == "readonly memory"
+assert not readonly.put("key", "other")
+assert readonly.get("key") == "value"
+
+# A method that takes the trait object by `Arc`.
+prefixed = memory.with_prefix("p:")
+assert prefixed.name() == "memory prefixed with p:"
+assert prefixed.put("key", "prefixed value")
+assert prefixed.get("key") == "prefixed value"
+assert memory.get("p:key") == "prefixed value"
+
+# Trait objects can be passed to and returned from an ordinary interface.
+store = Store(prefixed)
+assert store.get("key") == "prefixed value"
+assert store.put("other", "other value")
+assert memory.get("p:other") == "other value"
+assert store.backend().name() == "memory prefixed with p:"
@@ -0,0 +1,37 @@
+# frozen_string_literal: true
+
+# This Source Code Form is subject to the terms of the Mozilla Public
+# License, v. 2.0. If a copy of the MPL was not distributed with this
+# file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+require 'test/unit'
+require 'trait_interfaces'
+
+include Test::Unit::Assertions
+
+# Each function returns a different Rust implementation of the same interface.
+memory = TraitInterfaces.make_memory_backend
+assert_equal memory.name, 'memory'
+assert memory.put('key', 'value')
+assert_equal memory.get('key'), 'value'
+assert_nil memory.get('missing')
+
+readonly = TraitInterfaces.make_readonly_backend memory
+assert_kind_of TraitInterfaces::StorageBackend, readonly
+assert_equal readonly.name, 'readonly memory'
+assert !readonly.put('key', 'other')
+assert_equal readonly.get('key'), 'value'
+
+# A method that takes the trait object by `Arc`.
+prefixed = memory.with_prefix 'p:'
+assert_equal prefixed.name, 'memory prefixed with p:'
+assert prefixed.put('key', 'prefixed value')
+assert_equal prefixed.get('key'), 'prefixed value'
+assert_equal memory.get('p:key'), 'prefixed value'
+
+# Trait objects can be passed to and returned from an ordinary interface.
+store = TraitInterfaces::Store.new prefixed
+assert_equal store.get('key'), 'prefixed value'
+assert store.put('other', 'other value')
+assert_equal memory.get('p:other'), 'other value'
+assert_equal store.backend.name, 'memory prefixed with p:'
@@ -0,0 +1,31 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+import trait_interfaces
+
+// Each function returns a different Rust implementation of the same interface.
+let memory = makeMemoryBackend()
+assert(memory.name() == "memory")
+assert(memory.put(key: "key", value: "value"))
+assert(memory.get(key: "key") == "value")
+assert(memory.get(key: "missing") == nil)
+
+let readonly = makeReadonlyBackend(inner: memory)
+assert(readonly.name() == "readonly memory")
+assert(!readonly.put(key: "key", value: "other"))
+assert(readonly.get(key: "key") == "value")
+
+// A method that takes the trait object by `Arc`.
+let prefixed = memory.withPrefix(prefix: "p:")
+assert(prefixed.name() == "memory prefixed with p:")
+assert(prefixed.put(key: "key", value: "prefixed value"))
+assert(prefixed.get(key: "key") == "prefixed value")
+assert(memory.get(key: "p:key") == "prefixed value")
+
+// Trait objects can be passed to and returned from an ordinary interface.
+let store = Store(backend: prefixed)
+assert(store.get(key: "key") == "prefixed value")
+assert(store.put(key: "other", value: "other value"))
+assert(memory.get(key: "p:other") == "other value")
+assert(store.backend().name() == "memory prefixed with p:")
@@ -0,0 +1,9 @@
+uniffi_macros::build_foreign_language_testcases!(
+    "src/trait-interfaces.udl",
+    [
+        "tests/bindings/test_trait_interfaces.kts",
+        "tests/bindings/test_trait_interfaces.py",
+        "tests/bindings/test_trait_interfaces.rb",
+        "tests/bindings/test_trait_interfaces.swift",
+    ]
+);
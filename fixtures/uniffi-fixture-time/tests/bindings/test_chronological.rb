@@ -0,0 +1,50 @@
+# frozen_string_literal: true
+
+require 'test/unit'
+require 'chronological'
+
+include Test::Unit::Assertions
+include Chronological
+
+NANOSECOND = Rational(1, 1_000_000_000)
+
+# Test passing timestamp and duration while returning timestamp
+assert_equal Time.at(101, 101, :nanosecond),
+             Chronological.add(Time.at(100, 100, :nanosecond), 1 + NANOSECOND)
+
+# Test passing timestamp while returning duration
+assert_equal 1 + NANOSECOND,
+             Chronological.diff(Time.at(101, 101, :nanosecond), Time.at(100, 100, :nanosecond))
+
+# Test pre-epoch timestamps
+assert_equal Time.utc(1955, 11, 5, 0, 6, 1 + 283_000_002 * NANOSECOND),
+             Chronological.add(Time.utc(1955, 11, 5, 0, 6, 283_000_001 * NANOSECOND), 1 + NANOSECOND)
+
+# Test that durations can be given as any numeric type
+assert_equal Time.at(102), Chronological.add(Time.at(100), 2)
+assert_equal Time.at(100, 500, :millisecond), Chronological.add(Time.at(100), 0.5)
+
+# Test exceptions are propagated
+assert_raise ChronologicalError::TimeDiffError do
+  Chronological.diff(Time.at(100), Time.at(101))
+end
+
+# Test that negative durations are rejected
+assert_raise ArgumentError do
+  Chronological.add(Time.at(100), -1)
+end
+
+# Test that rust timestamps behave like ruby timestamps
+ruby_before = Time.now
+rust_now = Chronological.now
+ruby_after = Time.now
+
+assert ruby_before <= rust_now
+assert rust_now <= ruby_after
+
+# Test that uniffi returns UTC times
+assert Chronological.now.utc?
+
+# Test that timestamps round-trip exactly
+assert Chronological.equal(Time.at(100, 123_456_789, :nanosecond), Time.at(100, 123_456_789, :nanosecond))
+assert !Chronological.equal(Time.at(100, 1, :nanosecond), Time.at(100, 2, :nanosecond))
@@ -2,6 +2,7 @@ uniffi_macros::build_foreign_language_testcases!(
     "src/chronological.udl",
     [
         "tests/bindings/test_chronological.py",
+        "tests/bindings/test_chronological.rb",
         "tests/bindings/test_chronological.kts",
         "tests/bindings/test_chronological.swift",
     ]
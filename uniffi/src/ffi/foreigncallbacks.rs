@@ -108,12 +108,28 @@
 //! The `CallbackInternals` object unpacks the arguments from the passed buffer, gets the object out from the handlemap,
 //! and calls the actual implementation of the method.
 //!
-//! If there's a return value, it is packed up in to another `RustBuffer` and used as the return value for
-//! `ForeignCallback`. The caller of `ForeignCallback`, the `KeychainProxy` unpacks the returned buffer into the correct
-//! type and then returns to client code.
+//! If there's a return value, it is packed up in to another `RustBuffer` and written to the output buffer passed
+//! to the `ForeignCallback`. The caller of `ForeignCallback`, the `KeychainProxy` unpacks the returned buffer into the
+//! correct type and then returns to client code.
+//!
+//! ## Errors
+//!
+//! The `ForeignCallback` returns a status code to tell Rust how to interpret the output buffer:
+//!
+//! * [`CALLBACK_SUCCESS`]: the method returned normally, and the buffer contains its return value.
+//! * [`CALLBACK_ERROR`]: the method threw the error declared via `[Throws=ErrorName]`, and the buffer
+//!   contains the serialized error.
+//! * [`CALLBACK_UNEXPECTED_ERROR`]: the method threw something else. The buffer may contain a string
+//!   describing what went wrong.
+//!
+//! For methods declared with `[Throws=ErrorName]`, the Rust trait method must return `Result<T, ErrorName>`,
+//! and `ErrorName` must implement `From<UnexpectedUniFFICallbackError>` so that unexpected errors can be reported
+//! without panicking. Methods without `[Throws]` have no way to report an unexpected error, so the proxy panics.
 //!
 
 use super::RustBuffer;
+use std::fmt;
+use std::os::raw::c_int;
 use std::sync::atomic::{AtomicUsize, Ordering};
 
 /// ForeignCallback is the Rust representation of a foreign language function.
@@ -125,13 +141,62 @@ use std::sync::atomic::{AtomicUsize, Ordering};
 /// The `method` selector specifies the method that will be called on the object, by looking it up in a list of methods from
 /// the IDL. The index is 1 indexed. Note that the list of methods is generated by at uniffi from the IDL and used in all
 /// bindings: so we can rely on the method list being stable within the same run of uniffi.
-pub type ForeignCallback =
-    unsafe extern "C" fn(handle: u64, method: u32, args: RustBuffer) -> RustBuffer;
+/// The result of the call is written to `buf_ptr`, and the returned status code says how to interpret it.
+pub type ForeignCallback = unsafe extern "C" fn(
+    handle: u64,
+    method: u32,
+    args: RustBuffer,
+    buf_ptr: *mut RustBuffer,
+) -> c_int;
 
 /// The method index used by the Drop trait to communicate to the foreign language side that Rust has finished with it,
 /// and it can be deleted from the handle map.
 pub const IDX_CALLBACK_FREE: u32 = 0;
 
+/// The callback returned successfully, and the output buffer contains the serialized return value.
+pub const CALLBACK_SUCCESS: c_int = 0;
+/// The callback threw its declared error, and the output buffer contains the serialized error.
+pub const CALLBACK_ERROR: c_int = 1;
+/// The callback failed unexpectedly, and the output buffer may contain a string describing the failure.
+pub const CALLBACK_UNEXPECTED_ERROR: c_int = 2;
+
+/// The outcome of invoking a method on a foreign callback.
+pub enum CallbackResult {
+    /// The method returned successfully; the buffer contains its serialized return value.
+    Success(RustBuffer),
+    /// The method threw its declared error; the buffer contains the serialized error.
+    Error(RustBuffer),
+    /// The method failed in a way that it didn't declare.
+    UnexpectedError(UnexpectedUniFFICallbackError),
+}
+
+/// Error reported when a foreign callback fails in a way that isn't described by its `[Throws]` declaration.
+///
+/// Errors thrown by callback interface methods must implement `From<UnexpectedUniFFICallbackError>`,
+/// typically by mapping it onto a dedicated variant.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnexpectedUniFFICallbackError {
+    pub reason: String,
+}
+
+impl UnexpectedUniFFICallbackError {
+    pub fn from_reason(reason: String) -> Self {
+        Self { reason }
+    }
+}
+
+impl fmt::Display for UnexpectedUniFFICallbackError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "UnexpectedUniFFICallbackError(reason: {:?})",
+            self.reason
+        )
+    }
+}
+
+impl std::error::Error for UnexpectedUniFFICallbackError {}
+
 // Overly-paranoid sanity checking to ensure that these types are
 // convertible between each-other. `transmute` actually should check this for
 // us too, but this helps document the invariants we rely on in this code.
@@ -181,4 +246,87 @@ impl ForeignCallbackInternals {
         let ptr_value = self.callback_ptr.load(Ordering::SeqCst);
         unsafe { std::mem::transmute::<usize, Option<ForeignCallback>>(ptr_value) }
     }
+
+    /// Invoke a method on the foreign object identified by `handle`, and interpret the status code it returns.
+    pub fn invoke_callback(&self, handle: u64, method: u32, args: RustBuffer) -> CallbackResult {
+        let callback = self
+            .get_callback()
+            .expect("Bug: callback invoked before being set. This is likely a uniffi bug");
+        let mut ret_rbuf = RustBuffer::new();
+        let code = unsafe { callback(handle, method, args, &mut ret_rbuf) };
+        match code {
+            CALLBACK_SUCCESS => CallbackResult::Success(ret_rbuf),
+            CALLBACK_ERROR => CallbackResult::Error(ret_rbuf),
+            CALLBACK_UNEXPECTED_ERROR => {
+                let reason = if !ret_rbuf.is_empty() {
+                    String::from_utf8_lossy(&ret_rbuf.destroy_into_vec()).into_owned()
+                } else {
+                    ret_rbuf.destroy();
+                    "[Unknown Reason]".to_string()
+                };
+                CallbackResult::UnexpectedError(UnexpectedUniFFICallbackError::from_reason(reason))
+            }
+            _ => {
+                ret_rbuf.destroy();
+                CallbackResult::UnexpectedError(UnexpectedUniFFICallbackError::from_reason(
+                    format!("Unknown callback status code: {}", code),
+                ))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    unsafe extern "C" fn echo_callback(
+        _handle: u64,
+        method: u32,
+        args: RustBuffer,
+        buf_ptr: *mut RustBuffer,
+    ) -> c_int {
+        *buf_ptr = args;
+        method as c_int
+    }
+
+    fn invoke(method: u32, args: Vec<u8>) -> CallbackResult {
+        let internals = ForeignCallbackInternals::new();
+        internals.set_callback(echo_callback);
+        internals.invoke_callback(0, method, RustBuffer::from_vec(args))
+    }
+
+    #[test]
+    fn test_callback_success() {
+        match invoke(CALLBACK_SUCCESS as u32, vec![1, 2, 3]) {
+            CallbackResult::Success(buf) => assert_eq!(buf.destroy_into_vec(), vec![1, 2, 3]),
+            _ => panic!("expected success"),
+        }
+    }
+
+    #[test]
+    fn test_callback_error() {
+        match invoke(CALLBACK_ERROR as u32, vec![4, 5]) {
+            CallbackResult::Error(buf) => assert_eq!(buf.destroy_into_vec(), vec![4, 5]),
+            _ => panic!("expected error"),
+        }
+    }
+
+    #[test]
+    fn test_callback_unexpected_error() {
+        match invoke(CALLBACK_UNEXPECTED_ERROR as u32, b"oops".to_vec()) {
+            CallbackResult::UnexpectedError(e) => assert_eq!(e.reason, "oops"),
+            _ => panic!("expected unexpected error"),
+        }
+        match invoke(CALLBACK_UNEXPECTED_ERROR as u32, vec![]) {
+            CallbackResult::UnexpectedError(e) => assert_eq!(e.reason, "[Unknown Reason]"),
+            _ => panic!("expected unexpected error"),
+        }
+        match invoke(42, vec![]) {
+            CallbackResult::UnexpectedError(e) => {
+                assert_eq!(e.reason, "Unknown callback status code: 42")
+            }
+            _ => panic!("expected unexpected error"),
+        }
+    }
 }
@@ -0,0 +1,224 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! # Low-level support for calling async rust functions
+//!
+//! Functions and methods marked with `[Async]` in the UDL are implemented as an `async fn`
+//! in Rust. The scaffolding code calls the rust function to obtain its future, and wraps that
+//! future in a [`RustFuture`] whose pointer is returned to the foreign language bindings.
+//! The foreign language code then drives the future to completion using a small poll/wake
+//! protocol, via builtin FFI functions generated in the scaffolding:
+//!
+//!  - `ffi_<namespace>_rust_future_poll(future, waker, waker_data)` polls the future.
+//!    If it returns non-zero then the future is ready and its result can be taken.
+//!    Otherwise the future is pending, and the [`ForeignWaker`] will be called with
+//!    `waker_data` when the future is ready to make progress, at which point the foreign
+//!    language code should poll it again.
+//!  - `ffi_<namespace>_rust_future_complete(future)` takes the result of a ready future.
+//!    The return value is always serialized into a `RustBuffer`, using `FfiConverter::write`
+//!    for the function's return type. Errors and panics are reported via the `RustCallStatus`
+//!    exactly as they would be for a synchronous call.
+//!  - `ffi_<namespace>_rust_future_free(future)` releases the future. This must be called
+//!    exactly once, even if the future was never polled to completion, which allows the
+//!    foreign language to cancel an in-flight call by dropping it.
+//!
+//! This lets each foreign language integrate async calls into its own concurrency model
+//! (coroutines in Kotlin, `async`/`await` in Swift, asyncio in Python) without Rust having
+//! to know anything about the foreign event loop.
+//!
+//! The waker may be called from any thread, possibly before `rust_future_poll` has returned,
+//! and possibly more than once. The foreign language code must not poll the future again
+//! from inside the waker callback itself; it should instead schedule the next poll on its
+//! own event loop.
+
+use super::RustBuffer;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Wake, Waker};
+
+/// ForeignWaker is the Rust representation of a foreign language function that is used
+/// to wake a pending async call.
+///
+/// It is called with the opaque `waker_data` that was passed to the most recent poll of the
+/// future, and signals that the foreign language code should poll the future again.
+pub type ForeignWaker = unsafe extern "C" fn(data: u64);
+
+/// The result of an async call, with both the success and the error value lowered
+/// into a `RustBuffer`.
+pub type RustFutureResult = Result<RustBuffer, RustBuffer>;
+
+enum RustFutureState {
+    Pending(Pin<Box<dyn Future<Output = RustFutureResult> + Send + 'static>>),
+    Ready(RustFutureResult),
+    Complete,
+}
+
+/// A type-erased future for an async call, which is handed to the foreign language code as a
+/// pointer to an `Arc<RustFuture>`.
+///
+/// The scaffolding code is responsible for converting the output of the user's future into a
+/// [`RustFutureResult`], so that all async calls can share a single set of builtin FFI functions.
+pub struct RustFuture {
+    state: Mutex<RustFutureState>,
+}
+
+// The only thing stopping `RustFuture` from being automatically `Send` is the raw pointer inside
+// the `RustBuffer` of a ready result. That buffer is uniquely owned by the future until it is
+// handed to the foreign language code by `complete`, so it's safe to move between threads, and
+// all access to it is guarded by the mutex.
+unsafe impl Send for RustFuture {}
+unsafe impl Sync for RustFuture {}
+
+impl RustFuture {
+    pub fn new<F>(future: F) -> Self
+    where
+        F: Future<Output = RustFutureResult> + Send + 'static,
+    {
+        Self {
+            state: Mutex::new(RustFutureState::Pending(Box::pin(future))),
+        }
+    }
+
+    /// Convert this future into a raw pointer for passing to the foreign language code.
+    ///
+    /// The foreign language code must eventually pass the pointer to [`RustFuture::drop_raw`]
+    /// in order to free it.
+    pub fn into_raw(self) -> *const std::os::raw::c_void {
+        Arc::into_raw(Arc::new(self)) as *const std::os::raw::c_void
+    }
+
+    /// Borrow a future from a raw pointer that was produced by [`RustFuture::into_raw`].
+    ///
+    /// # Safety
+    /// The pointer must have been produced by `into_raw`, and must not have been freed.
+    pub unsafe fn borrow_raw<'a>(ptr: *const std::os::raw::c_void) -> &'a Self {
+        &*(ptr as *const Self)
+    }
+
+    /// Free a future from a raw pointer that was produced by [`RustFuture::into_raw`].
+    ///
+    /// # Safety
+    /// The pointer must have been produced by `into_raw`, and must not be used after this call.
+    pub unsafe fn drop_raw(ptr: *const std::os::raw::c_void) {
+        drop(Arc::from_raw(ptr as *const Self))
+    }
+
+    /// Poll the future, returning `true` if it is ready to be completed.
+    ///
+    /// If the future is still pending then `waker` will be called with `waker_data` when it is
+    /// able to make further progress. Polling a future that is already ready is a no-op.
+    pub fn poll(&self, waker: ForeignWaker, waker_data: u64) -> bool {
+        let mut state = self.state.lock().unwrap();
+        let result = match &mut *state {
+            RustFutureState::Pending(future) => {
+                let waker = Waker::from(Arc::new(RustFutureWaker { waker, waker_data }));
+                match future.as_mut().poll(&mut Context::from_waker(&waker)) {
+                    Poll::Ready(result) => result,
+                    Poll::Pending => return false,
+                }
+            }
+            RustFutureState::Ready(_) | RustFutureState::Complete => return true,
+        };
+        *state = RustFutureState::Ready(result);
+        true
+    }
+
+    /// Take the result of a future that is ready.
+    ///
+    /// This will panic if the future is not ready, or if its result has already been taken.
+    pub fn complete(&self) -> RustFutureResult {
+        let mut state = self.state.lock().unwrap();
+        match std::mem::replace(&mut *state, RustFutureState::Complete) {
+            RustFutureState::Ready(result) => result,
+            RustFutureState::Pending(_) => panic!("RustFuture completed before it was ready"),
+            RustFutureState::Complete => panic!("RustFuture completed more than once"),
+        }
+    }
+}
+
+/// Adapts a [`ForeignWaker`] into a Rust `Waker`.
+struct RustFutureWaker {
+    waker: ForeignWaker,
+    waker_data: u64,
+}
+
+impl Wake for RustFutureWaker {
+    fn wake(self: Arc<Self>) {
+        self.wake_by_ref()
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        unsafe { (self.waker)(self.waker_data) }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::sync::atomic::{AtomicU64, Ordering};
+
+    static WOKEN: AtomicU64 = AtomicU64::new(0);
+
+    unsafe extern "C" fn test_waker(data: u64) {
+        WOKEN.store(data, Ordering::SeqCst);
+    }
+
+    /// A future that is pending until it has been polled `count` times.
+    struct CountdownFuture {
+        count: u32,
+    }
+
+    impl Future for CountdownFuture {
+        type Output = u32;
+        fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<u32> {
+            if self.count == 0 {
+                Poll::Ready(42)
+            } else {
+                self.count -= 1;
+                cx.waker().wake_by_ref();
+                Poll::Pending
+            }
+        }
+    }
+
+    #[test]
+    fn test_poll_and_complete() {
+        let future = RustFuture::new(async {
+            let v = CountdownFuture { count: 1 }.await;
+            Ok(RustBuffer::from_vec(vec![v as u8]))
+        });
+        let ptr = future.into_raw();
+        unsafe {
+            let future = RustFuture::borrow_raw(ptr);
+            assert!(!future.poll(test_waker, 7));
+            assert_eq!(WOKEN.load(Ordering::SeqCst), 7);
+            assert!(future.poll(test_waker, 8));
+            // Polling a ready future is harmless.
+            assert!(future.poll(test_waker, 9));
+            assert_eq!(WOKEN.load(Ordering::SeqCst), 7);
+            let result = future.complete().ok().unwrap();
+            assert_eq!(result.destroy_into_vec(), vec![42]);
+            RustFuture::drop_raw(ptr);
+        }
+    }
+
+    #[test]
+    fn test_complete_error() {
+        let future = RustFuture::new(async { Err(RustBuffer::from_vec(vec![1, 2, 3])) });
+        assert!(future.poll(test_waker, 0));
+        let err = future.complete().err().unwrap();
+        assert_eq!(err.destroy_into_vec(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    #[should_panic(expected = "RustFuture completed before it was ready")]
+    fn test_complete_before_ready() {
+        let future = RustFuture::new(async {
+            CountdownFuture { count: 1 }.await;
+            Ok(RustBuffer::new())
+        });
+        let _ = future.complete();
+    }
+}
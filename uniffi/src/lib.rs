@@ -32,8 +32,9 @@ use anyhow::bail;
 use bytes::buf::{Buf, BufMut};
 use paste::paste;
 use std::{
-    collections::HashMap,
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet},
     convert::TryFrom,
+    hash::Hash,
     time::{Duration, SystemTime},
 };
 
@@ -43,6 +44,10 @@ pub use anyhow::Result;
 pub mod ffi;
 pub use ffi::*;
 
+// The attributes for defining a component's interface on Rust items,
+// so they can be used as e.g. `#[uniffi::export]`.
+pub use uniffi_macros::{export, object, record};
+
 // It would be nice if this module was behind a cfg(test) guard, but it
 // doesn't work between crates so let's hope LLVM tree-shaking works well.
 pub mod testing;
@@ -115,6 +120,28 @@ pub const fn check_compatible_version(bindgen_version: &'static str) -> bool {
         && package_version.len() < 10
 }
 
+/// Check whether two strings are equal, in a const context.
+///
+/// The scaffolding uses this to check that each constant declared in the UDL has the same
+/// value as the Rust `const` of the same name. Like `check_compatible_version` it compares
+/// the strings byte-by-byte, since the `&str` equals method can't be called in a const context.
+#[doc(hidden)]
+pub const fn const_str_eq(a: &str, b: &str) -> bool {
+    let a = a.as_bytes();
+    let b = b.as_bytes();
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
 /// Assert that the uniffi runtime version matches an expected value.
 ///
 /// This is a helper hook for the generated Rust scaffolding, to produce a compile-time
@@ -266,6 +293,33 @@ impl_via_ffi_for_num_primitive! {
     i8, u8, i16, u16, i32, u32, i64, u64, f32, f64
 }
 
+/// Support for passing 128-bit integers via the FFI.
+///
+/// There's no portable C-compatible type for these, so they're passed by serializing
+/// to a buffer as 16 big-endian bytes.
+macro_rules! impl_via_rustbuffer_for_128_bit_int {
+    ($($T:ty),*) => {
+            $(
+                paste! {
+                    impl RustBufferFfiConverter for $T {
+                        type RustType = Self;
+
+                        fn write(obj: Self::RustType, buf: &mut Vec<u8>) {
+                            buf.[<put_ $T>](obj);
+                        }
+
+                        fn try_read(buf: &mut &[u8]) -> Result<Self> {
+                            check_remaining(buf, 16)?;
+                            Ok(buf.[<get_ $T>]())
+                        }
+                    }
+                }
+            )*
+    };
+}
+
+impl_via_rustbuffer_for_128_bit_int! { i128, u128 }
+
 /// Support for passing boolean values via the FFI.
 ///
 /// Booleans are passed as an `i8` in order to avoid problems with handling
@@ -356,6 +410,45 @@ unsafe impl FfiConverter for String {
     }
 }
 
+/// Support for passing byte strings via the FFI, as the `bytes` type.
+///
+/// `Vec<u8>` already implements `FfiConverter` as a sequence of integers, so this is
+/// implemented on a separate unit struct. It works like the implementation for `String`:
+/// the `RustBuffer` holds the raw bytes, which are taken over without copying, and the
+/// foreign-language code can create one directly from its own memory via `ForeignBytes`.
+///
+/// When serialized in a buffer, bytes are represented as a i32 length followed by the bytes.
+pub struct FfiConverterBytes;
+
+unsafe impl FfiConverter for FfiConverterBytes {
+    type RustType = Vec<u8>;
+    type FfiType = RustBuffer;
+
+    fn lower(obj: Self::RustType) -> Self::FfiType {
+        RustBuffer::from_vec(obj)
+    }
+
+    fn try_lift(v: Self::FfiType) -> Result<Self::RustType> {
+        Ok(v.destroy_into_vec())
+    }
+
+    fn write(obj: Self::RustType, buf: &mut Vec<u8>) {
+        // TODO: it would be nice not to panic here.
+        let len = i32::try_from(obj.len()).unwrap();
+        buf.put_i32(len); // We limit byte strings to i32::MAX bytes
+        buf.put(obj.as_slice());
+    }
+
+    fn try_read(buf: &mut &[u8]) -> Result<Self::RustType> {
+        check_remaining(buf, 4)?;
+        let len = usize::try_from(buf.get_i32())?;
+        check_remaining(buf, len)?;
+        let bytes = buf.chunk()[..len].to_vec();
+        buf.advance(len);
+        Ok(bytes)
+    }
+}
+
 /// A helper trait to implement lowering/lifting using a `RustBuffer`
 ///
 /// For complex types where it's too fiddly or too unsafe to convert them into a special-purpose
@@ -531,22 +624,23 @@ impl<T: FfiConverter> RustBufferFfiConverter for Vec<T> {
 }
 
 /// Support for associative arrays via the FFI.
-/// Note that because of webidl limitations,
-/// the key must always be of the String type.
 ///
 /// HashMaps are currently always passed by serializing to a buffer.
-/// We write a `i32` entries count followed by each entry (string
-/// key followed by the value) in turn.
+/// We write a `i32` entries count followed by each entry (key
+/// followed by the value) in turn.
 /// (It's a signed type due to limits of the JVM).
-impl<V: FfiConverter> RustBufferFfiConverter for HashMap<String, V> {
-    type RustType = HashMap<String, V::RustType>;
+impl<K: FfiConverter, V: FfiConverter> RustBufferFfiConverter for HashMap<K, V>
+where
+    K::RustType: Hash + Eq,
+{
+    type RustType = HashMap<K::RustType, V::RustType>;
 
     fn write(obj: Self::RustType, buf: &mut Vec<u8>) {
         // TODO: would be nice not to panic here :-/
         let len = i32::try_from(obj.len()).unwrap();
         buf.put_i32(len); // We limit HashMaps to i32::MAX entries
         for (key, value) in obj.into_iter() {
-            <String as FfiConverter>::write(key, buf);
+            <K as FfiConverter>::write(key, buf);
             <V as FfiConverter>::write(value, buf);
         }
     }
@@ -556,7 +650,100 @@ impl<V: FfiConverter> RustBufferFfiConverter for HashMap<String, V> {
         let len = usize::try_from(buf.get_i32())?;
         let mut map = HashMap::with_capacity(len);
         for _ in 0..len {
-            let key = String::try_read(buf)?;
+            let key = <K as FfiConverter>::try_read(buf)?;
+            let value = <V as FfiConverter>::try_read(buf)?;
+            map.insert(key, value);
+        }
+        Ok(map)
+    }
+}
+
+/// Support for sets via the FFI.
+///
+/// Sets are serialized in the same way as sequences, with an `i32` count followed by
+/// each element in turn.
+impl<T: FfiConverter> RustBufferFfiConverter for HashSet<T>
+where
+    T::RustType: Hash + Eq,
+{
+    type RustType = HashSet<T::RustType>;
+
+    fn write(obj: Self::RustType, buf: &mut Vec<u8>) {
+        // TODO: would be nice not to panic here :-/
+        let len = i32::try_from(obj.len()).unwrap();
+        buf.put_i32(len); // We limit HashSets to i32::MAX elements
+        for item in obj.into_iter() {
+            <T as FfiConverter>::write(item, buf);
+        }
+    }
+
+    fn try_read(buf: &mut &[u8]) -> Result<Self::RustType> {
+        check_remaining(buf, 4)?;
+        let len = usize::try_from(buf.get_i32())?;
+        let mut set = HashSet::with_capacity(len);
+        for _ in 0..len {
+            set.insert(<T as FfiConverter>::try_read(buf)?);
+        }
+        Ok(set)
+    }
+}
+
+/// Support for ordered sets via the FFI.
+///
+/// These look just like a `HashSet` on the wire, but the elements are always
+/// written in order, so the foreign-language code sees them in a deterministic order.
+impl<T: FfiConverter> RustBufferFfiConverter for BTreeSet<T>
+where
+    T::RustType: Ord,
+{
+    type RustType = BTreeSet<T::RustType>;
+
+    fn write(obj: Self::RustType, buf: &mut Vec<u8>) {
+        // TODO: would be nice not to panic here :-/
+        let len = i32::try_from(obj.len()).unwrap();
+        buf.put_i32(len); // We limit BTreeSets to i32::MAX elements
+        for item in obj.into_iter() {
+            <T as FfiConverter>::write(item, buf);
+        }
+    }
+
+    fn try_read(buf: &mut &[u8]) -> Result<Self::RustType> {
+        check_remaining(buf, 4)?;
+        let len = usize::try_from(buf.get_i32())?;
+        let mut set = BTreeSet::new();
+        for _ in 0..len {
+            set.insert(<T as FfiConverter>::try_read(buf)?);
+        }
+        Ok(set)
+    }
+}
+
+/// Support for ordered associative arrays via the FFI.
+///
+/// These look just like a `HashMap` on the wire, but the entries are always
+/// written in key order, so the foreign-language code sees them in a deterministic order.
+impl<K: FfiConverter, V: FfiConverter> RustBufferFfiConverter for BTreeMap<K, V>
+where
+    K::RustType: Ord,
+{
+    type RustType = BTreeMap<K::RustType, V::RustType>;
+
+    fn write(obj: Self::RustType, buf: &mut Vec<u8>) {
+        // TODO: would be nice not to panic here :-/
+        let len = i32::try_from(obj.len()).unwrap();
+        buf.put_i32(len); // We limit BTreeMaps to i32::MAX entries
+        for (key, value) in obj.into_iter() {
+            <K as FfiConverter>::write(key, buf);
+            <V as FfiConverter>::write(value, buf);
+        }
+    }
+
+    fn try_read(buf: &mut &[u8]) -> Result<Self::RustType> {
+        check_remaining(buf, 4)?;
+        let len = usize::try_from(buf.get_i32())?;
+        let mut map = BTreeMap::new();
+        for _ in 0..len {
+            let key = <K as FfiConverter>::try_read(buf)?;
             let value = <V as FfiConverter>::try_read(buf)?;
             map.insert(key, value);
         }
@@ -564,6 +751,49 @@ impl<V: FfiConverter> RustBufferFfiConverter for HashMap<String, V> {
     }
 }
 
+/// Support for boxed values via the FFI.
+///
+/// Records and enums that contain themselves need a `Box` to have a finite size in Rust,
+/// but the box is invisible to the foreign-language code; it's serialized exactly like
+/// the value it contains.
+impl<T: FfiConverter> RustBufferFfiConverter for Box<T> {
+    type RustType = Box<T::RustType>;
+
+    fn write(obj: Self::RustType, buf: &mut Vec<u8>) {
+        <T as FfiConverter>::write(*obj, buf);
+    }
+
+    fn try_read(buf: &mut &[u8]) -> Result<Self::RustType> {
+        Ok(Box::new(<T as FfiConverter>::try_read(buf)?))
+    }
+}
+
+/// Support for passing tuples via the FFI.
+///
+/// Tuples are serialized as each of their elements in turn, with no header since
+/// the number of elements is known from the type. The UDL allows tuples of two or
+/// three elements, which is all we implement this for.
+macro_rules! impl_via_rustbuffer_for_tuple {
+    ($($T:ident),+) => {
+        impl<$($T: FfiConverter),+> RustBufferFfiConverter for ($($T,)+) {
+            type RustType = ($($T::RustType,)+);
+
+            #[allow(non_snake_case)]
+            fn write(obj: Self::RustType, buf: &mut Vec<u8>) {
+                let ($($T,)+) = obj;
+                $(<$T as FfiConverter>::write($T, buf);)+
+            }
+
+            fn try_read(buf: &mut &[u8]) -> Result<Self::RustType> {
+                Ok(($(<$T as FfiConverter>::try_read(buf)?,)+))
+            }
+        }
+    };
+}
+
+impl_via_rustbuffer_for_tuple! { A, B }
+impl_via_rustbuffer_for_tuple! { A, B, C }
+
 /// Support for passing reference-counted shared objects via the FFI.
 ///
 /// To avoid dealing with complex lifetime semantics over the FFI, any data passed
@@ -651,4 +881,67 @@ mod test {
             "Expected results after lowering and lifting to be equal"
         )
     }
+
+    #[test]
+    fn u128_roundtrip() {
+        let expected = u128::MAX - 1;
+        let result = u128::try_lift(u128::lower(expected)).expect("Failed to lift!");
+        assert_eq!(expected, result);
+        let mut buf = Vec::new();
+        <i128 as FfiConverter>::write(-2, &mut buf);
+        assert_eq!(buf.len(), 16);
+        assert_eq!(buf[0], 0xff);
+        assert_eq!(buf[15], 0xfe);
+    }
+
+    #[test]
+    fn tuple_roundtrip() {
+        let expected = (String::from("a"), 1u8, Some(true));
+        type T = (String, u8, Option<bool>);
+        let result = T::try_lift(T::lower(expected.clone())).expect("Failed to lift!");
+        assert_eq!(expected, result);
+        let mut buf = Vec::new();
+        <(u8, bool) as FfiConverter>::write((7, false), &mut buf);
+        assert_eq!(buf, vec![7, 0]);
+    }
+
+    #[test]
+    fn set_and_ordered_map_roundtrip() {
+        let expected: HashSet<String> = ["a", "b"].iter().map(|s| s.to_string()).collect();
+        let result = HashSet::<String>::try_lift(HashSet::<String>::lower(expected.clone()))
+            .expect("Failed to lift!");
+        assert_eq!(expected, result);
+        let mut buf = Vec::new();
+        <BTreeSet<u8> as FfiConverter>::write([3, 1, 2].iter().copied().collect(), &mut buf);
+        assert_eq!(buf, vec![0, 0, 0, 3, 1, 2, 3]);
+        let mut buf = Vec::new();
+        <BTreeMap<u8, bool> as FfiConverter>::write(
+            [(2, true), (1, false)].iter().copied().collect(),
+            &mut buf,
+        );
+        assert_eq!(buf, vec![0, 0, 0, 2, 1, 0, 2, 1]);
+        let mut slice = &buf[..];
+        let result =
+            <BTreeMap<u8, bool> as FfiConverter>::try_read(&mut slice).expect("Failed to read!");
+        assert_eq!(
+            result.into_iter().collect::<Vec<_>>(),
+            vec![(1, false), (2, true)]
+        );
+        assert!(slice.is_empty());
+    }
+
+    #[test]
+    fn bytes_roundtrip() {
+        let expected = vec![0, 1, 2, 255];
+        let result = FfiConverterBytes::try_lift(FfiConverterBytes::lower(expected.clone()))
+            .expect("Failed to lift!");
+        assert_eq!(expected, result);
+        let mut buf = Vec::new();
+        FfiConverterBytes::write(expected.clone(), &mut buf);
+        assert_eq!(buf, vec![0, 0, 0, 4, 0, 1, 2, 255]);
+        let mut slice = &buf[..];
+        let result = FfiConverterBytes::try_read(&mut slice).expect("Failed to read!");
+        assert_eq!(expected, result);
+        assert!(slice.is_empty());
+    }
 }
@@ -6,10 +6,49 @@ use anyhow::Result;
 use askama::Template;
 use heck::{CamelCase, MixedCase, ShoutySnakeCase};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
+use crate::bindings::WrappedTypeConfig;
 use crate::interface::*;
 use crate::MergeWith;
 
+// Kotlin's hard keywords, which need to be escaped if they're used as identifiers.
+// https://kotlinlang.org/docs/keyword-reference.html#hard-keywords
+const RESERVED_WORDS: &[&str] = &[
+    "as",
+    "break",
+    "class",
+    "continue",
+    "do",
+    "else",
+    "false",
+    "for",
+    "fun",
+    "if",
+    "in",
+    "interface",
+    "is",
+    "null",
+    "object",
+    "package",
+    "return",
+    "super",
+    "this",
+    "throw",
+    "true",
+    "try",
+    "typealias",
+    "typeof",
+    "val",
+    "var",
+    "when",
+    "while",
+];
+
+fn is_reserved_word(word: &str) -> bool {
+    RESERVED_WORDS.contains(&word)
+}
+
 // Some config options for it the caller wants to customize the generated Kotlin.
 // Note that this can only be used to control details of the Kotlin *that do not affect the underlying component*,
 // sine the details of the underlying component are entirely determined by the `ComponentInterface`.
@@ -17,6 +56,8 @@ use crate::MergeWith;
 pub struct Config {
     package_name: Option<String>,
     cdylib_name: Option<String>,
+    external_packages: Option<HashMap<String, String>>,
+    wrapped_types: Option<HashMap<String, WrappedTypeConfig>>,
 }
 
 impl Config {
@@ -35,6 +76,40 @@ impl Config {
             "uniffi".into()
         }
     }
+
+    /// The Kotlin package holding the bindings for another crate, from which `[External]`
+    /// types are imported. Defaults to the package that crate would get if unconfigured.
+    pub fn external_package_name(&self, crate_name: &str) -> String {
+        match self
+            .external_packages
+            .as_ref()
+            .and_then(|packages| packages.get(crate_name))
+        {
+            Some(package_name) => package_name.clone(),
+            None => format!("uniffi.{}", crate_name.replace('-', "_")),
+        }
+    }
+
+    /// How the named `[Wrapped]` type is represented in Kotlin.
+    pub fn wrapped_type(&self, name: &str) -> WrappedTypeConfig {
+        self.wrapped_types
+            .as_ref()
+            .and_then(|types| types.get(name))
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// The imports needed by the `[Wrapped]` types in the given interface.
+    pub fn wrapped_type_imports(&self, ci: &ComponentInterface) -> Vec<String> {
+        let mut imports = ci
+            .iter_wrapped_types()
+            .into_iter()
+            .flat_map(|(name, _)| self.wrapped_type(&name).imports())
+            .collect::<Vec<_>>();
+        imports.sort();
+        imports.dedup();
+        imports
+    }
 }
 
 impl From<&ComponentInterface> for Config {
@@ -42,6 +117,8 @@ impl From<&ComponentInterface> for Config {
         Config {
             package_name: Some(format!("uniffi.{}", ci.namespace())),
             cdylib_name: Some(format!("uniffi_{}", ci.namespace())),
+            external_packages: None,
+            wrapped_types: None,
         }
     }
 }
@@ -51,6 +128,8 @@ impl MergeWith for Config {
         Config {
             package_name: self.package_name.merge_with(&other.package_name),
             cdylib_name: self.cdylib_name.merge_with(&other.cdylib_name),
+            external_packages: self.external_packages.merge_with(&other.external_packages),
+            wrapped_types: self.wrapped_types.merge_with(&other.wrapped_types),
         }
     }
 }
@@ -86,20 +165,41 @@ mod filters {
             Type::Float32 => "Float".to_string(),
             Type::Float64 => "Double".to_string(),
             // These types need conversion, and special handling for lifting/lowering.
+            Type::UInt128 | Type::Int128 => "java.math.BigInteger".to_string(),
             Type::Boolean => "Boolean".to_string(),
             Type::String => "String".to_string(),
+            Type::Bytes => "ByteArray".to_string(),
             Type::Timestamp => "java.time.Instant".to_string(),
             Type::Duration => "java.time.Duration".to_string(),
             Type::Enum(name)
             | Type::Record(name)
             | Type::Object(name)
             | Type::Error(name)
-            | Type::CallbackInterface(name) => class_name_kt(name)?,
+            | Type::CallbackInterface(name)
+            | Type::Flags { name, .. }
+            // External types are imported by name from the other crate's package,
+            // and wrapped types are a `typealias` for their primitive or configured type.
+            | Type::External { name, .. }
+            | Type::Wrapped { name, .. } => class_name_kt(name)?,
             Type::Optional(t) => format!("{}?", type_kt(t)?),
             Type::Sequence(t) => format!("List<{}>", type_kt(t)?),
-            Type::Map(t) => format!("Map<String, {}>", type_kt(t)?),
-            Type::External { .. } => panic!("no support for external types yet"),
-            Type::Wrapped { .. } => panic!("no support for wrapped types yet"),
+            Type::Map(k, v) => format!("Map<{}, {}>", type_kt(k)?, type_kt(v)?),
+            Type::Set(t) => format!("Set<{}>", type_kt(t)?),
+            Type::OrderedMap(k, v) => {
+                format!("java.util.SortedMap<{}, {}>", type_kt(k)?, type_kt(v)?)
+            }
+            Type::Tuple(ts) => format!(
+                "{}<{}>",
+                match ts.len() {
+                    2 => "Pair",
+                    3 => "Triple",
+                    _ => panic!("tuples must have two or three elements"),
+                },
+                ts.iter()
+                    .map(type_kt)
+                    .collect::<Result<Vec<_>, _>>()?
+                    .join(", ")
+            ),
         })
     }
 
@@ -119,9 +219,15 @@ mod filters {
             FFIType::RustBuffer => "RustBuffer.ByValue".to_string(),
             FFIType::ForeignBytes => "ForeignBytes.ByValue".to_string(),
             FFIType::ForeignCallback => "ForeignCallback".to_string(),
+            FFIType::ForeignWaker => "ForeignWaker".to_string(),
         })
     }
 
+    /// Get the Kotlin syntax for the low-level type that a given api-level `Type` is passed as.
+    pub fn ffi_type_kt(type_: &Type) -> Result<String, askama::Error> {
+        type_ffi(&FFIType::from(type_))
+    }
+
     pub fn literal_kt(literal: &Literal) -> Result<String, askama::Error> {
         fn typed_number(type_: &Type, num_str: String) -> Result<String, askama::Error> {
             Ok(match type_ {
@@ -177,7 +283,12 @@ mod filters {
 
     /// Get the idiomatic Kotlin rendering of a variable name.
     pub fn var_name_kt(nm: &dyn fmt::Display) -> Result<String, askama::Error> {
-        Ok(nm.to_string().to_mixed_case())
+        let nm = nm.to_string().to_mixed_case();
+        if is_reserved_word(&nm) {
+            Ok(format!("`{}`", nm))
+        } else {
+            Ok(nm)
+        }
     }
 
     /// Get the idiomatic Kotlin rendering of an individual enum variant.
@@ -185,6 +296,28 @@ mod filters {
         Ok(nm.to_string().to_shouty_snake_case())
     }
 
+    /// Get the idiomatic Kotlin rendering of a constant name.
+    pub fn const_name_kt(nm: &dyn fmt::Display) -> Result<String, askama::Error> {
+        Ok(nm.to_string().to_shouty_snake_case())
+    }
+
+    /// Render a docstring as a KDoc comment.
+    ///
+    /// The first line is indented by the template, and the rest by the given number of spaces,
+    /// including the end of the output so that the documented item follows on its own line.
+    pub fn docstring_kt(docstring: &str, spaces: &usize) -> Result<String, askama::Error> {
+        let indent = " ".repeat(*spaces);
+        let lines = docstring
+            .lines()
+            .map(|line| match line {
+                "" => format!("{} *", indent),
+                // Don't let the docstring end the comment early.
+                _ => format!("{} * {}", indent, line.replace("*/", "*&#47;")),
+            })
+            .collect::<Vec<_>>();
+        Ok(format!("/**\n{1}\n{0} */\n{0}", indent, lines.join("\n")))
+    }
+
     /// Get the idiomatic Kotlin rendering of an exception name
     ///
     /// This replaces "Error" at the end of the name with "Exception".  Rust code typically uses
@@ -216,9 +349,17 @@ mod filters {
             ),
             Type::Optional(_)
             | Type::Sequence(_)
-            | Type::Map(_)
+            | Type::Map(..)
+            | Type::Set(_)
+            | Type::OrderedMap(..)
+            | Type::Tuple(_)
+            | Type::Bytes
+            | Type::UInt128
+            | Type::Int128
             | Type::Timestamp
-            | Type::Duration => {
+            | Type::Duration
+            | Type::External { .. }
+            | Type::Wrapped { .. } => {
                 format!("lower{}({})", class_name_kt(&type_.canonical_name())?, nm,)
             }
             _ => format!("{}.lower()", nm),
@@ -244,9 +385,17 @@ mod filters {
             ),
             Type::Optional(_)
             | Type::Sequence(_)
-            | Type::Map(_)
+            | Type::Map(..)
+            | Type::Set(_)
+            | Type::OrderedMap(..)
+            | Type::Tuple(_)
+            | Type::Bytes
+            | Type::UInt128
+            | Type::Int128
             | Type::Timestamp
-            | Type::Duration => format!(
+            | Type::Duration
+            | Type::External { .. }
+            | Type::Wrapped { .. } => format!(
                 "write{}({}, {})",
                 class_name_kt(&type_.canonical_name())?,
                 nm,
@@ -270,9 +419,19 @@ mod filters {
             ),
             Type::Optional(_)
             | Type::Sequence(_)
-            | Type::Map(_)
+            | Type::Map(..)
+            | Type::Set(_)
+            | Type::OrderedMap(..)
+            | Type::Tuple(_)
+            | Type::Bytes
+            | Type::UInt128
+            | Type::Int128
             | Type::Timestamp
-            | Type::Duration => format!("lift{}({})", class_name_kt(&type_.canonical_name())?, nm),
+            | Type::Duration
+            | Type::External { .. }
+            | Type::Wrapped { .. } => {
+                format!("lift{}({})", class_name_kt(&type_.canonical_name())?, nm)
+            }
             _ => format!("{}.lift({})", type_kt(type_)?, nm),
         })
     }
@@ -291,9 +450,19 @@ mod filters {
             ),
             Type::Optional(_)
             | Type::Sequence(_)
-            | Type::Map(_)
+            | Type::Map(..)
+            | Type::Set(_)
+            | Type::OrderedMap(..)
+            | Type::Tuple(_)
+            | Type::Bytes
+            | Type::UInt128
+            | Type::Int128
             | Type::Timestamp
-            | Type::Duration => format!("read{}({})", class_name_kt(&type_.canonical_name())?, nm),
+            | Type::Duration
+            | Type::External { .. }
+            | Type::Wrapped { .. } => {
+                format!("read{}({})", class_name_kt(&type_.canonical_name())?, nm)
+            }
             _ => format!("{}.read({})", type_kt(type_)?, nm),
         })
     }
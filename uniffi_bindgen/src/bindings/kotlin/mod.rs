@@ -67,9 +67,10 @@ pub fn compile_bindings(config: &Config, ci: &ComponentInterface, out_dir: &Path
     let status = Command::new("kotlinc")
         // Our generated bindings should not produce any warnings; fail tests if they do.
         .arg("-Werror")
-        // Reflect $CLASSPATH from the environment, to help find `jna.jar`.
+        // Include the bindings for any other components we've already compiled, so that
+        // their types can be used as `[External]` types.
         .arg("-classpath")
-        .arg(env::var("CLASSPATH").unwrap_or_else(|_| "".to_string()))
+        .arg(classpath_for_testing(out_dir)?)
         .arg(&kt_file)
         .arg("-d")
         .arg(jar_file)
@@ -86,26 +87,9 @@ pub fn compile_bindings(config: &Config, ci: &ComponentInterface, out_dir: &Path
 /// Execute the specifed kotlin script, with classpath based on the generated
 // artifacts in the given output directory.
 pub fn run_script(out_dir: &Path, script_file: &Path) -> Result<()> {
-    let mut classpath = env::var_os("CLASSPATH").unwrap_or_else(|| OsString::from(""));
-    // This lets java find the compiled library for the rust component.
-    classpath.push(":");
-    classpath.push(out_dir);
-    // This lets java use any generate .jar files containing bindings for the rust component.
-    for entry in PathBuf::from(out_dir)
-        .read_dir()
-        .context("Failed to list target directory when running Kotlin script")?
-    {
-        let entry = entry.context("Directory listing failed while running Kotlin script")?;
-        if let Some(ext) = entry.path().extension() {
-            if ext == "jar" {
-                classpath.push(":");
-                classpath.push(entry.path());
-            }
-        }
-    }
     let mut cmd = Command::new("kotlinc");
     // Make sure it can load the .jar and its dependencies.
-    cmd.arg("-classpath").arg(classpath);
+    cmd.arg("-classpath").arg(classpath_for_testing(out_dir)?);
     // Code that wants to use an API with unsigned types, must opt in to this experimental Kotlin feature.
     // Specify it here in order to not have to worry about that when writing tests.
     cmd.arg("-Xopt-in=kotlin.ExperimentalUnsignedTypes");
@@ -124,3 +108,26 @@ pub fn run_script(out_dir: &Path, script_file: &Path) -> Result<()> {
     }
     Ok(())
 }
+
+/// Build a classpath based on $CLASSPATH from the environment (to help find `jna.jar`),
+/// plus the generated artifacts in the given output directory.
+fn classpath_for_testing(out_dir: &Path) -> Result<OsString> {
+    let mut classpath = env::var_os("CLASSPATH").unwrap_or_else(|| OsString::from(""));
+    // This lets java find the compiled library for the rust component.
+    classpath.push(":");
+    classpath.push(out_dir);
+    // This lets java use any generate .jar files containing bindings for the rust component.
+    for entry in PathBuf::from(out_dir)
+        .read_dir()
+        .context("Failed to list target directory when building Kotlin classpath")?
+    {
+        let entry = entry.context("Directory listing failed while building Kotlin classpath")?;
+        if let Some(ext) = entry.path().extension() {
+            if ext == "jar" {
+                classpath.push(":");
+                classpath.push(entry.path());
+            }
+        }
+    }
+    Ok(classpath)
+}
@@ -1,7 +1,7 @@
 {% let type_name = cbi.name()|class_name_kt %}
-public interface {{ type_name }} {
+{% call kt::docstring(cbi, 0) %}public interface {{ type_name }} {
     {% for meth in cbi.methods() -%}
-    fun {{ meth.name()|fn_name_kt }}({% call kt::arg_list_decl(meth) %})
+    {% call kt::docstring(meth, 4) %}{% call kt::deprecated(meth) %}fun {{ meth.name()|fn_name_kt }}({% call kt::arg_list_decl(meth) %})
     {%- match meth.return_type() -%}
     {%- when Some with (return_type) %}: {{ return_type|type_kt -}}
     {%- else -%}
@@ -15,56 +15,86 @@ public interface {{ type_name }} {
 
 internal class {{ callback_interface_impl }} : ForeignCallback {
     @Suppress("TooGenericExceptionCaught")
-    override fun invoke(handle: Long, method: Int, args: RustBuffer.ByValue): RustBuffer.ByValue {
-        return {{ callback_internals }}.handleMap.callWithResult(handle) { cb -> 
-            when (method) {
-                IDX_CALLBACK_FREE -> {{ callback_internals }}.drop(handle)
-                {% for meth in cbi.methods() -%}
-                {% let method_name = format!("invoke_{}", meth.name())|fn_name_kt -%}
-                {{ loop.index }} -> this.{{ method_name }}(cb, args)
-                {% endfor %}
-                // This should never happen, because an out of bounds method index won't
-                // ever be used. Once we can catch errors, we should return an InternalException.
-                // https://github.com/mozilla/uniffi-rs/issues/351
-                else -> RustBuffer.ByValue()
+    override fun invoke(handle: Long, method: Int, args: RustBuffer.ByValue, outBuf: RustBufferByReference): Int {
+        if (method == IDX_CALLBACK_FREE) {
+            {{ callback_internals }}.drop(handle)
+            return UNIFFI_CALLBACK_SUCCESS
+        }
+        return try {
+            {{ callback_internals }}.handleMap.callWithResult(handle) { cb ->
+                when (method) {
+                    {% for meth in cbi.methods() -%}
+                    {% let method_name = format!("invoke_{}", meth.name())|fn_name_kt -%}
+                    {{ loop.index }} -> this.{{ method_name }}(cb, args, outBuf)
+                    {% endfor %}
+                    // This should never happen, because an out of bounds method index won't
+                    // ever be used.
+                    else -> throw InternalException("Invalid callback method index: $method")
+                }
             }
+        } catch (e: Throwable) {
+            // Anything we didn't expect is reported to Rust as an unexpected error, along with
+            // a description of what went wrong, if we're able to provide one.
+            try {
+                outBuf.setValue(e.toString().lower())
+            } catch (e: Throwable) {
+                // If that fails, Rust will report an unknown reason.
+            }
+            UNIFFI_CALLBACK_UNEXPECTED_ERROR
         }
     }
 
     {% for meth in cbi.methods() -%}
     {% let method_name = format!("invoke_{}", meth.name())|fn_name_kt %}
-    private fun {{ method_name }}(kotlinCallbackInterface: {{ type_name }}, args: RustBuffer.ByValue): RustBuffer.ByValue =
-        try {
-        {#- Unpacking args from the RustBuffer #}
-            {%- if meth.arguments().len() != 0 -%}
-            {#- Calling the concrete callback object #}
-            val buf = args.asByteBuffer() ?: throw InternalException("No ByteBuffer in RustBuffer; this is a Uniffi bug")
-            kotlinCallbackInterface.{{ meth.name()|fn_name_kt }}(
-                    {% for arg in meth.arguments() -%}
-                    {{ "buf"|read_kt(arg.type_()) }}
-                    {%- if !loop.last %}, {% endif %}
-                    {% endfor -%}
-                )
-            {% else %}
-            kotlinCallbackInterface.{{ meth.name()|fn_name_kt }}()
-            {% endif -%}
+    private fun {{ method_name }}(kotlinCallbackInterface: {{ type_name }}, args: RustBuffer.ByValue, outBuf: RustBufferByReference): Int {
+        fun makeCall(): RustBuffer.ByValue =
+            try {
+            {#- Unpacking args from the RustBuffer #}
+                {%- if meth.arguments().len() != 0 -%}
+                {#- Calling the concrete callback object #}
+                val buf = args.asByteBuffer() ?: throw InternalException("No ByteBuffer in RustBuffer; this is a Uniffi bug")
+                kotlinCallbackInterface.{{ meth.name()|fn_name_kt }}(
+                        {% for arg in meth.arguments() -%}
+                        {{ "buf"|read_kt(arg.type_()) }}
+                        {%- if !loop.last %}, {% endif %}
+                        {% endfor -%}
+                    )
+                {% else %}
+                kotlinCallbackInterface.{{ meth.name()|fn_name_kt }}()
+                {% endif -%}
 
-        {#- Packing up the return value into a RustBuffer #}
-                {%- match meth.return_type() -%}
-                {%- when Some with (return_type) -%}
-                .let { rval -> 
-                    val rbuf = RustBufferBuilder()
-                    {{ "rval"|write_kt("rbuf", return_type) }} 
-                    rbuf.finalize()
-                }
-                {%- else -%}
-                .let { RustBuffer.ByValue() }
-                {% endmatch -%}
-                // TODO catch errors and report them back to Rust. 
-                // https://github.com/mozilla/uniffi-rs/issues/351
-        } finally {
-            RustBuffer.free(args)
+            {#- Packing up the return value into a RustBuffer #}
+                    {%- match meth.return_type() -%}
+                    {%- when Some with (return_type) -%}
+                    .let { rval ->
+                        val rbuf = RustBufferBuilder()
+                        {{ "rval"|write_kt("rbuf", return_type) }}
+                        rbuf.finalize()
+                    }
+                    {%- else -%}
+                    .let { RustBuffer.ByValue() }
+                    {% endmatch -%}
+            } finally {
+                RustBuffer.free(args)
+            }
+
+        {% match meth.throws() -%}
+        {% when Some with (error_name) -%}
+        return try {
+            outBuf.setValue(makeCall())
+            UNIFFI_CALLBACK_SUCCESS
+        } catch (e: {{ error_name|exception_name_kt }}) {
+            // The declared error is serialized and sent back to Rust.
+            val rbuf = RustBufferBuilder()
+            {{ error_name|exception_name_kt }}.write(e, rbuf)
+            outBuf.setValue(rbuf.finalize())
+            UNIFFI_CALLBACK_ERROR
         }
+        {%- else %}
+        outBuf.setValue(makeCall())
+        return UNIFFI_CALLBACK_SUCCESS
+        {%- endmatch %}
+    }
 
     {% endfor %}
 }
@@ -7,21 +7,33 @@
 
 {% if e.is_flat() %}
 
-enum class {{ e.name()|class_name_kt }} {
+{#
+// Each variant has the `value` that it's passed to Rust as. Non-exhaustive enums also have
+// an `UNKNOWN` variant for values added by a newer version of the library, with a null `value`.
+#}
+{% call kt::docstring(e, 0) %}enum class {{ e.name()|class_name_kt }}(val value: Int{% if e.is_non_exhaustive() %}?{% endif %}) {
     {% for variant in e.variants() -%}
-    {{ variant.name()|enum_variant_kt }}{% if loop.last %};{% else %},{% endif %}
+    {% call kt::docstring(variant, 4) %}{% call kt::deprecated(variant) %}{{ variant.name()|enum_variant_kt }}({{ variant.discr() }}){% if loop.last && !e.is_non_exhaustive() %};{% else %},{% endif %}
     {%- endfor %}
+    {%- if e.is_non_exhaustive() %}
+    /**
+     * A variant that this version of the bindings doesn't know about.
+     */
+    UNKNOWN(null);
+    {%- endif %}
 
     companion object {
         internal fun lift(rbuf: RustBuffer.ByValue): {{ e.name()|class_name_kt }} {
             return liftFromRustBuffer(rbuf) { buf -> {{ e.name()|class_name_kt }}.read(buf) }
         }
 
-        internal fun read(buf: ByteBuffer) =
-            try { values()[buf.getInt() - 1] }
-            catch (e: IndexOutOfBoundsException) {
-                throw RuntimeException("invalid enum value, something is very wrong!!", e)
-            }
+        internal fun read(buf: ByteBuffer): {{ e.name()|class_name_kt }} {
+            val value = buf.getInt()
+            return values().firstOrNull { it.value == value }
+                {%- if e.is_non_exhaustive() %} ?: UNKNOWN
+                {%- else %} ?: throw RuntimeException("invalid enum value, something is very wrong!!")
+                {%- endif %}
+        }
     }
 
     internal fun lower(): RustBuffer.ByValue {
@@ -29,19 +41,23 @@ enum class {{ e.name()|class_name_kt }} {
     }
 
     internal fun write(buf: RustBufferBuilder) {
-        buf.putInt(this.ordinal + 1)
+        {%- if e.is_non_exhaustive() %}
+        buf.putInt(this.value ?: throw IllegalArgumentException("{{ e.name()|class_name_kt }}.UNKNOWN can't be passed to Rust"))
+        {%- else %}
+        buf.putInt(this.value)
+        {%- endif %}
     }
 }
 
 {% else %}
 
-{% call kt::unsigned_types_annotation(e) %}
+{% call kt::docstring(e, 0) %}{% call kt::unsigned_types_annotation(e) %}
 sealed class {{ e.name()|class_name_kt }}{% if ci.item_contains_object_references(e) %}: Disposable {% endif %} {
     {% for variant in e.variants() -%}
     {% if !variant.has_fields() -%}
-    object {{ variant.name()|class_name_kt }} : {{ e.name()|class_name_kt }}()
+    {% call kt::docstring(variant, 4) %}{% call kt::deprecated(variant) %}object {{ variant.name()|class_name_kt }} : {{ e.name()|class_name_kt }}()
     {% else -%}
-    data class {{ variant.name()|class_name_kt }}(
+    {% call kt::docstring(variant, 4) %}{% call kt::deprecated(variant) %}data class {{ variant.name()|class_name_kt }}(
         {% for field in variant.fields() -%}
         val {{ field.name()|var_name_kt }}: {{ field.type_()|type_kt}}{% if loop.last %}{% else %}, {% endif %}
         {% endfor -%}
@@ -57,7 +73,7 @@ sealed class {{ e.name()|class_name_kt }}{% if ci.item_contains_object_reference
         internal fun read(buf: ByteBuffer): {{ e.name()|class_name_kt }} {
             return when(buf.getInt()) {
                 {%- for variant in e.variants() %}
-                {{ loop.index }} -> {{ e.name()|class_name_kt }}.{{ variant.name()|class_name_kt }}{% if variant.has_fields() %}(
+                {{ variant.discr() }} -> {{ e.name()|class_name_kt }}.{{ variant.name()|class_name_kt }}{% if variant.has_fields() %}(
                     {% for field in variant.fields() -%}
                     {{ "buf"|read_kt(field.type_()) }}{% if loop.last %}{% else %},{% endif %}
                     {% endfor -%}
@@ -76,7 +92,7 @@ sealed class {{ e.name()|class_name_kt }}{% if ci.item_contains_object_reference
         when(this) {
             {%- for variant in e.variants() %}
             is {{ e.name()|class_name_kt }}.{{ variant.name()|class_name_kt }} -> {
-                buf.putInt({{ loop.index }})
+                buf.putInt({{ variant.discr() }})
                 {% for field in variant.fields() -%}
                 {{ "(this.{})"|format(field.name())|write_kt("buf", field.type_()) }}
                 {% endfor %}
@@ -28,22 +28,22 @@ interface CallStatusErrorHandler<E> {
 // Error {{ e.name() }}
 {%- let toplevel_name=e.name()|exception_name_kt %}
 {% if e.is_flat() %}
-sealed class {{ toplevel_name }}(message: String): Exception(message){% if ci.item_contains_object_references(e) %}, Disposable {% endif %} {
+{% call kt::docstring(e, 0) %}sealed class {{ toplevel_name }}(message: String): Exception(message){% if ci.item_contains_object_references(e) %}, Disposable {% endif %} {
         // Each variant is a nested class
         // Flat enums carries a string error message, so no special implementation is necessary.
         {% for variant in e.variants() -%}
-        class {{ variant.name()|exception_name_kt }}(message: String) : {{ toplevel_name }}(message)
+        {% call kt::docstring(variant, 8) %}{% call kt::deprecated(variant) %}class {{ variant.name()|exception_name_kt }}(message: String) : {{ toplevel_name }}(message)
         {% endfor %}
 
 {%- else %}
-sealed class {{ toplevel_name }}(): Exception(){% if ci.item_contains_object_references(e) %}, Disposable {% endif %} {
+{% call kt::docstring(e, 0) %}sealed class {{ toplevel_name }}(): Exception(){% if ci.item_contains_object_references(e) %}, Disposable {% endif %} {
 
     // Each variant is a nested class
     {% for variant in e.variants() -%}
     {% if !variant.has_fields() -%}
-    class {{ variant.name()|exception_name_kt }} : {{ toplevel_name }}()
+    {% call kt::docstring(variant, 4) %}{% call kt::deprecated(variant) %}class {{ variant.name()|exception_name_kt }} : {{ toplevel_name }}()
     {% else %}
-    class {{ variant.name()|exception_name_kt }}(
+    {% call kt::docstring(variant, 4) %}{% call kt::deprecated(variant) %}class {{ variant.name()|exception_name_kt }}(
         {% for field in variant.fields() -%}
         val {{ field.name()|var_name_kt }}: {{ field.type_()|type_kt}}{% if loop.last %}{% else %}, {% endif %}
         {% endfor -%}
@@ -80,6 +80,22 @@ sealed class {{ toplevel_name }}(): Exception(){% if ci.item_contains_object_ref
             }
             {%- endif %}
         }
+        {%- if ci.is_callback_interface_throws_type(e.type_()) %}
+
+        // Errors thrown by callback interface implementations are written back to Rust.
+        fun write(e: {{ toplevel_name }}, buf: RustBufferBuilder) {
+            when(e) {
+                {%- for variant in e.variants() %}
+                is {{ toplevel_name }}.{{ variant.name()|exception_name_kt }} -> {
+                    buf.putInt({{ loop.index }})
+                    {%- for field in variant.fields() %}
+                    {{ "(e.{})"|format(field.name())|write_kt("buf", field.type_()) }}
+                    {%- endfor %}
+                }
+                {%- endfor %}
+            }.let { /* this makes the `when` an expression, which ensures it is exhaustive */ }
+        }
+        {%- endif %}
     }
 
     {% if ci.item_contains_object_references(e) %}
@@ -0,0 +1,36 @@
+{#
+// Flags wrap the integer that holds their bits, with a constant for each of the flags
+// declared in the UDL. They're combined with `or` and checked with `in`.
+#}
+{%- let class_name = f.name()|class_name_kt %}
+{%- let bits_type = f.prim()|type_kt %}
+{% call kt::docstring(f, 0) %}@ExperimentalUnsignedTypes
+data class {{ class_name }}(val bits: {{ bits_type }}) {
+    infix fun or(other: {{ class_name }}) = {{ class_name }}(bits or other.bits)
+
+    infix fun and(other: {{ class_name }}) = {{ class_name }}(bits and other.bits)
+
+    operator fun contains(other: {{ class_name }}) = (bits and other.bits) == other.bits
+
+    companion object {
+        {%- for flag in f.flags() %}
+        {% call kt::docstring(flag, 8) %}val {{ flag.name()|const_name_kt }} = {{ class_name }}({{ flag.value() }}u)
+        {%- endfor %}
+
+        internal fun lift(v: {{ f.ffi_type()|type_ffi }}): {{ class_name }} {
+            return {{ class_name }}({{ "v"|lift_kt(f.prim()) }})
+        }
+
+        internal fun read(buf: ByteBuffer): {{ class_name }} {
+            return {{ class_name }}({{ "buf"|read_kt(f.prim()) }})
+        }
+    }
+
+    internal fun lower(): {{ f.ffi_type()|type_ffi }} {
+        return {{ "bits"|lower_kt(f.prim()) }}
+    }
+
+    internal fun write(buf: RustBufferBuilder) {
+        {{ "bits"|write_kt("buf", f.prim()) }}
+    }
+}
@@ -205,13 +205,18 @@ internal class ConcurrentHandleMap<T>(
 }
 
 interface ForeignCallback : com.sun.jna.Callback {
-    public fun invoke(handle: Long, method: Int, args: RustBuffer.ByValue): RustBuffer.ByValue
+    public fun invoke(handle: Long, method: Int, args: RustBuffer.ByValue, outBuf: RustBufferByReference): Int
 }
 
 // Magic number for the Rust proxy to call using the same mechanism as every other method,
 // to free the callback once it's dropped by Rust.
 internal const val IDX_CALLBACK_FREE = 0
 
+// Status codes returned by the `ForeignCallback`, telling Rust how to interpret `outBuf`.
+internal const val UNIFFI_CALLBACK_SUCCESS = 0
+internal const val UNIFFI_CALLBACK_ERROR = 1
+internal const val UNIFFI_CALLBACK_UNEXPECTED_ERROR = 2
+
 internal abstract class CallbackInternals<CallbackInterface>(
     val foreignCallback: ForeignCallback
 ) {
@@ -237,4 +242,65 @@ internal abstract class CallbackInternals<CallbackInterface>(
     fun write(v: CallbackInterface, buf: RustBufferBuilder) =
         buf.putLong(lower(v))
 }
-{% endif %}
\ No newline at end of file
+{% endif %}
+{% if ci.has_async_functions() %}
+// Async functions and methods return a pointer to a `RustFuture`, which we drive to completion
+// from a coroutine using the poll/wake protocol from the `uniffi` runtime.
+//
+// Each time we poll the future, we suspend the calling coroutine and register its continuation
+// under a fresh handle. Rust calls the `ForeignWaker` with that handle (possibly on another
+// thread) once the future can make progress, which resumes the coroutine to poll again.
+
+interface ForeignWaker : com.sun.jna.Callback {
+    public fun invoke(data: Long)
+}
+
+internal object RustFutureWaker : ForeignWaker {
+    private val continuations = ConcurrentHashMap<Long, Continuation<Boolean>>()
+    private val currentHandle = AtomicLong(0L)
+
+    fun register(continuation: Continuation<Boolean>): Long =
+        currentHandle.getAndIncrement().also { handle ->
+            continuations[handle] = continuation
+        }
+
+    fun unregister(handle: Long): Continuation<Boolean>? = continuations.remove(handle)
+
+    override fun invoke(data: Long) {
+        unregister(data)?.resume(false)
+    }
+}
+
+internal suspend fun <T, E: Exception> rustFutureAwait(
+    rustFuture: Pointer,
+    errorHandler: CallStatusErrorHandler<E>,
+    readItem: (ByteBuffer) -> T
+): T {
+    try {
+        do {
+            val ready = suspendCoroutine<Boolean> { continuation ->
+                val handle = RustFutureWaker.register(continuation)
+                val poll = try {
+                    rustCall() { status ->
+                        _UniFFILib.INSTANCE.{{ ci.ffi_rust_future_poll().name() }}(rustFuture, RustFutureWaker, handle, status)
+                    }
+                } catch (e: Throwable) {
+                    RustFutureWaker.unregister(handle)
+                    throw e
+                }
+                if (poll != 0.toByte()) {
+                    RustFutureWaker.unregister(handle)?.resume(true)
+                }
+            }
+        } while (!ready)
+        val rbuf = rustCallWithError(errorHandler) { status ->
+            _UniFFILib.INSTANCE.{{ ci.ffi_rust_future_complete().name() }}(rustFuture, status)
+        }
+        return liftFromRustBuffer(rbuf, readItem)
+    } finally {
+        rustCall() { status ->
+            _UniFFILib.INSTANCE.{{ ci.ffi_rust_future_free().name() }}(rustFuture, status)
+        }
+    }
+}
+{% endif %}
@@ -20,14 +20,13 @@ internal interface _UniFFILib : Library {
     companion object {
         internal val INSTANCE: _UniFFILib by lazy { 
             loadIndirect<_UniFFILib>(componentName = "{{ ci.namespace() }}")
-            {% let callback_interfaces = ci.iter_callback_interface_definitions() %}
-            {%- if !callback_interfaces.is_empty() -%}
             .also { lib: _UniFFILib ->
-                {% for cb in callback_interfaces -%}
+                uniffiCheckContractApiVersion(lib)
+                uniffiCheckApiChecksums(lib)
+                {% for cb in ci.iter_callback_interface_definitions() -%}
                 CallbackInterface{{ cb.name()|class_name_kt }}Internals.register(lib)
                 {% endfor -%}
             }
-            {% endif %}
         }
     }
 
@@ -38,3 +37,25 @@ internal interface _UniFFILib : Library {
 
     {% endfor %}
 }
+
+// Check that the library we loaded was built from the same interface as these bindings,
+// so that a mismatch fails loudly here rather than with undefined behaviour on a later call.
+
+private fun uniffiCheckContractApiVersion(lib: _UniFFILib) {
+    val bindingsContractVersion = {{ ci.uniffi_contract_version() }}
+    val scaffoldingContractVersion = rustCall() { status ->
+        lib.{{ ci.ffi_uniffi_contract_version().name() }}(status)
+    }
+    if (bindingsContractVersion != scaffoldingContractVersion) {
+        throw InternalException("UniFFI contract version mismatch: bindings expect $bindingsContractVersion but library has $scaffoldingContractVersion; bindings do not match library, try regenerating them")
+    }
+}
+
+@Suppress("UNUSED_PARAMETER")
+private fun uniffiCheckApiChecksums(lib: _UniFFILib) {
+    {%- for checksum in ci.iter_checksums() %}
+    if (rustCall() { status -> lib.{{ checksum.ffi_func().name() }}(status) } != {{ checksum.checksum() }}.toShort()) {
+        throw InternalException("UniFFI API checksum mismatch for {{ checksum.item_name() }}: bindings do not match library, try regenerating them")
+    }
+    {%- endfor %}
+}
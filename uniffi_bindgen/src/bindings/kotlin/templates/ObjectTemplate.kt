@@ -1,22 +1,25 @@
-{% call kt::unsigned_types_annotation(obj) %}
+{% call kt::docstring(obj, 0) %}{% call kt::unsigned_types_annotation(obj) %}
 public interface {{ obj.name()|class_name_kt }}Interface {
-    {% for meth in obj.methods() -%}
-    fun {{ meth.name()|fn_name_kt }}({% call kt::arg_list_decl(meth) %})
+    {% for meth in obj.instance_methods() -%}
+    {% call kt::docstring(meth, 4) %}{% call kt::deprecated(meth) %}{% if meth.is_async() %}suspend {% endif %}fun {{ meth.name()|fn_name_kt }}({% call kt::arg_list_decl(meth) %})
     {%- match meth.return_type() -%}
     {%- when Some with (return_type) %}: {{ return_type|type_kt -}}
     {%- else -%}
     {%- endmatch %}
     {% endfor %}
+    {%- for prop in obj.properties() %}
+    {% call kt::docstring(prop, 4) %}{% if prop.is_readonly() %}val{% else %}var{% endif %} {{ prop.name()|var_name_kt }}: {{ prop.type_()|type_kt }}
+    {% endfor %}
 }
 
-{% call kt::unsigned_types_annotation(obj) %}
+{% call kt::docstring(obj, 0) %}{% call kt::unsigned_types_annotation(obj) %}
 class {{ obj.name()|class_name_kt }}(
     pointer: Pointer
 ) : FFIObject(pointer), {{ obj.name()|class_name_kt }}Interface {
 
     {%- match obj.primary_constructor() %}
     {%- when Some with (cons) %}
-    constructor({% call kt::arg_list_decl(cons) -%}) :
+    {% call kt::docstring(cons, 4) %}{% call kt::deprecated(cons) %}constructor({% call kt::arg_list_decl(cons) -%}) :
         this({% call kt::to_ffi_call(cons) %})
     {%- when None %}
     {%- endmatch %}
@@ -43,11 +46,20 @@ class {{ obj.name()|class_name_kt }}(
         buf.putLong(Pointer.nativeValue(this.lower()))
     }
 
-    {% for meth in obj.methods() -%}
+    {% for meth in obj.instance_methods() -%}
+    {%- if meth.is_async() -%}
+    {% call kt::docstring(meth, 4) %}{% call kt::deprecated(meth) %}override suspend fun {{ meth.name()|fn_name_kt }}({% call kt::arg_list_protocol(meth) %})
+    {%- match meth.return_type() %}{%- when Some with (return_type) %}: {{ return_type|type_kt }}{%- else %}{%- endmatch %} {
+        val _rustFuture = callWithPointer {
+            {%- call kt::to_ffi_call_with_prefix("it", meth) %}
+        }
+        return {% call kt::rust_future_await("_rustFuture", meth) %}
+    }
+    {% else -%}
     {%- match meth.return_type() -%}
 
     {%- when Some with (return_type) -%}
-    override fun {{ meth.name()|fn_name_kt }}({% call kt::arg_list_protocol(meth) %}): {{ return_type|type_kt }} =
+    {% call kt::docstring(meth, 4) %}{% call kt::deprecated(meth) %}override fun {{ meth.name()|fn_name_kt }}({% call kt::arg_list_protocol(meth) %}): {{ return_type|type_kt }} =
         callWithPointer {
             {%- call kt::to_ffi_call_with_prefix("it", meth) %}
         }.let {
@@ -55,13 +67,78 @@ class {{ obj.name()|class_name_kt }}(
         }
 
     {%- when None -%}
-    override fun {{ meth.name()|fn_name_kt }}({% call kt::arg_list_protocol(meth) %}) =
+    {% call kt::docstring(meth, 4) %}{% call kt::deprecated(meth) %}override fun {{ meth.name()|fn_name_kt }}({% call kt::arg_list_protocol(meth) %}) =
         callWithPointer {
             {%- call kt::to_ffi_call_with_prefix("it", meth) %}
         }
     {% endmatch %}
+    {%- endif %}
     {% endfor %}
 
+    {%- for prop in obj.properties() %}
+    {% call kt::docstring(prop, 4) %}override {% if prop.is_readonly() %}val{% else %}var{% endif %} {{ prop.name()|var_name_kt }}: {{ prop.type_()|type_kt }}
+        get() = callWithPointer {
+            {%- call kt::to_ffi_call_with_prefix("it", prop.getter()) %}
+        }.let {
+            {{ "it"|lift_kt(prop.type_()) }}
+        }
+        {%- match prop.setter() %}
+        {%- when Some with (setter) %}
+        set(value) {
+            callWithPointer {
+                {%- call kt::to_ffi_call_with_prefix("it", setter) %}
+            }
+        }
+        {%- else %}
+        {%- endmatch %}
+    {% endfor %}
+
+    {#- Kotlin only has `toString()`, which uses `Display` if the object has both. #}
+    {%- for trait_impl in obj.trait_impls() %}
+    {%- if trait_impl.trait_() == ObjectTrait::Display || (trait_impl.trait_() == ObjectTrait::Debug && obj.trait_impl(ObjectTrait::Display).is_none()) %}
+    override fun toString(): String =
+        callWithPointer {
+            rustCall() { status ->
+                _UniFFILib.INSTANCE.{{ trait_impl.ffi_func().name() }}(it, status)
+            }
+        }.let {
+            {{ "it"|lift_kt(trait_impl.return_type()) }}
+        }
+    {% endif %}
+    {%- endfor %}
+
+    {%- match obj.trait_impl(ObjectTrait::Eq) %}
+    {%- when Some with (trait_impl) %}
+    override fun equals(other: Any?): Boolean {
+        if (other !is {{ obj.name()|class_name_kt }}) {
+            return false
+        }
+        return callWithPointer { ptr ->
+            other.callWithPointer { otherPtr ->
+                rustCall() { status ->
+                    _UniFFILib.INSTANCE.{{ trait_impl.ffi_func().name() }}(ptr, otherPtr, status)
+                }
+            }
+        }.let {
+            {{ "it"|lift_kt(trait_impl.return_type()) }}
+        }
+    }
+    {% when None %}
+    {%- endmatch %}
+
+    {%- match obj.trait_impl(ObjectTrait::Hash) %}
+    {%- when Some with (trait_impl) %}
+    override fun hashCode(): Int =
+        callWithPointer {
+            rustCall() { status ->
+                _UniFFILib.INSTANCE.{{ trait_impl.ffi_func().name() }}(it, status)
+            }
+        }.let {
+            {{ "it"|lift_kt(trait_impl.return_type()) }}.hashCode()
+        }
+    {% when None %}
+    {%- endmatch %}
+
     companion object {
         internal fun lift(ptr: Pointer): {{ obj.name()|class_name_kt }} {
             return {{ obj.name()|class_name_kt }}(ptr)
@@ -74,8 +151,31 @@ class {{ obj.name()|class_name_kt }}(
         }
 
         {% for cons in obj.alternate_constructors() -%}
-        fun {{ cons.name()|fn_name_kt }}({% call kt::arg_list_decl(cons) %}): {{ obj.name()|class_name_kt }} =
+        {% call kt::docstring(cons, 8) %}{% call kt::deprecated(cons) %}fun {{ cons.name()|fn_name_kt }}({% call kt::arg_list_decl(cons) %}): {{ obj.name()|class_name_kt }} =
             {{ obj.name()|class_name_kt }}({% call kt::to_ffi_call(cons) %})
         {% endfor %}
+
+        {% for meth in obj.static_methods() -%}
+        {%- if meth.is_async() -%}
+        {% call kt::docstring(meth, 8) %}{% call kt::deprecated(meth) %}suspend fun {{ meth.name()|fn_name_kt }}({% call kt::arg_list_decl(meth) %})
+        {%- match meth.return_type() %}{%- when Some with (return_type) %}: {{ return_type|type_kt }}{%- else %}{%- endmatch %} {
+            val _rustFuture = {% call kt::to_ffi_call(meth) %}
+            return {% call kt::rust_future_await("_rustFuture", meth) %}
+        }
+        {% else -%}
+        {%- match meth.return_type() -%}
+
+        {%- when Some with (return_type) -%}
+        {% call kt::docstring(meth, 8) %}{% call kt::deprecated(meth) %}fun {{ meth.name()|fn_name_kt }}({% call kt::arg_list_decl(meth) %}): {{ return_type|type_kt }} {
+            val _retval = {% call kt::to_ffi_call(meth) %}
+            return {{ "_retval"|lift_kt(return_type) }}
+        }
+
+        {%- when None -%}
+        {% call kt::docstring(meth, 8) %}{% call kt::deprecated(meth) %}fun {{ meth.name()|fn_name_kt }}({% call kt::arg_list_decl(meth) %}) =
+            {% call kt::to_ffi_call(meth) %}
+        {% endmatch %}
+        {%- endif %}
+        {% endfor %}
     }
 }
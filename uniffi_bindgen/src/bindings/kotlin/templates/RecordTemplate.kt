@@ -1,7 +1,7 @@
-{% call kt::unsigned_types_annotation(rec) %}
+{% call kt::docstring(rec, 0) %}{% call kt::deprecated(rec) %}{% call kt::unsigned_types_annotation(rec) %}
 data class {{ rec.name()|class_name_kt }} (
     {%- for field in rec.fields() %}
-    var {{ field.name()|var_name_kt }}: {{ field.type_()|type_kt -}}
+    {% call kt::docstring(field, 4) %}{% call kt::deprecated(field) %}var {{ field.name()|var_name_kt }}: {{ field.type_()|type_kt -}}
     {%- match field.default_value() %}
         {%- when Some with(literal) %} = {{ literal|literal_kt }}
         {%- else %}
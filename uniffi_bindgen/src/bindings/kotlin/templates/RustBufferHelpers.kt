@@ -282,6 +282,102 @@ internal fun String.write(buf: RustBufferBuilder) {
     buf.put(byteArr)
 }
 
+{% when Type::UInt128 -%}
+{% let type_name = typ|type_kt %}
+
+internal fun liftU128(rbuf: RustBuffer.ByValue): {{ type_name }} {
+    return liftFromRustBuffer(rbuf) { buf ->
+        readU128(buf)
+    }
+}
+
+internal fun readU128(buf: ByteBuffer): {{ type_name }} {
+    val bytes = ByteArray(16)
+    buf.get(bytes)
+    return java.math.BigInteger(1, bytes)
+}
+
+internal fun lowerU128(v: {{ type_name }}): RustBuffer.ByValue {
+    return lowerIntoRustBuffer(v) { v, buf ->
+        writeU128(v, buf)
+    }
+}
+
+internal fun writeU128(v: {{ type_name }}, buf: RustBufferBuilder) {
+    if (v.signum() < 0 || v.bitLength() > 128) {
+        throw IllegalArgumentException("$v is out of range for a u128")
+    }
+    // `toByteArray()` gives the shortest two's complement encoding, so sign-extend it to 16 bytes.
+    val bytes = v.toByteArray()
+    val padded = ByteArray(16) { if (v.signum() < 0) -1 else 0 }
+    bytes.copyInto(padded, maxOf(0, 16 - bytes.size), maxOf(0, bytes.size - 16))
+    buf.put(padded)
+}
+
+{% when Type::Int128 -%}
+{% let type_name = typ|type_kt %}
+
+internal fun liftI128(rbuf: RustBuffer.ByValue): {{ type_name }} {
+    return liftFromRustBuffer(rbuf) { buf ->
+        readI128(buf)
+    }
+}
+
+internal fun readI128(buf: ByteBuffer): {{ type_name }} {
+    val bytes = ByteArray(16)
+    buf.get(bytes)
+    return java.math.BigInteger(bytes)
+}
+
+internal fun lowerI128(v: {{ type_name }}): RustBuffer.ByValue {
+    return lowerIntoRustBuffer(v) { v, buf ->
+        writeI128(v, buf)
+    }
+}
+
+internal fun writeI128(v: {{ type_name }}, buf: RustBufferBuilder) {
+    if (v.bitLength() > 127) {
+        throw IllegalArgumentException("$v is out of range for an i128")
+    }
+    // `toByteArray()` gives the shortest two's complement encoding, so sign-extend it to 16 bytes.
+    val bytes = v.toByteArray()
+    val padded = ByteArray(16) { if (v.signum() < 0) -1 else 0 }
+    bytes.copyInto(padded, maxOf(0, 16 - bytes.size), maxOf(0, bytes.size - 16))
+    buf.put(padded)
+}
+
+{% when Type::Bytes -%}
+
+internal fun lift{{ canonical_type_name }}(rbuf: RustBuffer.ByValue): ByteArray {
+    try {
+        val byteArr = ByteArray(rbuf.len)
+        rbuf.asByteBuffer()!!.get(byteArr)
+        return byteArr
+    } finally {
+        RustBuffer.free(rbuf)
+    }
+}
+
+internal fun read{{ canonical_type_name }}(buf: ByteBuffer): ByteArray {
+    val len = buf.getInt()
+    val byteArr = ByteArray(len)
+    buf.get(byteArr)
+    return byteArr
+}
+
+internal fun lower{{ canonical_type_name }}(v: ByteArray): RustBuffer.ByValue {
+    // As with strings, we copy directly into a `RustBuffer` rather than into a JNA `Memory`
+    // for `ForeignBytes`, which would just be another copy.
+    val rbuf = RustBuffer.alloc(v.size)
+    rbuf.asByteBuffer()!!.put(v)
+    return rbuf
+}
+
+internal fun write{{ canonical_type_name }}(v: ByteArray, buf: RustBufferBuilder) {
+    buf.putInt(v.size)
+    buf.put(v)
+}
+
 {% when Type::Timestamp -%}
 {% let type_name = typ|type_kt %}
 
@@ -449,55 +545,241 @@ internal fun write{{ canonical_type_name }}(v: List<{{ inner_type_name }}>, buf:
     }
 }
 
-{% when Type::Map with (inner_type) -%}
-{% let inner_type_name = inner_type|type_kt %}
+{% when Type::Map with (key_type, value_type) -%}
+{% let key_type_name = key_type|type_kt %}
+{% let value_type_name = value_type|type_kt %}
 
 // Helper functions for pasing values of type {{ typ|type_kt }}
 
-{% call kt::unsigned_types_annotation(inner_type) %}
-internal fun lift{{ canonical_type_name }}(rbuf: RustBuffer.ByValue): Map<String, {{ inner_type_name }}> {
+{% call kt::unsigned_types_annotation(typ) %}
+internal fun lift{{ canonical_type_name }}(rbuf: RustBuffer.ByValue): Map<{{ key_type_name }}, {{ value_type_name }}> {
     return liftFromRustBuffer(rbuf) { buf ->
         read{{ canonical_type_name }}(buf)
     }
 }
 
-{% call kt::unsigned_types_annotation(inner_type) %}
-internal fun read{{ canonical_type_name }}(buf: ByteBuffer): Map<String, {{ inner_type_name }}> {
+{% call kt::unsigned_types_annotation(typ) %}
+internal fun read{{ canonical_type_name }}(buf: ByteBuffer): Map<{{ key_type_name }}, {{ value_type_name }}> {
     // TODO: Once Kotlin's `buildMap` API is stabilized we should use it here.
-    val items : MutableMap<String, {{ inner_type_name }}> = mutableMapOf()
+    val items : MutableMap<{{ key_type_name }}, {{ value_type_name }}> = mutableMapOf()
     val len = buf.getInt()
     repeat(len) {
-        val k = String.read(buf)
-        val v = {{ "buf"|read_kt(inner_type) }}
+        val k = {{ "buf"|read_kt(key_type) }}
+        val v = {{ "buf"|read_kt(value_type) }}
         items[k] = v
     }
     return items
 }
 
-{% call kt::unsigned_types_annotation(inner_type) %}
-internal fun lower{{ canonical_type_name }}(m: Map<String, {{ inner_type_name }}>): RustBuffer.ByValue {
+{% call kt::unsigned_types_annotation(typ) %}
+internal fun lower{{ canonical_type_name }}(m: Map<{{ key_type_name }}, {{ value_type_name }}>): RustBuffer.ByValue {
     return lowerIntoRustBuffer(m) { m, buf ->
         write{{ canonical_type_name }}(m, buf)
     }
 }
 
-{% call kt::unsigned_types_annotation(inner_type) %}
-internal fun write{{ canonical_type_name }}(v: Map<String, {{ inner_type_name }}>, buf: RustBufferBuilder) {
+{% call kt::unsigned_types_annotation(typ) %}
+internal fun write{{ canonical_type_name }}(v: Map<{{ key_type_name }}, {{ value_type_name }}>, buf: RustBufferBuilder) {
     buf.putInt(v.size)
     // The parens on `(k, v)` here ensure we're calling the right method,
     // which is important for compatibility with older android devices.
     // Ref https://blog.danlew.net/2017/03/16/kotlin-puzzler-whose-line-is-it-anyways/
     v.forEach { (k, v) ->
-        k.write(buf)
-        {{ "v"|write_kt("buf", inner_type) }}
+        {{ "k"|write_kt("buf", key_type) }}
+        {{ "v"|write_kt("buf", value_type) }}
+    }
+}
+
+{% when Type::Set with (inner_type) -%}
+{% let inner_type_name = inner_type|type_kt %}
+
+// Helper functions for pasing values of type {{ typ|type_kt }}
+
+{% call kt::unsigned_types_annotation(inner_type) %}
+internal fun lift{{ canonical_type_name }}(rbuf: RustBuffer.ByValue): Set<{{ inner_type_name }}> {
+    return liftFromRustBuffer(rbuf) { buf ->
+        read{{ canonical_type_name }}(buf)
     }
 }
 
+{% call kt::unsigned_types_annotation(inner_type) %}
+internal fun read{{ canonical_type_name }}(buf: ByteBuffer): Set<{{ inner_type_name }}> {
+    // `mutableSetOf` keeps the elements in the order that Rust wrote them.
+    val items : MutableSet<{{ inner_type_name }}> = mutableSetOf()
+    val len = buf.getInt()
+    repeat(len) {
+        items.add({{ "buf"|read_kt(inner_type) }})
+    }
+    return items
+}
+
+{% call kt::unsigned_types_annotation(inner_type) %}
+internal fun lower{{ canonical_type_name }}(v: Set<{{ inner_type_name }}>): RustBuffer.ByValue {
+    return lowerIntoRustBuffer(v) { v, buf ->
+        write{{ canonical_type_name }}(v, buf)
+    }
+}
+
+{% call kt::unsigned_types_annotation(inner_type) %}
+internal fun write{{ canonical_type_name }}(v: Set<{{ inner_type_name }}>, buf: RustBufferBuilder) {
+    buf.putInt(v.size)
+    v.forEach {
+        {{ "it"|write_kt("buf", inner_type) }}
+    }
+}
+
+{% when Type::OrderedMap with (key_type, value_type) -%}
+{% let key_type_name = key_type|type_kt %}
+{% let value_type_name = value_type|type_kt %}
+
+// Helper functions for pasing values of type {{ typ|type_kt }}
+
+{% call kt::unsigned_types_annotation(typ) %}
+internal fun lift{{ canonical_type_name }}(rbuf: RustBuffer.ByValue): java.util.SortedMap<{{ key_type_name }}, {{ value_type_name }}> {
+    return liftFromRustBuffer(rbuf) { buf ->
+        read{{ canonical_type_name }}(buf)
+    }
+}
+
+{% call kt::unsigned_types_annotation(typ) %}
+internal fun read{{ canonical_type_name }}(buf: ByteBuffer): java.util.SortedMap<{{ key_type_name }}, {{ value_type_name }}> {
+    val items = java.util.TreeMap<{{ key_type_name }}, {{ value_type_name }}>()
+    val len = buf.getInt()
+    repeat(len) {
+        val k = {{ "buf"|read_kt(key_type) }}
+        val v = {{ "buf"|read_kt(value_type) }}
+        items[k] = v
+    }
+    return items
+}
+
+{% call kt::unsigned_types_annotation(typ) %}
+internal fun lower{{ canonical_type_name }}(m: java.util.SortedMap<{{ key_type_name }}, {{ value_type_name }}>): RustBuffer.ByValue {
+    return lowerIntoRustBuffer(m) { m, buf ->
+        write{{ canonical_type_name }}(m, buf)
+    }
+}
+
+{% call kt::unsigned_types_annotation(typ) %}
+internal fun write{{ canonical_type_name }}(v: java.util.SortedMap<{{ key_type_name }}, {{ value_type_name }}>, buf: RustBufferBuilder) {
+    buf.putInt(v.size)
+    // A `SortedMap` iterates in key order, so the entries go over the wire in order.
+    // The parens on `(k, v)` here are for the same reason as for unordered maps.
+    v.forEach { (k, v) ->
+        {{ "k"|write_kt("buf", key_type) }}
+        {{ "v"|write_kt("buf", value_type) }}
+    }
+}
+
+{% when Type::Tuple with (element_types) -%}
+{% let type_name = typ|type_kt %}
+
+// Helper functions for pasing values of type {{ type_name }}
+
+{% call kt::unsigned_types_annotation(typ) %}
+internal fun lift{{ canonical_type_name }}(rbuf: RustBuffer.ByValue): {{ type_name }} {
+    return liftFromRustBuffer(rbuf) { buf ->
+        read{{ canonical_type_name }}(buf)
+    }
+}
+
+{% call kt::unsigned_types_annotation(typ) %}
+internal fun read{{ canonical_type_name }}(buf: ByteBuffer): {{ type_name }} {
+    return {{ type_name }}(
+        {%- for element_type in element_types %}
+        {{ "buf"|read_kt(element_type) }}{% if !loop.last %},{% endif %}
+        {%- endfor %}
+    )
+}
+
+{% call kt::unsigned_types_annotation(typ) %}
+internal fun lower{{ canonical_type_name }}(v: {{ type_name }}): RustBuffer.ByValue {
+    return lowerIntoRustBuffer(v) { v, buf ->
+        write{{ canonical_type_name }}(v, buf)
+    }
+}
+
+{% call kt::unsigned_types_annotation(typ) %}
+internal fun write{{ canonical_type_name }}(v: {{ type_name }}, buf: RustBufferBuilder) {
+    val (
+        {%- for element_type in element_types %}
+        v{{ loop.index0 }}{% if !loop.last %},{% endif %}
+        {%- endfor %}
+    ) = v
+    {%- for element_type in element_types %}
+    {{ "v{}"|format(loop.index0)|write_kt("buf", element_type) }}
+    {%- endfor %}
+}
+
 {% when Type::Enum with (enum_name) -%}
 {# Helpers for Enum types are defined inline with the Enum class #}
+{% call kt::external_type_helpers(typ) %}
 
 {% when Type::Record with (record_name) -%}
 {# Helpers for Record types are defined inline with the Record class #}
+{% call kt::external_type_helpers(typ) %}
+
+{% when Type::Flags with { name, prim } -%}
+{# Helpers for Flags types are defined inline with the Flags class #}
+{% call kt::external_type_helpers(typ) %}
+
+{% when Type::Wrapped with { name, prim } -%}
+{%- let type_name = typ|type_kt %}
+{%- let ffi_type_name = prim|ffi_type_kt %}
+{%- let wrapped = config.wrapped_type(name) %}
+
+// Helper functions for passing values of type {{ type_name }}, which is passed to Rust as its primitive type.
+
+{% call kt::unsigned_types_annotation(typ) %}
+internal fun lift{{ canonical_type_name }}(v: {{ ffi_type_name }}): {{ type_name }} {
+    val builtinValue = {{ "v"|lift_kt(prim) }}
+    return {{ wrapped.lift("builtinValue") }}
+}
+
+{% call kt::unsigned_types_annotation(typ) %}
+internal fun read{{ canonical_type_name }}(buf: ByteBuffer): {{ type_name }} {
+    val builtinValue = {{ "buf"|read_kt(prim) }}
+    return {{ wrapped.lift("builtinValue") }}
+}
+
+{% call kt::unsigned_types_annotation(typ) %}
+internal fun lower{{ canonical_type_name }}(v: {{ type_name }}): {{ ffi_type_name }} {
+    val builtinValue = {{ wrapped.lower("v") }}
+    return {{ "builtinValue"|lower_kt(prim) }}
+}
+
+{% call kt::unsigned_types_annotation(typ) %}
+internal fun write{{ canonical_type_name }}(v: {{ type_name }}, buf: RustBufferBuilder) {
+    val builtinValue = {{ wrapped.lower("v") }}
+    {{ "builtinValue"|write_kt("buf", prim) }}
+}
+
+{% call kt::external_type_helpers(typ) %}
+
+{% when Type::External with { name, crate_name } -%}
+{% let type_name = typ|type_kt %}
+
+// Helper functions for passing values of type {{ type_name }}, which is defined in `{{ crate_name }}`.
+
+internal fun lift{{ canonical_type_name }}(rbuf: RustBuffer.ByValue): {{ type_name }} {
+    return liftFromRustBuffer(rbuf) { buf ->
+        read{{ canonical_type_name }}(buf)
+    }
+}
+
+internal fun read{{ canonical_type_name }}(buf: ByteBuffer): {{ type_name }} {
+    return readExternal{{ canonical_type_name }}(buf)
+}
+
+internal fun lower{{ canonical_type_name }}(v: {{ type_name }}): RustBuffer.ByValue {
+    return lowerIntoRustBuffer(v) { v, buf ->
+        write{{ canonical_type_name }}(v, buf)
+    }
+}
+
+internal fun write{{ canonical_type_name }}(v: {{ type_name }}, buf: RustBufferBuilder) {
+    buf.put(writeExternal{{ canonical_type_name }}(v))
+}
 
 {% when Type::Object with (object_name) -%}
 {# Object types cannot be lifted, lowered or serialized (yet) #}
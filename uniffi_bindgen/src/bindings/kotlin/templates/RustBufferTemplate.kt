@@ -32,6 +32,19 @@ open class RustBuffer : Structure() {
         }
 }
 
+{%- if ci.iter_callback_interface_definitions().len() > 0 %}
+// This is a helper for writing a `RustBuffer` into a pointer that was passed to us by the
+// rust code, which is how callback interfaces return their results.
+class RustBufferByReference : com.sun.jna.ptr.ByReference(16) {
+    fun setValue(value: RustBuffer.ByValue) {
+        val pointer = getPointer()
+        pointer.setInt(0, value.capacity)
+        pointer.setInt(4, value.len)
+        pointer.setPointer(8, value.data)
+    }
+}
+
+{% endif -%}
 // This is a helper for safely passing byte references into the rust code.
 // It's not actually used at the moment, because there aren't many things that you
 // can take a direct pointer to in the JVM, and if we're going to copy something
@@ -1,7 +1,17 @@
+{%- if func.is_async() %}
+
+{% call kt::docstring(func, 0) %}{% call kt::deprecated(func) %}{% call kt::unsigned_types_annotation(func) %}
+suspend fun {{ func.name()|fn_name_kt }}({%- call kt::arg_list_decl(func) -%})
+{%- match func.return_type() %}{%- when Some with (return_type) %}: {{ return_type|type_kt }}{%- else %}{%- endmatch %} {
+    val _rustFuture = {% call kt::to_ffi_call(func) %}
+    return {% call kt::rust_future_await("_rustFuture", func) %}
+}
+
+{% else -%}
 {%- match func.return_type() -%}
 {%- when Some with (return_type) %}
 
-{% call kt::unsigned_types_annotation(func) %}
+{% call kt::docstring(func, 0) %}{% call kt::deprecated(func) %}{% call kt::unsigned_types_annotation(func) %}
 fun {{ func.name()|fn_name_kt }}({%- call kt::arg_list_decl(func) -%}): {{ return_type|type_kt }} {
     val _retval = {% call kt::to_ffi_call(func) %}
     return {{ "_retval"|lift_kt(return_type) }}
@@ -9,7 +19,8 @@ fun {{ func.name()|fn_name_kt }}({%- call kt::arg_list_decl(func) -%}): {{ retur
 
 {% when None -%}
 
-{% call kt::unsigned_types_annotation(func) %}
+{% call kt::docstring(func, 0) %}{% call kt::deprecated(func) %}{% call kt::unsigned_types_annotation(func) %}
 fun {{ func.name()|fn_name_kt }}({% call kt::arg_list_decl(func) %}) =
     {% call kt::to_ffi_call(func) %}
 {% endmatch %}
+{%- endif %}
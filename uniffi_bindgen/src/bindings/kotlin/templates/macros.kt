@@ -27,6 +27,21 @@
 }
 {%- endmacro %}
 
+{#-
+// Await the `RustFuture` returned from calling an async function or method,
+// reading the result (if any) from the `RustBuffer` that it completes with.
+-#}
+{%- macro rust_future_await(var, func) -%}
+rustFutureAwait({{ var }},
+    {%- match func.throws() %}
+    {%- when Some with (e) %} {{ e|exception_name_kt }}
+    {%- else %} NullCallStatusErrorHandler
+    {%- endmatch %}) {
+    {%- match func.return_type() %}
+    {%- when Some with (return_type) %} buf -> {{ "buf"|read_kt(return_type) }}
+    {%- else %} _ -> Unit
+    {%- endmatch %} }
+{%- endmacro -%}
 
 {%- macro _arg_list_ffi_call(func) %}
     {%- for arg in func.arguments() %}
@@ -68,7 +83,53 @@
     uniffi_out_err: RustCallStatus
 {%- endmacro -%}
 
+// Render the doc comment of an item as KDoc, if it has one. Call it just before the item,
+// passing the indentation of the item in spaces.
+{%- macro docstring(defn, indent) -%}
+{%- match defn.docstring() -%}
+{%- when Some with (docstring) -%}
+{{ docstring|docstring_kt(indent) }}
+{%- else -%}
+{%- endmatch -%}
+{%- endmacro -%}
+
+// Annotate a deprecated item. Call it on the same line as the item, before any other annotations.
+{%- macro deprecated(defn) -%}
+{%- match defn.deprecated() -%}
+{%- when Some with (reason) -%}
+@Deprecated("{{ reason }}"){{ " " }}
+{%- else -%}
+{%- endmatch -%}
+{%- endmacro -%}
+
 // Add annotation if there are unsigned types
 {%- macro unsigned_types_annotation(member) -%}
 {% if ci.item_contains_unsigned_types(member) %}@ExperimentalUnsignedTypes{% endif %}
 {%- endmacro -%}
+
+{#-
+// Public helpers that let the bindings for other crates use this type as an `[External]` type.
+// Each crate's bindings have their own `RustBuffer` classes, so these only use Java types
+// in their signatures, and pass values around in their serialized form.
+-#}
+{%- macro external_type_helpers(typ) %}
+{%- let external_name = typ.canonical_name()|class_name_kt %}
+{% if ci.item_contains_unsigned_types(typ) %}@ExperimentalUnsignedTypes{% endif %}
+fun readExternal{{ external_name }}(buf: ByteBuffer): {{ typ|type_kt }} {
+    return {{ "buf"|read_kt(typ) }}
+}
+
+{% if ci.item_contains_unsigned_types(typ) %}@ExperimentalUnsignedTypes{% endif %}
+fun writeExternal{{ external_name }}(v: {{ typ|type_kt }}): ByteArray {
+    val rbuf = lowerIntoRustBuffer(v) { v, buf ->
+        {{ "v"|write_kt("buf", typ) }}
+    }
+    try {
+        val bytes = ByteArray(rbuf.len)
+        rbuf.asByteBuffer()?.get(bytes)
+        return bytes
+    } finally {
+        RustBuffer.free(rbuf)
+    }
+}
+{%- endmacro -%}
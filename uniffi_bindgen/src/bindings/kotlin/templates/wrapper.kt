@@ -1,6 +1,8 @@
 // This file was autogenerated by some hot garbage in the `uniffi` crate.
 // Trust me, you don't want to mess with it!
 
+{% match ci.namespace_docstring() -%}
+{%- when Some with (docstring) %}{{ docstring|docstring_kt(0) }}{% else %}{% endmatch -%}
 @file:Suppress("NAME_SHADOWING")
 
 package {{ config.package_name() }};
@@ -23,11 +25,27 @@ import com.sun.jna.Pointer
 import com.sun.jna.Structure
 import java.nio.ByteBuffer
 import java.nio.ByteOrder
+import java.util.concurrent.ConcurrentHashMap
 import java.util.concurrent.atomic.AtomicLong
 import java.util.concurrent.atomic.AtomicBoolean
 import java.util.concurrent.atomic.AtomicReference
 import java.util.concurrent.locks.ReentrantLock
 import kotlin.concurrent.withLock
+import kotlin.coroutines.Continuation
+import kotlin.coroutines.resume
+import kotlin.coroutines.suspendCoroutine
+
+{%- for import in config.wrapped_type_imports(ci) %}
+import {{ import }}
+{%- endfor %}
+
+{%- for (name, crate_name) in ci.iter_external_types() %}
+{%- let package_name = config.external_package_name(crate_name) %}
+{%- let canonical_type_name = format!("Type{}", name)|class_name_kt %}
+import {{ package_name }}.{{ name|class_name_kt }}
+import {{ package_name }}.readExternal{{ canonical_type_name }}
+import {{ package_name }}.writeExternal{{ canonical_type_name }}
+{%- endfor %}
 
 {% include "RustBufferTemplate.kt" %}
 
@@ -38,11 +56,32 @@ import kotlin.concurrent.withLock
 {% include "Helpers.kt" %}
 
 // Public interface members begin here.
+
+{%- let wrapped_types = ci.iter_wrapped_types() %}
+{%- if wrapped_types.len() > 0 %}
+// Wrapped types
+{%- for (name, prim) in wrapped_types %}
+{%- match config.wrapped_type(name).type_name() %}
+{%- when Some with (type_name) %}
+typealias {{ name|class_name_kt }} = {{ type_name }}
+{%- when None %}
+typealias {{ name|class_name_kt }} = {{ prim|type_kt }}
+{%- endmatch %}
+{%- endfor %}
+{% endif %}
 // Public facing enums
 {% for e in ci.iter_enum_definitions() %}
 {% include "EnumTemplate.kt" %}
 {%- endfor -%}
 
+{%- let flags = ci.iter_flags_definitions() %}
+{%- if flags.len() > 0 %}
+// Flags
+{%- for f in flags %}
+{% include "FlagsTemplate.kt" %}
+{%- endfor %}
+{% endif %}
+
 // Error definitions
 {% include "ErrorTemplate.kt" %}
 
@@ -51,6 +90,13 @@ import kotlin.concurrent.withLock
 {% include "RecordTemplate.kt" %}
 {% endfor %}
 
+{%- let constants = ci.iter_constant_definitions() %}
+{%- if constants.len() > 0 %}
+// Constants
+{%- for constant in constants %}
+{% call kt::docstring(constant, 0) %}const val {{ constant.name()|const_name_kt }}: {{ constant.type_()|type_kt }} = {{ constant.value()|literal_kt }}
+{%- endfor %}
+{% endif %}
 // Namespace functions
 {% for func in ci.iter_function_definitions() %}
 {% include "TopLevelFunctionTemplate.kt" %}
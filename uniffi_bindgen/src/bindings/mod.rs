@@ -98,6 +98,56 @@ impl MergeWith for Config {
     }
 }
 
+/// How the bindings for one language represent a `[Wrapped]` type.
+///
+/// By default, the foreign code sees the primitive type that's passed to Rust. This can be
+/// changed in `uniffi.toml`, in a table named after the wrapped type, for example:
+///
+/// ```toml
+/// [bindings.kotlin.wrapped_types.Url]
+/// type_name = "URL"
+/// imports = ["java.net.URL"]
+/// lift = "URL({})"
+/// lower = "{}.toString()"
+/// ```
+///
+/// In the `lift` and `lower` expressions, `{}` stands for the value being converted.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct WrappedTypeConfig {
+    type_name: Option<String>,
+    imports: Option<Vec<String>>,
+    lift: Option<String>,
+    lower: Option<String>,
+}
+
+impl WrappedTypeConfig {
+    /// The name of the foreign type, if it's not the primitive type.
+    pub fn type_name(&self) -> Option<&str> {
+        self.type_name.as_deref()
+    }
+
+    /// The modules to import for the foreign type.
+    pub fn imports(&self) -> Vec<String> {
+        self.imports.clone().unwrap_or_default()
+    }
+
+    /// An expression that converts the primitive value `nm` into the foreign type.
+    pub fn lift(&self, nm: &str) -> String {
+        match &self.lift {
+            Some(expr) => expr.replace("{}", nm),
+            None => nm.to_string(),
+        }
+    }
+
+    /// An expression that converts `nm`, of the foreign type, into the primitive value.
+    pub fn lower(&self, nm: &str) -> String {
+        match &self.lower {
+            Some(expr) => expr.replace("{}", nm),
+            None => nm.to_string(),
+        }
+    }
+}
+
 /// Generate foreign language bindings from a compiled `uniffi` library.
 pub fn write_bindings<P>(
     config: &Config,
@@ -6,7 +6,9 @@ use anyhow::Result;
 use askama::Template;
 use heck::{CamelCase, ShoutySnakeCase, SnakeCase};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
+use crate::bindings::WrappedTypeConfig;
 use crate::interface::*;
 use crate::MergeWith;
 
@@ -16,6 +18,7 @@ use crate::MergeWith;
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Config {
     cdylib_name: Option<String>,
+    wrapped_types: Option<HashMap<String, WrappedTypeConfig>>,
 }
 
 impl Config {
@@ -26,12 +29,34 @@ impl Config {
             "uniffi".into()
         }
     }
+
+    /// How the named `[Wrapped]` type is represented in Python.
+    pub fn wrapped_type(&self, name: &str) -> WrappedTypeConfig {
+        self.wrapped_types
+            .as_ref()
+            .and_then(|types| types.get(name))
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// The imports needed by the `[Wrapped]` types in the given interface.
+    pub fn wrapped_type_imports(&self, ci: &ComponentInterface) -> Vec<String> {
+        let mut imports = ci
+            .iter_wrapped_types()
+            .into_iter()
+            .flat_map(|(name, _)| self.wrapped_type(&name).imports())
+            .collect::<Vec<_>>();
+        imports.sort();
+        imports.dedup();
+        imports
+    }
 }
 
 impl From<&ComponentInterface> for Config {
     fn from(ci: &ComponentInterface) -> Self {
         Config {
             cdylib_name: Some(format!("uniffi_{}", ci.namespace())),
+            wrapped_types: None,
         }
     }
 }
@@ -40,6 +65,7 @@ impl MergeWith for Config {
     fn merge_with(&self, other: &Self) -> Self {
         Config {
             cdylib_name: self.cdylib_name.merge_with(&other.cdylib_name),
+            wrapped_types: self.wrapped_types.merge_with(&other.wrapped_types),
         }
     }
 }
@@ -75,7 +101,8 @@ mod filters {
             FFIType::RustArcPtr => "ctypes.c_void_p".to_string(),
             FFIType::RustBuffer => "RustBuffer".to_string(),
             FFIType::ForeignBytes => "ForeignBytes".to_string(),
-            FFIType::ForeignCallback => unimplemented!("Callback interfaces are not implemented"),
+            FFIType::ForeignCallback => "FOREIGN_CALLBACK_T".to_string(),
+            FFIType::ForeignWaker => "ForeignWaker".to_string(),
         })
     }
 
@@ -132,6 +159,47 @@ mod filters {
         Ok(nm.to_string().to_shouty_snake_case())
     }
 
+    pub fn const_name_py(nm: &dyn fmt::Display) -> Result<String, askama::Error> {
+        Ok(nm.to_string().to_shouty_snake_case())
+    }
+
+    /// Render a docstring as a Python string literal on a new line, indented by the given number
+    /// of spaces. It goes directly after a `class` or `def` line, or after an assignment for
+    /// attribute docstrings.
+    pub fn docstring_py(docstring: &str, spaces: &usize) -> Result<String, askama::Error> {
+        let indent = " ".repeat(*spaces);
+        let mut escaped = docstring
+            .replace('\\', r"\\")
+            .replace(r#"""""#, r#"\"\"\""#);
+        // A quote at the very end would run into the closing quotes.
+        if escaped.ends_with('"') {
+            escaped.insert(escaped.len() - 1, '\\');
+        }
+        let lines = escaped
+            .lines()
+            .enumerate()
+            .map(|(i, line)| match (i, line) {
+                (0, _) | (_, "") => line.to_string(),
+                _ => format!("{}{}", indent, line),
+            })
+            .collect::<Vec<_>>();
+        Ok(if lines.len() == 1 {
+            format!("\n{}\"\"\"{}\"\"\"", indent, lines[0])
+        } else {
+            format!("\n{0}\"\"\"{1}\n{0}\"\"\"", indent, lines.join("\n"))
+        })
+    }
+
+    /// Render a statement that emits a `DeprecationWarning` from the caller, on a new line
+    /// indented by the given number of spaces. It goes at the start of a function body.
+    pub fn deprecated_py(reason: &str, spaces: &usize) -> Result<String, askama::Error> {
+        Ok(format!(
+            "\n{}warnings.warn(\"{}\", DeprecationWarning, stacklevel=2)",
+            " ".repeat(*spaces),
+            reason
+        ))
+    }
+
     pub fn coerce_py(nm: &dyn fmt::Display, type_: &Type) -> Result<String, askama::Error> {
         Ok(match type_ {
             Type::Int8
@@ -141,26 +209,40 @@ mod filters {
             | Type::Int32
             | Type::UInt32
             | Type::Int64
-            | Type::UInt64 => format!("int({})", nm), // TODO: check max/min value
+            | Type::UInt64
+            | Type::Int128
+            | Type::UInt128 => format!("int({})", nm), // TODO: check max/min value
             Type::Float32 | Type::Float64 => format!("float({})", nm),
             Type::Boolean => format!("bool({})", nm),
+            Type::Bytes => format!("bytes({})", nm),
             Type::String
             | Type::Object(_)
             | Type::Enum(_)
             | Type::Error(_)
             | Type::Record(_)
             | Type::Timestamp
-            | Type::Duration => nm.to_string(),
-            Type::CallbackInterface(_) => panic!("No support for coercing callback interfaces yet"),
+            | Type::Duration
+            | Type::CallbackInterface(_)
+            // Wrapped types coerce their primitive value when they're lowered.
+            | Type::Wrapped { .. } => nm.to_string(),
             Type::Optional(t) => format!("(None if {} is None else {})", nm, coerce_py(nm, t)?),
             Type::Sequence(t) => format!("list({} for x in {})", coerce_py(&"x", t)?, nm),
-            Type::Map(t) => format!(
+            Type::Map(k, v) | Type::OrderedMap(k, v) => format!(
                 "dict(({},{}) for (k, v) in {}.items())",
-                coerce_py(&"k", &Type::String)?,
-                coerce_py(&"v", t)?,
+                coerce_py(&"k", k)?,
+                coerce_py(&"v", v)?,
                 nm
             ),
-            Type::Wrapped { prim, .. } => coerce_py(nm, prim.as_ref())?,
+            Type::Set(t) => format!("set({} for x in {})", coerce_py(&"x", t)?, nm),
+            Type::Tuple(ts) => format!(
+                "({},)",
+                ts.iter()
+                    .enumerate()
+                    .map(|(i, t)| coerce_py(&format!("{}[{}]", nm, i), t))
+                    .collect::<Result<Vec<_>, _>>()?
+                    .join(", ")
+            ),
+            Type::Flags { name, .. } => format!("{}({})", class_name_py(name)?, nm),
             Type::External { .. } => panic!("should not be necessary to coerce External types"),
         })
     }
@@ -179,21 +261,36 @@ mod filters {
             | Type::Float64 => nm.to_string(),
             Type::Boolean => format!("(1 if {} else 0)", nm),
             Type::String => format!("RustBuffer.allocFromString({})", nm),
+            Type::Bytes => format!("RustBuffer.allocFromBytes({})", nm),
             Type::Object(_) => format!("({}._pointer)", nm),
-            Type::CallbackInterface(_) => panic!("No support for lowering callback interfaces yet"),
+            Type::CallbackInterface(_) => format!(
+                "{}Internals.lower({})",
+                class_name_py(&type_.canonical_name())?,
+                nm
+            ),
             Type::Error(_) => panic!("No support for lowering errors, yet"),
             Type::Enum(_)
             | Type::Record(_)
             | Type::Optional(_)
             | Type::Sequence(_)
-            | Type::Map(_)
+            | Type::Map(..)
+            | Type::Set(_)
+            | Type::OrderedMap(..)
+            | Type::Tuple(_)
+            | Type::UInt128
+            | Type::Int128
             | Type::Timestamp
             | Type::Duration => format!(
                 "RustBuffer.allocFrom{}({})",
                 class_name_py(&type_.canonical_name())?,
                 nm
             ),
-            Type::Wrapped { prim, .. } => lower_py(nm, prim.as_ref())?,
+            Type::Flags { .. } => format!("{}.value", nm),
+            Type::Wrapped { .. } => format!(
+                "FfiConverter{}.lower({})",
+                class_name_py(&type_.canonical_name())?,
+                nm
+            ),
             Type::External { .. } => panic!("should not be necessary to lower External types"),
         })
     }
@@ -211,21 +308,38 @@ mod filters {
             Type::Float32 | Type::Float64 => format!("float({})", nm),
             Type::Boolean => format!("(True if {} else False)", nm),
             Type::String => format!("{}.consumeIntoString()", nm),
+            Type::Bytes => format!("{}.consumeIntoBytes()", nm),
             Type::Object(name) => format!("{}._make_instance_({})", class_name_py(name)?, nm),
-            Type::CallbackInterface(_) => panic!("No support for lifting callback interfaces, yet"),
+            Type::CallbackInterface(_) => format!(
+                "{}Internals.lift({})",
+                class_name_py(&type_.canonical_name())?,
+                nm
+            ),
             Type::Error(_) => panic!("No support for lowering errors, yet"),
             Type::Enum(_)
             | Type::Record(_)
             | Type::Optional(_)
             | Type::Sequence(_)
-            | Type::Map(_)
+            | Type::Map(..)
+            | Type::Set(_)
+            | Type::OrderedMap(..)
+            | Type::Tuple(_)
+            | Type::UInt128
+            | Type::Int128
             | Type::Timestamp
             | Type::Duration => format!(
                 "{}.consumeInto{}()",
                 nm,
                 class_name_py(&type_.canonical_name())?
             ),
-            Type::Wrapped { prim, .. } => lift_py(nm, prim.as_ref())?,
+            Type::Flags { name, prim } => {
+                format!("{}({})", class_name_py(name)?, lift_py(nm, prim.as_ref())?)
+            }
+            Type::Wrapped { .. } => format!(
+                "FfiConverter{}.lift({})",
+                class_name_py(&type_.canonical_name())?,
+                nm
+            ),
             Type::External { .. } => panic!("should not be necessary to lift External types"),
         })
     }
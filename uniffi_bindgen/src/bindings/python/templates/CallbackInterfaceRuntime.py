@@ -0,0 +1,68 @@
+class ConcurrentHandleMap:
+    """
+    A map where inserting, getting and removing data is synchronized with a lock.
+    """
+
+    def __init__(self):
+        # type Handle = int
+        self._left_map = {}  # type: Dict[Handle, Any]
+        self._lock = threading.Lock()
+        self._current_handle = 0
+        self._stride = 1
+
+    def insert(self, obj):
+        with self._lock:
+            handle = self._current_handle
+            self._current_handle += self._stride
+            self._left_map[handle] = obj
+            return handle
+
+    def get(self, handle):
+        with self._lock:
+            return self._left_map.get(handle)
+
+    def remove(self, handle):
+        with self._lock:
+            return self._left_map.pop(handle, None)
+
+# Magic number for the Rust proxy to call using the same mechanism as every other method,
+# to free the callback once it's dropped by Rust.
+IDX_CALLBACK_FREE = 0
+
+# Status codes returned by the foreign callback, telling Rust how to interpret the output buffer.
+UNIFFI_CALLBACK_SUCCESS = 0
+UNIFFI_CALLBACK_ERROR = 1
+UNIFFI_CALLBACK_UNEXPECTED_ERROR = 2
+
+class CallbackInternals:
+    """
+    Holds the handle map of callback objects that have been passed to Rust, along with the
+    ctypes function that Rust calls to invoke their methods.
+    """
+
+    def __init__(self, foreign_callback):
+        # We keep a reference to the ctypes function so that it doesn't get garbage collected
+        # while Rust is holding on to it.
+        self._foreign_callback = foreign_callback
+        self._handle_map = ConcurrentHandleMap()
+
+    def register(self, init_callback_fn):
+        rust_call(init_callback_fn, self._foreign_callback)
+
+    def lift(self, handle):
+        return self._handle_map.get(handle)
+
+    def read(self, stream):
+        return self.lift(stream._unpack_from(8, ">Q"))
+
+    def lower(self, cb):
+        return self._handle_map.insert(cb)
+
+    def write(self, cb, builder):
+        builder._pack_into(8, ">Q", self.lower(cb))
+
+    def drop(self, handle):
+        self._handle_map.remove(handle)
+
+# Declaration and FfiConverters for the callback function type that Rust uses to call into Python.
+FOREIGN_CALLBACK_T = ctypes.CFUNCTYPE(ctypes.c_int, ctypes.c_uint64, ctypes.c_uint32, RustBuffer, ctypes.POINTER(RustBuffer))
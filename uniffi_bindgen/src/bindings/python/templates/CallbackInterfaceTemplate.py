@@ -0,0 +1,83 @@
+{%- let type_name = cbi.name()|class_name_py %}
+{%- let canonical_type_name = cbi.type_().canonical_name()|class_name_py %}
+{%- let callback_internals = format!("{}Internals", canonical_type_name) %}
+{%- let foreign_callback = format!("foreignCallback{}", canonical_type_name) %}
+
+# Declaration and FfiConverters for {{ type_name }} Callback Interface
+
+class {{ type_name }}:{% call py::docstring(cbi, 4) %}
+    {%- for meth in cbi.methods() %}
+    def {{ meth.name()|fn_name_py }}(self, {% call py::arg_list_decl(meth) %}):{% call py::docstring(meth, 8) %}
+        raise NotImplementedError
+
+    {%- endfor %}
+
+
+def py_{{ foreign_callback }}(handle, method, args, buf_ptr):
+    {% for meth in cbi.methods() -%}
+    {% let method_name = format!("invoke_{}", meth.name())|fn_name_py %}
+    def {{ method_name }}(python_callback, args):
+        with args.consumeWithStream() as buf:
+            rval = python_callback.{{ meth.name()|fn_name_py }}(
+                {%- for arg in meth.arguments() %}
+                RustBufferTypeReader.read{{ arg.type_().canonical_name()|class_name_py }}(buf){% if !loop.last %},{% endif %}
+                {%- endfor %}
+            )
+        {%- match meth.return_type() %}
+        {%- when Some with (return_type) %}
+        with RustBuffer.allocWithBuilder() as builder:
+            RustBufferTypeBuilder.write{{ return_type.canonical_name()|class_name_py }}(builder, rval)
+            return builder.finalize()
+        {%- else %}
+        return RustBuffer(0, 0, None)
+        {%- endmatch %}
+
+    {% endfor %}
+
+    if method == IDX_CALLBACK_FREE:
+        {{ callback_internals }}.drop(handle)
+        return UNIFFI_CALLBACK_SUCCESS
+
+    cb = {{ callback_internals }}.lift(handle)
+    try:
+        if cb is None:
+            args.free()
+            raise InternalError("No callback in handlemap; this is a Uniffi bug")
+        {%- for meth in cbi.methods() %}
+        {% if loop.first %}if{% else %}elif{% endif %} method == {{ loop.index }}:
+            {%- match meth.throws_type() %}
+            {%- when Some with (throws_type) %}
+            {%- let err = ci.get_error_definition(meth.throws().unwrap()).unwrap() %}
+            try:
+                buf_ptr[0] = {{ format!("invoke_{}", meth.name())|fn_name_py }}(cb, args)
+                return UNIFFI_CALLBACK_SUCCESS
+            except ({% for variant in err.variants() %}{{ err.name()|class_name_py }}.{{ variant.name()|class_name_py }}, {% endfor %}) as e:
+                # The declared error is serialized and sent back to Rust.
+                with RustBuffer.allocWithBuilder() as builder:
+                    RustBufferTypeBuilder.write{{ throws_type.canonical_name()|class_name_py }}(builder, e)
+                    buf_ptr[0] = builder.finalize()
+                return UNIFFI_CALLBACK_ERROR
+            {%- else %}
+            buf_ptr[0] = {{ format!("invoke_{}", meth.name())|fn_name_py }}(cb, args)
+            return UNIFFI_CALLBACK_SUCCESS
+            {%- endmatch %}
+        {%- endfor %}
+        # This should never happen, because an out of bounds method index won't
+        # ever be used.
+        args.free()
+        raise InternalError("Invalid callback method index: {}".format(method))
+    except BaseException as e:
+        # Anything we didn't expect is reported to Rust as an unexpected error, along with
+        # a description of what went wrong, if we're able to provide one.
+        try:
+            buf_ptr[0] = RustBuffer.allocFromString(repr(e))
+        except BaseException:
+            # If that fails, Rust will report an unknown reason.
+            pass
+        return UNIFFI_CALLBACK_UNEXPECTED_ERROR
+
+# We need to keep this function reference alive:
+# if they get GC'd while in use then UniFFI internals could fail with an error.
+{{ foreign_callback }} = FOREIGN_CALLBACK_T(py_{{ foreign_callback }})
+{{ callback_internals }} = CallbackInternals({{ foreign_callback }})
+{{ callback_internals }}.register(_UniFFILib.{{ cbi.ffi_init_callback().name() }})
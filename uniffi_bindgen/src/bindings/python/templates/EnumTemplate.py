@@ -6,20 +6,24 @@
 #}
 {% if e.is_flat() %}
 
-class {{ e.name()|class_name_py }}(enum.Enum):
+class {{ e.name()|class_name_py }}(enum.Enum):{% call py::docstring(e, 4) %}
     {% for variant in e.variants() -%}
-    {{ variant.name()|enum_name_py }} = {{ loop.index }}
+    {{ variant.name()|enum_name_py }} = {{ variant.discr() }}{% call py::docstring(variant, 4) %}
     {% endfor %}
+    {%- if e.is_non_exhaustive() %}
+    UNKNOWN = None
+    """A variant that this version of the bindings doesn't know about."""
+    {% endif %}
 
 {% else %}
 
-class {{ e.name()|class_name_py }}(object):
+class {{ e.name()|class_name_py }}(object):{% call py::docstring(e, 4) %}
     def __init__(self):
         raise RuntimeError("{{ e.name()|class_name_py }} cannot be instantiated directly")
 
     # Each enum variant is a nested class of the enum itself.
     {% for variant in e.variants() -%}
-    class {{ variant.name()|enum_name_py }}(object):
+    class {{ variant.name()|enum_name_py }}(object):{% call py::docstring(variant, 8) %}
         def __init__(self,{% for field in variant.fields() %}{{ field.name()|var_name_py }}{% if loop.last %}{% else %}, {% endif %}{% endfor %}):
             {% if variant.has_fields() %}
             {%- for field in variant.fields() %}
@@ -23,14 +23,14 @@ class RustCallStatus(ctypes.Structure):
             return "RustCallStatus(<invalid code>)"
 {%- for e in ci.iter_error_definitions() %}
 
-class {{ e.name()|class_name_py }}:
+class {{ e.name()|class_name_py }}:{% call py::docstring(e, 4) %}
 
     {%- if e.is_flat() %}
 
     # Each variant is a nested class of the error itself.
     # It just carries a string error message, so no special implementation is necessary.
     {%- for variant in e.variants() %}
-    class {{ variant.name()|class_name_py }}(Exception):
+    class {{ variant.name()|class_name_py }}(Exception):{% call py::docstring(variant, 8) %}
         pass
     {%- endfor %}
 
@@ -38,7 +38,7 @@ class {{ e.name()|class_name_py }}:
 
     # Each variant is a nested class of the error itself.
     {%- for variant in e.variants() %}
-    class {{ variant.name()|class_name_py }}(Exception):
+    class {{ variant.name()|class_name_py }}(Exception):{% call py::docstring(variant, 8) %}
         def __init__(self{% for field in variant.fields() %}, {{ field.name()|var_name_py }}{% endfor %}):
             {%- if variant.has_fields() %}
             {%- for field in variant.fields() %}
@@ -0,0 +1,8 @@
+{#
+# Flags use the stdlib `enum.Flag`, which combines its members with `|`, and
+# is passed to Rust as the integer `value` of the combination.
+#}
+class {{ f.name()|class_name_py }}(enum.Flag):{% call py::docstring(f, 4) %}
+    {% for flag in f.flags() -%}
+    {{ flag.name()|enum_name_py }} = {{ flag.value() }}{% call py::docstring(flag, 4) %}
+    {% endfor %}
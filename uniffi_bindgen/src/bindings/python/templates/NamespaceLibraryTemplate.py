@@ -31,3 +31,22 @@ _UniFFILib.{{ func.name() }}.argtypes = (
 )
 _UniFFILib.{{ func.name() }}.restype = {% match func.return_type() %}{% when Some with (type_) %}{{ type_|type_ffi }}{% when None %}None{% endmatch %}
 {%- endfor %}
+
+# Check that the library we loaded was built from the same interface as these bindings,
+# so that a mismatch fails loudly here rather than with undefined behaviour on a later call.
+
+def uniffiCheckContractApiVersion(lib):
+    bindings_contract_version = {{ ci.uniffi_contract_version() }}
+    scaffolding_contract_version = rust_call(lib.{{ ci.ffi_uniffi_contract_version().name() }})
+    if bindings_contract_version != scaffolding_contract_version:
+        raise InternalError("UniFFI contract version mismatch: bindings expect {} but library has {}; bindings do not match library, try regenerating them".format(bindings_contract_version, scaffolding_contract_version))
+
+def uniffiCheckApiChecksums(lib):
+    {%- for checksum in ci.iter_checksums() %}
+    if rust_call(lib.{{ checksum.ffi_func().name() }}) != {{ checksum.checksum() }}:
+        raise InternalError("UniFFI API checksum mismatch for {{ checksum.item_name() }}: bindings do not match library, try regenerating them")
+    {%- endfor %}
+    pass
+
+uniffiCheckContractApiVersion(_UniFFILib)
+uniffiCheckApiChecksums(_UniFFILib)
@@ -1,7 +1,7 @@
-class {{ obj.name()|class_name_py }}(object):
+class {{ obj.name()|class_name_py }}(object):{% call py::docstring(obj, 4) %}
     {%- match obj.primary_constructor() %}
     {%- when Some with (cons) %}
-    def __init__(self, {% call py::arg_list_decl(cons) -%}):
+    def __init__(self, {% call py::arg_list_decl(cons) -%}):{% call py::docstring(cons, 8) %}{% call py::deprecated(cons, 8) %}
         {%- call py::coerce_args_extra_indent(cons) %}
         self._pointer = {% call py::to_ffi_call(cons) %}
     {%- when None %}
@@ -24,25 +24,91 @@ class {{ obj.name()|class_name_py }}(object):
 
     {% for cons in obj.alternate_constructors() -%}
     @classmethod
-    def {{ cons.name()|fn_name_py }}(cls, {% call py::arg_list_decl(cons) %}):
+    def {{ cons.name()|fn_name_py }}(cls, {% call py::arg_list_decl(cons) %}):{% call py::docstring(cons, 8) %}{% call py::deprecated(cons, 8) %}
         {%- call py::coerce_args_extra_indent(cons) %}
         # Call the (fallible) function before creating any half-baked object instances.
         pointer = {% call py::to_ffi_call(cons) %}
         return cls._make_instance_(pointer)
     {% endfor %}
 
-    {% for meth in obj.methods() -%}
+    {% for meth in obj.static_methods() -%}
+    @staticmethod
+    {%- if meth.is_async() %}
+    async def {{ meth.name()|fn_name_py }}({% call py::arg_list_decl(meth) %}):{% call py::docstring(meth, 8) %}{% call py::deprecated(meth, 8) %}
+        {%- call py::coerce_args_extra_indent(meth) %}
+        _rust_future = {% call py::to_ffi_call(meth) %}
+        return await {% call py::rust_future_await("_rust_future", meth) %}
+    {%- else %}
+    def {{ meth.name()|fn_name_py }}({% call py::arg_list_decl(meth) %}):{% call py::docstring(meth, 8) %}{% call py::deprecated(meth, 8) %}
+        {%- call py::coerce_args_extra_indent(meth) %}
+        {% match meth.return_type() -%}
+        {%- when Some with (return_type) -%}
+        _retval = {% call py::to_ffi_call(meth) %}
+        return {{ "_retval"|lift_py(return_type) }}
+        {%- when None -%}
+        {% call py::to_ffi_call(meth) %}
+        {%- endmatch %}
+    {% endif %}
+    {% endfor %}
+
+    {% for meth in obj.instance_methods() -%}
+    {%- if meth.is_async() -%}
+    async def {{ meth.name()|fn_name_py }}(self, {% call py::arg_list_decl(meth) %}):{% call py::docstring(meth, 8) %}{% call py::deprecated(meth, 8) %}
+        {%- call py::coerce_args_extra_indent(meth) %}
+        _rust_future = {% call py::to_ffi_call_with_prefix("self._pointer", meth) %}
+        return await {% call py::rust_future_await("_rust_future", meth) %}
+    {% else -%}
     {%- match meth.return_type() -%}
 
     {%- when Some with (return_type) -%}
-    def {{ meth.name()|fn_name_py }}(self, {% call py::arg_list_decl(meth) %}):
+    def {{ meth.name()|fn_name_py }}(self, {% call py::arg_list_decl(meth) %}):{% call py::docstring(meth, 8) %}{% call py::deprecated(meth, 8) %}
         {%- call py::coerce_args_extra_indent(meth) %}
         _retval = {% call py::to_ffi_call_with_prefix("self._pointer", meth) %}
         return {{ "_retval"|lift_py(return_type) }}
 
     {%- when None -%}
-    def {{ meth.name()|fn_name_py }}(self, {% call py::arg_list_decl(meth) %}):
+    def {{ meth.name()|fn_name_py }}(self, {% call py::arg_list_decl(meth) %}):{% call py::docstring(meth, 8) %}{% call py::deprecated(meth, 8) %}
         {%- call py::coerce_args_extra_indent(meth) %}
         {% call py::to_ffi_call_with_prefix("self._pointer", meth) %}
     {% endmatch %}
+    {%- endif %}
+    {% endfor %}
+
+    {%- for prop in obj.properties() %}
+    @property
+    def {{ prop.name()|var_name_py }}(self):{% call py::docstring(prop, 8) %}
+        _retval = {% call py::to_ffi_call_with_prefix("self._pointer", prop.getter()) %}
+        return {{ "_retval"|lift_py(prop.type_()) }}
+    {%- match prop.setter() %}
+    {%- when Some with (setter) %}
+
+    @{{ prop.name()|var_name_py }}.setter
+    def {{ prop.name()|var_name_py }}(self, value):
+        {%- call py::coerce_args_extra_indent(setter) %}
+        {% call py::to_ffi_call_with_prefix("self._pointer", setter) %}
+    {%- else %}
+    {%- endmatch %}
+    {% endfor %}
+
+    {%- for trait_impl in obj.trait_impls() %}
+    {%- match trait_impl.trait_() %}
+    {%- when ObjectTrait::Debug %}
+    def __repr__(self):
+        _retval = rust_call(_UniFFILib.{{ trait_impl.ffi_func().name() }}, self._pointer)
+        return {{ "_retval"|lift_py(trait_impl.return_type()) }}
+    {%- when ObjectTrait::Display %}
+    def __str__(self):
+        _retval = rust_call(_UniFFILib.{{ trait_impl.ffi_func().name() }}, self._pointer)
+        return {{ "_retval"|lift_py(trait_impl.return_type()) }}
+    {%- when ObjectTrait::Eq %}
+    def __eq__(self, other):
+        if not isinstance(other, {{ obj.name()|class_name_py }}):
+            return NotImplemented
+        _retval = rust_call(_UniFFILib.{{ trait_impl.ffi_func().name() }}, self._pointer, other._pointer)
+        return {{ "_retval"|lift_py(trait_impl.return_type()) }}
+    {%- when ObjectTrait::Hash %}
+    def __hash__(self):
+        _retval = rust_call(_UniFFILib.{{ trait_impl.ffi_func().name() }}, self._pointer)
+        return {{ "_retval"|lift_py(trait_impl.return_type()) }}
+    {%- endmatch %}
     {% endfor %}
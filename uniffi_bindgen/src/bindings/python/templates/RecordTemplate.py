@@ -1,7 +1,7 @@
-class {{ rec.name()|class_name_py }}(object):
+class {{ rec.name()|class_name_py }}(object):{% call py::docstring(rec, 4) %}
     def __init__(self,{% for field in rec.fields() %}{{ field.name()|var_name_py }}{% if loop.last %}{% else %}, {% endif %}{% endfor %}):
         {%- for field in rec.fields() %}
-        self.{{ field.name()|var_name_py }} = {{ field.name()|var_name_py }}
+        self.{{ field.name()|var_name_py }} = {{ field.name()|var_name_py }}{% call py::docstring(field, 8) %}
         {%- endfor %}
 
     def __str__(self):
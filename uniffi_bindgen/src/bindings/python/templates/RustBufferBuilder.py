@@ -121,6 +121,29 @@ class RustBufferTypeBuilder(object):
         builder._pack_into(4, ">i", len(utf8Bytes))
         builder.write(utf8Bytes)
 
+    {% when Type::UInt128 -%}
+
+    @staticmethod
+    def writeU128(builder, v):
+        if not 0 <= v < 2**128:
+            raise ValueError("{} is out of range for a u128".format(v))
+        builder.write(v.to_bytes(16, "big", signed=False))
+
+    {% when Type::Int128 -%}
+
+    @staticmethod
+    def writeI128(builder, v):
+        if not -2**127 <= v < 2**127:
+            raise ValueError("{} is out of range for an i128".format(v))
+        builder.write(v.to_bytes(16, "big", signed=True))
+
+    {% when Type::Bytes -%}
+
+    @staticmethod
+    def writeBytes(builder, v):
+        builder._pack_into(4, ">i", len(v))
+        builder.write(v)
+
     {% when Type::Timestamp -%}
 
     @staticmethod
@@ -160,6 +183,14 @@ class RustBufferTypeBuilder(object):
         # and will fail to compile if they don't fit in that size.
         cls.writeU64(builder, v._pointer)
 
+    {% when Type::CallbackInterface with (interface_name) -%}
+    # The Callback Interface type {{ interface_name }}.
+    # We register the callback object in a handle map, and write the handle.
+
+    @staticmethod
+    def write{{ canonical_type_name }}(builder, v):
+        {{ canonical_type_name }}Internals.write(v, builder)
+
     {% when Type::Enum with (enum_name) -%}
     {%- let e = ci.get_enum_definition(enum_name).unwrap() -%}
     # The Enum type {{ enum_name }}.
@@ -167,17 +198,46 @@ class RustBufferTypeBuilder(object):
     @classmethod
     def write{{ canonical_type_name }}(cls, builder, v):
         {%- if e.is_flat() %}
+        {%- if e.is_non_exhaustive() %}
+        if v is {{ enum_name|class_name_py }}.UNKNOWN:
+            raise ValueError("{{ enum_name|class_name_py }}.UNKNOWN can't be passed to Rust")
+        {%- endif %}
         builder._pack_into(4, ">i", v.value)
         {%- else -%}
         {%- for variant in e.variants() %}
         if v.is_{{ variant.name()|var_name_py }}():
-            builder._pack_into(4, ">i", {{ loop.index }})
+            builder._pack_into(4, ">i", {{ variant.discr() }})
             {%- for field in variant.fields() %}
             cls.write{{ field.type_().canonical_name()|class_name_py }}(builder, v.{{ field.name() }})
             {%- endfor %}
         {%- endfor %}
         {%- endif %}
 
+    {% when Type::Error with (error_name) -%}
+    {%- let e = ci.get_error_definition(error_name).unwrap() %}
+    {%- if ci.is_callback_interface_throws_type(typ) %}
+    # The Error type {{ error_name }}.
+    # Errors are only written when they are thrown by a callback interface and sent back to Rust.
+
+    @classmethod
+    def write{{ canonical_type_name }}(cls, builder, v):
+        {%- for variant in e.variants() %}
+        if isinstance(v, {{ error_name|class_name_py }}.{{ variant.name()|class_name_py }}):
+            builder._pack_into(4, ">i", {{ loop.index }})
+            {%- for field in variant.fields() %}
+            cls.write{{ field.type_().canonical_name()|class_name_py }}(builder, v.{{ field.name()|var_name_py }})
+            {%- endfor %}
+            return
+        {%- endfor %}
+        raise InternalError("Unexpected value for error {{ error_name }}: {!r}".format(v))
+    {%- else %}
+    # This type cannot currently be serialized, but we can produce a helpful error.
+
+    @staticmethod
+    def write{{ canonical_type_name }}(self, builder):
+        raise InternalError("RustBufferStream.write() not implemented yet for {{ canonical_type_name }}")
+    {%- endif %}
+
     {% when Type::Record with (record_name) -%}
     {%- let rec = ci.get_record_definition(record_name).unwrap() -%}
     # The Record type {{ record_name }}.
@@ -208,21 +268,57 @@ class RustBufferTypeBuilder(object):
         for item in items:
             cls.write{{ inner_type.canonical_name()|class_name_py }}(builder, item)
 
-    {% when Type::Map with (inner_type) -%}
-    # The Map<T> type for {{ inner_type.canonical_name() }}.
+    {% when Type::Map with (key_type, value_type) -%}
+    # The Map<K, V> type for {{ key_type.canonical_name() }} and {{ value_type.canonical_name() }}.
 
     @classmethod
     def write{{ canonical_type_name }}(cls, builder, items):
         builder._pack_into(4, ">i", len(items))
         for (k, v) in items.items():
-            cls.writeString(builder, k)
-            cls.write{{ inner_type.canonical_name()|class_name_py }}(builder, v)
+            cls.write{{ key_type.canonical_name()|class_name_py }}(builder, k)
+            cls.write{{ value_type.canonical_name()|class_name_py }}(builder, v)
+
+    {% when Type::Set with (inner_type) -%}
+    # The Set<T> type for {{ inner_type.canonical_name() }}.
+
+    @classmethod
+    def write{{ canonical_type_name }}(cls, builder, items):
+        builder._pack_into(4, ">i", len(items))
+        for item in items:
+            cls.write{{ inner_type.canonical_name()|class_name_py }}(builder, item)
+
+    {% when Type::OrderedMap with (key_type, value_type) -%}
+    # The ordered Map<K, V> type for {{ key_type.canonical_name() }} and {{ value_type.canonical_name() }}.
+
+    @classmethod
+    def write{{ canonical_type_name }}(cls, builder, items):
+        builder._pack_into(4, ">i", len(items))
+        for (k, v) in sorted(items.items()):
+            cls.write{{ key_type.canonical_name()|class_name_py }}(builder, k)
+            cls.write{{ value_type.canonical_name()|class_name_py }}(builder, v)
+
+    {% when Type::Tuple with (element_types) -%}
+    # The tuple type {{ canonical_type_name }}.
+
+    @classmethod
+    def write{{ canonical_type_name }}(cls, builder, v):
+        {%- for element_type in element_types %}
+        cls.write{{ element_type.canonical_name()|class_name_py }}(builder, v[{{ loop.index0 }}])
+        {%- endfor %}
+
+    {% when Type::Flags with { name, prim } %}
+    # The Flags type {{ name }}.
+
+    @classmethod
+    def write{{ canonical_type_name }}(cls, builder, v):
+        cls.write{{ prim.canonical_name()|class_name_py }}(builder, v.value)
 
     {% when Type::Wrapped with { name, prim } %}
+    # The Wrapped type {{ name }}.
 
     @classmethod
     def write{{ canonical_type_name }}(cls, builder, v):
-        cls.write{{ prim.canonical_name()|class_name_py }}(builder, v)
+        cls.write{{ prim.canonical_name()|class_name_py }}(builder, FfiConverter{{ canonical_type_name }}.unwrap(v))
 
     {%- when Type::External with { name, crate_name } %}
 
@@ -118,6 +118,27 @@ class RustBufferTypeReader(object):
         utf8Bytes = stream.read(size)
         return utf8Bytes.decode("utf-8")
 
+    {% when Type::UInt128 -%}
+
+    @staticmethod
+    def readU128(stream):
+        return int.from_bytes(stream.read(16), "big", signed=False)
+
+    {% when Type::Int128 -%}
+
+    @staticmethod
+    def readI128(stream):
+        return int.from_bytes(stream.read(16), "big", signed=True)
+
+    {% when Type::Bytes -%}
+
+    @staticmethod
+    def readBytes(stream):
+        size = stream._unpack_from(4, ">i")
+        if size < 0:
+            raise InternalError("Unexpected negative byte string length")
+        return stream.read(size)
+
 
     {% when Type::Timestamp -%}
     # The Timestamp type.
@@ -157,6 +178,13 @@ class RustBufferTypeReader(object):
         pointer = stream._unpack_from(8, ">Q")
         return {{ object_name|class_name_py }}._make_instance_(pointer)
 
+    {% when Type::CallbackInterface with (interface_name) -%}
+    # The Callback Interface type {{ interface_name }}.
+
+    @staticmethod
+    def read{{ canonical_type_name }}(stream):
+        return {{ canonical_type_name }}Internals.read(stream)
+
     {% when Type::Enum with (enum_name) -%}
     {%- let e = ci.get_enum_definition(enum_name).unwrap() -%}
     # The Enum type {{ enum_name }}.
@@ -165,10 +193,17 @@ class RustBufferTypeReader(object):
     def read{{ canonical_type_name }}(cls, stream):
         variant = stream._unpack_from(4, ">i")
         {% if e.is_flat() -%}
+        {% if e.is_non_exhaustive() -%}
+        try:
+            return {{ enum_name|class_name_py }}(variant)
+        except ValueError:
+            return {{ enum_name|class_name_py }}.UNKNOWN
+        {%- else -%}
         return {{ enum_name|class_name_py }}(variant)
+        {%- endif %}
         {%- else -%}
         {%- for variant in e.variants() %}
-        if variant == {{ loop.index }}:
+        if variant == {{ variant.discr() }}:
             {%- if variant.has_fields() %}
             return {{ enum_name|class_name_py }}.{{ variant.name()|enum_name_py }}(
                 {%- for field in variant.fields() %}
@@ -256,8 +291,36 @@ class RustBufferTypeReader(object):
             count -= 1
         return items
 
-    {% when Type::Map with (inner_type) -%}
-    # The Map<T> type for {{ inner_type.canonical_name() }}.
+    {% when Type::Map with (key_type, value_type) -%}
+    # The Map<K, V> type for {{ key_type.canonical_name() }} and {{ value_type.canonical_name() }}.
+
+    @classmethod
+    def read{{ canonical_type_name }}(cls, stream):
+        count = stream._unpack_from(4, ">i")
+        if count < 0:
+            raise InternalError("Unexpected negative map size")
+        items = {}
+        while count > 0:
+            key = cls.read{{ key_type.canonical_name()|class_name_py }}(stream)
+            items[key] = cls.read{{ value_type.canonical_name()|class_name_py }}(stream)
+            count -= 1
+        return items
+
+    {% when Type::Set with (inner_type) -%}
+    # The Set<T> type for {{ inner_type.canonical_name() }}.
+
+    @classmethod
+    def read{{ canonical_type_name }}(cls, stream):
+        count = stream._unpack_from(4, ">i")
+        if count < 0:
+            raise InternalError("Unexpected negative set size")
+        return set(
+            cls.read{{ inner_type.canonical_name()|class_name_py }}(stream) for _ in range(count)
+        )
+
+    {% when Type::OrderedMap with (key_type, value_type) -%}
+    # The ordered Map<K, V> type for {{ key_type.canonical_name() }} and {{ value_type.canonical_name() }}.
+    # Rust writes the entries in key order, and a `dict` keeps them in that order.
 
     @classmethod
     def read{{ canonical_type_name }}(cls, stream):
@@ -266,16 +329,35 @@ class RustBufferTypeReader(object):
             raise InternalError("Unexpected negative map size")
         items = {}
         while count > 0:
-            key = cls.readString(stream)
-            items[key] = cls.read{{ inner_type.canonical_name()|class_name_py }}(stream)
+            key = cls.read{{ key_type.canonical_name()|class_name_py }}(stream)
+            items[key] = cls.read{{ value_type.canonical_name()|class_name_py }}(stream)
             count -= 1
         return items
 
+    {% when Type::Tuple with (element_types) -%}
+    # The tuple type {{ canonical_type_name }}.
+
+    @classmethod
+    def read{{ canonical_type_name }}(cls, stream):
+        return (
+            {%- for element_type in element_types %}
+            cls.read{{ element_type.canonical_name()|class_name_py }}(stream),
+            {%- endfor %}
+        )
+
+    {% when Type::Flags with { name, prim } -%}
+    # The Flags type {{ name }}.
+
+    @classmethod
+    def read{{ canonical_type_name }}(cls, stream):
+        return {{ name|class_name_py }}(cls.read{{ prim.canonical_name()|class_name_py }}(stream))
+
     {% when Type::Wrapped with { name, prim } -%}
+    # The Wrapped type {{ name }}.
 
     @classmethod
     def read{{ canonical_type_name }}(cls, stream):
-        return cls.read{{ prim.canonical_name()|class_name_py }}(stream)
+        return FfiConverter{{ canonical_type_name }}.wrap(cls.read{{ prim.canonical_name()|class_name_py }}(stream))
 
     {% when Type::External with { name, crate_name } -%}
 
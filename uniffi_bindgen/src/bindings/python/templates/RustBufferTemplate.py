@@ -76,6 +76,43 @@ class RustBuffer(ctypes.Structure):
         with self.consumeWithStream() as stream:
             return stream.read(stream.remaining()).decode("utf-8")
 
+    {% when Type::Bytes -%}
+    # The primitive bytes type.
+
+    @staticmethod
+    def allocFromBytes(value):
+        # Rust copies the bytes straight out of the Python object, rather than via a builder.
+        data = ctypes.cast(ctypes.c_char_p(value), ctypes.POINTER(ctypes.c_char))
+        return rust_call(_UniFFILib.{{ ci.ffi_rustbuffer_from_bytes().name() }}, ForeignBytes(len(value), data))
+
+    def consumeIntoBytes(self):
+        with self.consumeWithStream() as stream:
+            return stream.read(stream.remaining())
+
+    {% when Type::UInt128 -%}
+
+    @staticmethod
+    def allocFromU128(v):
+        with RustBuffer.allocWithBuilder() as builder:
+            RustBufferTypeBuilder.writeU128(builder, v)
+            return builder.finalize()
+
+    def consumeIntoU128(self):
+        with self.consumeWithStream() as stream:
+            return RustBufferTypeReader.readU128(stream)
+
+    {% when Type::Int128 -%}
+
+    @staticmethod
+    def allocFromI128(v):
+        with RustBuffer.allocWithBuilder() as builder:
+            RustBufferTypeBuilder.writeI128(builder, v)
+            return builder.finalize()
+
+    def consumeIntoI128(self):
+        with self.consumeWithStream() as stream:
+            return RustBufferTypeReader.readI128(stream)
+
     {% when Type::Timestamp -%}
 
     @staticmethod
@@ -154,8 +191,47 @@ class RustBuffer(ctypes.Structure):
         with self.consumeWithStream() as stream:
             return RustBufferTypeReader.read{{ canonical_type_name }}(stream)
 
-    {% when Type::Map with (inner_type) -%}
-    # The Map<T> type for {{ inner_type.canonical_name() }}.
+    {% when Type::Map with (key_type, value_type) -%}
+    # The Map<K, V> type for {{ key_type.canonical_name() }} and {{ value_type.canonical_name() }}.
+
+    @staticmethod
+    def allocFrom{{ canonical_type_name }}(v):
+        with RustBuffer.allocWithBuilder() as builder:
+            RustBufferTypeBuilder.write{{ canonical_type_name }}(builder, v)
+            return builder.finalize()
+
+    def consumeInto{{ canonical_type_name }}(self):
+        with self.consumeWithStream() as stream:
+            return RustBufferTypeReader.read{{ canonical_type_name }}(stream)
+
+    {% when Type::Set with (inner_type) -%}
+    # The Set<T> type for {{ inner_type.canonical_name() }}.
+
+    @staticmethod
+    def allocFrom{{ canonical_type_name }}(v):
+        with RustBuffer.allocWithBuilder() as builder:
+            RustBufferTypeBuilder.write{{ canonical_type_name }}(builder, v)
+            return builder.finalize()
+
+    def consumeInto{{ canonical_type_name }}(self):
+        with self.consumeWithStream() as stream:
+            return RustBufferTypeReader.read{{ canonical_type_name }}(stream)
+
+    {% when Type::OrderedMap with (key_type, value_type) -%}
+    # The ordered Map<K, V> type for {{ key_type.canonical_name() }} and {{ value_type.canonical_name() }}.
+
+    @staticmethod
+    def allocFrom{{ canonical_type_name }}(v):
+        with RustBuffer.allocWithBuilder() as builder:
+            RustBufferTypeBuilder.write{{ canonical_type_name }}(builder, v)
+            return builder.finalize()
+
+    def consumeInto{{ canonical_type_name }}(self):
+        with self.consumeWithStream() as stream:
+            return RustBufferTypeReader.read{{ canonical_type_name }}(stream)
+
+    {% when Type::Tuple with (element_types) -%}
+    # The tuple type {{ canonical_type_name }}.
 
     @staticmethod
     def allocFrom{{ canonical_type_name }}(v):
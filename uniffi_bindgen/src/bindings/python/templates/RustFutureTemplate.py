@@ -0,0 +1,58 @@
+# Async functions and methods return a pointer to a `RustFuture`, which we drive to completion
+# from the asyncio event loop using the poll/wake protocol from the `uniffi` runtime.
+
+ForeignWaker = ctypes.CFUNCTYPE(None, ctypes.c_uint64)
+
+class _RustFutureWakers(object):
+    # Maps the opaque `waker_data` handles that we pass to Rust onto the asyncio futures that
+    # are waiting to be woken. Rust may call the waker from any thread, so this is locked.
+
+    def __init__(self):
+        self._lock = threading.Lock()
+        self._next_handle = 1
+        self._waiting = {}
+
+    def insert(self, loop, woken):
+        with self._lock:
+            handle = self._next_handle
+            self._next_handle += 1
+            self._waiting[handle] = (loop, woken)
+            return handle
+
+    def remove(self, handle):
+        with self._lock:
+            return self._waiting.pop(handle, None)
+
+_rust_future_wakers = _RustFutureWakers()
+
+def _rust_future_set_woken(woken):
+    if not woken.done():
+        woken.set_result(None)
+
+@ForeignWaker
+def _rust_future_waker(handle):
+    entry = _rust_future_wakers.remove(handle)
+    if entry is not None:
+        loop, woken = entry
+        loop.call_soon_threadsafe(_rust_future_set_woken, woken)
+
+async def rust_future_await(rust_future, read_method, error_class):
+    # Poll the future until it's ready, waiting for the waker in between polls, then take
+    # its result. The future is freed even if we're cancelled, which also cancels it in Rust.
+    loop = asyncio.get_running_loop()
+    handle = None
+    try:
+        while True:
+            woken = loop.create_future()
+            handle = _rust_future_wakers.insert(loop, woken)
+            if rust_call(_UniFFILib.{{ ci.ffi_rust_future_poll().name() }}, rust_future, _rust_future_waker, handle):
+                break
+            await woken
+        result = rust_call_with_error(error_class, _UniFFILib.{{ ci.ffi_rust_future_complete().name() }}, rust_future)
+        with result.consumeWithStream() as stream:
+            if read_method is not None:
+                return read_method(stream)
+    finally:
+        if handle is not None:
+            _rust_future_wakers.remove(handle)
+        rust_call(_UniFFILib.{{ ci.ffi_rust_future_free().name() }}, rust_future)
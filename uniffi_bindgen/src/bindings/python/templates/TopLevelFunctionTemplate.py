@@ -1,14 +1,23 @@
+{%- if func.is_async() %}
+
+async def {{ func.name()|fn_name_py }}({%- call py::arg_list_decl(func) -%}):{% call py::docstring(func, 4) %}{% call py::deprecated(func, 4) %}
+    {%- call py::coerce_args(func) %}
+    _rust_future = {% call py::to_ffi_call(func) %}
+    return await {% call py::rust_future_await("_rust_future", func) %}
+
+{% else -%}
 {%- match func.return_type() -%}
 {%- when Some with (return_type) %}
 
-def {{ func.name()|fn_name_py }}({%- call py::arg_list_decl(func) -%}):
+def {{ func.name()|fn_name_py }}({%- call py::arg_list_decl(func) -%}):{% call py::docstring(func, 4) %}{% call py::deprecated(func, 4) %}
     {%- call py::coerce_args(func) %}
     _retval = {% call py::to_ffi_call(func) %}
     return {{ "_retval"|lift_py(return_type) }}
 
 {% when None -%}
 
-def {{ func.name()|fn_name_py }}({%- call py::arg_list_decl(func) -%}):
+def {{ func.name()|fn_name_py }}({%- call py::arg_list_decl(func) -%}):{% call py::docstring(func, 4) %}{% call py::deprecated(func, 4) %}
     {%- call py::coerce_args(func) %}
     {% call py::to_ffi_call(func) %}
-{% endmatch %}
\ No newline at end of file
+{% endmatch %}
+{%- endif %}
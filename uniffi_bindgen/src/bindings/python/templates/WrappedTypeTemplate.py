@@ -0,0 +1,23 @@
+{#
+# Wrapped types are passed as their primitive type. Python code sees the primitive
+# value, unless `uniffi.toml` gives expressions to convert it to and from another type.
+#}
+{%- let ffi_converter = format!("FfiConverterType{}", name)|class_name_py %}
+{%- let wrapped = config.wrapped_type(name) %}
+class {{ ffi_converter }}:
+    @staticmethod
+    def wrap(builtin_value):
+        return {{ wrapped.lift("builtin_value") }}
+
+    @staticmethod
+    def unwrap(value):
+        return {{ wrapped.lower("value") }}
+
+    @staticmethod
+    def lift(value):
+        return {{ ffi_converter }}.wrap({{ "value"|lift_py(prim) }})
+
+    @staticmethod
+    def lower(value):
+        builtin_value = {{ "{}.unwrap(value)"|format(ffi_converter)|coerce_py(prim) }}
+        return {{ "builtin_value"|lower_py(prim) }}
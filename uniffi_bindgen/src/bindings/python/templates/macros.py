@@ -4,6 +4,30 @@
 // passed to rust via `_arg_list_ffi_call` (we use  `var_name_py` in `lower_py`)
 #}
 
+{#
+// Render the docstring of an item, if it has one. Call it at the end of the line that
+// declares the item, passing the indentation of the item's body in spaces.
+#}
+{%- macro docstring(defn, indent) -%}
+{%- match defn.docstring() -%}
+{%- when Some with (docstring) -%}
+{{ docstring|docstring_py(indent) }}
+{%- else -%}
+{%- endmatch -%}
+{%- endmacro -%}
+
+{#
+// Warn when a deprecated function is called. Call it at the end of the line that declares
+// the function, after any docstring, passing the indentation of the function body in spaces.
+#}
+{%- macro deprecated(func, indent) -%}
+{%- match func.deprecated() -%}
+{%- when Some with (reason) -%}
+{{ reason|deprecated_py(indent) }}
+{%- else -%}
+{%- endmatch -%}
+{%- endmacro -%}
+
 {%- macro to_ffi_call(func) -%}
     {%- match func.throws() -%}
     {%- when Some with (e) -%}
@@ -30,6 +54,18 @@ rust_call(
 )
 {%- endmacro -%}
 
+{#-
+// Await the `RustFuture` returned from calling an async function or method,
+// reading the result (if any) from the `RustBuffer` that it completes with.
+-#}
+{%- macro rust_future_await(var, func) -%}
+rust_future_await(
+    {{- var }},
+    {%- match func.return_type() %}{% when Some with (return_type) %} RustBufferTypeReader.read{{ return_type.canonical_name()|class_name_py }}{% else %} None{% endmatch %},
+    {%- match func.throws() %}{% when Some with (e) %} {{ e|class_name_py }}{% else %} None{% endmatch -%}
+)
+{%- endmacro -%}
+
 {%- macro _arg_list_ffi_call(func) %}
     {%- for arg in func.arguments() %}
         {{- arg.name()|lower_py(arg.type_()) }}
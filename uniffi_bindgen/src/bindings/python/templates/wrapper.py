@@ -1,5 +1,10 @@
 # This file was autogenerated by some hot garbage in the `uniffi` crate.
 # Trust me, you don't want to mess with it!
+{%- match ci.namespace_docstring() %}
+{%- when Some with (docstring) %}
+{{ docstring|docstring_py(0) }}
+{%- else %}
+{%- endmatch %}
 
 # Common helper code.
 #
@@ -17,9 +22,19 @@ import os
 import sys
 import ctypes
 import enum
+import warnings
 import struct
 import contextlib
 import datetime
+{%- for import in config.wrapped_type_imports(ci) %}
+import {{ import }}
+{%- endfor %}
+{%- if ci.has_async_functions() %}
+import asyncio
+{%- endif %}
+{%- if ci.has_async_functions() || ci.iter_callback_interface_definitions().len() > 0 %}
+import threading
+{%- endif %}
 
 {% include "RustBufferTemplate.py" %}
 {% include "RustBufferStream.py" %}
@@ -28,6 +43,22 @@ import datetime
 # Error definitions
 {% include "ErrorTemplate.py" %}
 
+{%- if ci.has_async_functions() %}
+
+{% include "RustFutureTemplate.py" %}
+{%- endif %}
+
+{%- if ci.iter_callback_interface_definitions().len() > 0 %}
+
+{% include "CallbackInterfaceRuntime.py" %}
+{%- endif %}
+
+{%- for (name, prim) in ci.iter_wrapped_types() %}
+
+{% include "WrappedTypeTemplate.py" %}
+{%- endfor %}
+
+
 {% include "NamespaceLibraryTemplate.py" %}
 
 # Public interface members begin here.
@@ -36,10 +67,18 @@ import datetime
 {% include "EnumTemplate.py" %}
 {%- endfor -%}
 
+{%- for f in ci.iter_flags_definitions() %}
+{% include "FlagsTemplate.py" %}
+{%- endfor -%}
+
 {%- for rec in ci.iter_record_definitions() %}
 {% include "RecordTemplate.py" %}
 {% endfor %}
 
+{%- for constant in ci.iter_constant_definitions() %}
+{{ constant.name()|const_name_py }} = {{ constant.value()|literal_py }}{% call py::docstring(constant, 0) %}
+{%- endfor %}
+
 {% for func in ci.iter_function_definitions() %}
 {% include "TopLevelFunctionTemplate.py" %}
 {% endfor %}
@@ -48,14 +87,24 @@ import datetime
 {% include "ObjectTemplate.py" %}
 {% endfor %}
 
+{%- for cbi in ci.iter_callback_interface_definitions() %}
+{% include "CallbackInterfaceTemplate.py" %}
+{% endfor %}
+
 __all__ = [
     "InternalError",
     {%- for e in ci.iter_enum_definitions() %}
     "{{ e.name()|class_name_py }}",
     {%- endfor %}
+    {%- for f in ci.iter_flags_definitions() %}
+    "{{ f.name()|class_name_py }}",
+    {%- endfor %}
     {%- for record in ci.iter_record_definitions() %}
     "{{ record.name()|class_name_py }}",
     {%- endfor %}
+    {%- for constant in ci.iter_constant_definitions() %}
+    "{{ constant.name()|const_name_py }}",
+    {%- endfor %}
     {%- for func in ci.iter_function_definitions() %}
     "{{ func.name()|fn_name_py }}",
     {%- endfor %}
@@ -65,6 +114,9 @@ __all__ = [
     {%- for e in ci.iter_error_definitions() %}
     "{{ e.name()|class_name_py }}",
     {%- endfor %}
+    {%- for c in ci.iter_callback_interface_definitions() %}
+    "{{ c.name()|class_name_py }}",
+    {%- endfor %}
 ]
 
 {% import "macros.py" as py %}
@@ -95,7 +95,8 @@ mod filters {
             FFIType::RustArcPtr => ":pointer".to_string(),
             FFIType::RustBuffer => "RustBuffer.by_value".to_string(),
             FFIType::ForeignBytes => "ForeignBytes".to_string(),
-            FFIType::ForeignCallback => unimplemented!("Callback interfaces are not implemented"),
+            FFIType::ForeignCallback => ":foreign_callback".to_string(),
+            FFIType::ForeignWaker => ":foreign_waker".to_string(),
         })
     }
 
@@ -151,6 +152,35 @@ mod filters {
         Ok(nm.to_string().to_shouty_snake_case())
     }
 
+    pub fn const_name_rb(nm: &dyn fmt::Display) -> Result<String, askama::Error> {
+        Ok(nm.to_string().to_shouty_snake_case())
+    }
+
+    /// Render a docstring as `#` comments for YARD, indenting every line after the first by the
+    /// given number of spaces, and leaving the documented item to follow on its own line.
+    pub fn docstring_rb(docstring: &str, spaces: &usize) -> Result<String, askama::Error> {
+        let indent = " ".repeat(*spaces);
+        let lines = docstring
+            .lines()
+            .map(|line| format!("#{}{}", if line.is_empty() { "" } else { " " }, line))
+            .collect::<Vec<_>>();
+        Ok(format!(
+            "{}\n{}",
+            lines.join(&format!("\n{}", indent)),
+            indent
+        ))
+    }
+
+    /// Render a statement that warns the caller about using something deprecated, on a new line
+    /// indented by the given number of spaces. It goes at the start of a method body.
+    pub fn deprecated_rb(reason: &str, spaces: &usize) -> Result<String, askama::Error> {
+        Ok(format!(
+            "\n{}warn(\"Deprecated: {}\", uplevel: 1)",
+            " ".repeat(*spaces),
+            reason
+        ))
+    }
+
     pub fn coerce_rb(nm: &dyn fmt::Display, type_: &Type) -> Result<String, askama::Error> {
         Ok(match type_ {
             Type::Int8
@@ -160,14 +190,19 @@ mod filters {
             | Type::Int32
             | Type::UInt32
             | Type::Int64
-            | Type::UInt64 => format!("{}.to_i", nm), // TODO: check max/min value
+            | Type::UInt64
+            | Type::Int128
+            | Type::UInt128 => format!("{}.to_i", nm), // TODO: check max/min value
             Type::Float32 | Type::Float64 => format!("{}.to_f", nm),
             Type::Boolean => format!("{} ? true : false", nm),
-            Type::Object(_) | Type::Enum(_) | Type::Error(_) | Type::Record(_) => nm.to_string(),
-            Type::String => format!("{}.to_s", nm),
-            Type::Timestamp => panic!("No support for timestamps in Ruby, yet"),
-            Type::Duration => panic!("No support for durations in Ruby, yet"),
-            Type::CallbackInterface(_) => panic!("No support for coercing callback interfaces yet"),
+            Type::Object(_)
+            | Type::Enum(_)
+            | Type::Error(_)
+            | Type::Record(_)
+            | Type::CallbackInterface(_)
+            | Type::Timestamp
+            | Type::Duration => nm.to_string(),
+            Type::String | Type::Bytes => format!("{}.to_s", nm),
             Type::Optional(t) => format!("({} ? {} : nil)", nm, coerce_rb(nm, t)?),
             Type::Sequence(t) => {
                 let coerce_code = coerce_rb(&"v", t)?;
@@ -177,9 +212,17 @@ mod filters {
                     format!("{}.map {{ |v| {} }}", nm, coerce_code)
                 }
             }
-            Type::Map(t) => {
-                let k_coerce_code = coerce_rb(&"k", &Type::String)?;
-                let v_coerce_code = coerce_rb(&"v", t)?;
+            Type::Set(t) => {
+                let coerce_code = coerce_rb(&"v", t)?;
+                if coerce_code == "v" {
+                    format!("{}.to_set", nm)
+                } else {
+                    format!("{}.map {{ |v| {} }}.to_set", nm, coerce_code)
+                }
+            }
+            Type::Map(k, v) | Type::OrderedMap(k, v) => {
+                let k_coerce_code = coerce_rb(&"k", k)?;
+                let v_coerce_code = coerce_rb(&"v", v)?;
 
                 if k_coerce_code == "k" && v_coerce_code == "v" {
                     nm.to_string()
@@ -190,6 +233,15 @@ mod filters {
                     )
                 }
             }
+            Type::Tuple(ts) => format!(
+                "[{}]",
+                ts.iter()
+                    .enumerate()
+                    .map(|(i, t)| coerce_rb(&format!("{}[{}]", nm, i), t))
+                    .collect::<Result<Vec<_>, _>>()?
+                    .join(", ")
+            ),
+            Type::Flags { prim, .. } => coerce_rb(nm, prim)?,
             Type::External { .. } => panic!("No support for external types, yet"),
             Type::Wrapped { .. } => panic!("No support for wrapped types, yet"),
         })
@@ -209,20 +261,31 @@ mod filters {
             | Type::Float64 => nm.to_string(),
             Type::Boolean => format!("({} ? 1 : 0)", nm),
             Type::String => format!("RustBuffer.allocFromString({})", nm),
-            Type::Timestamp => panic!("No support for timestamps in Ruby, yet"),
-            Type::Duration => panic!("No support for durations in Ruby, yet"),
+            Type::Bytes => format!("RustBuffer.allocFromBytes({})", nm),
             Type::Object(name) => format!("({}._uniffi_lower {})", class_name_rb(name)?, nm),
-            Type::CallbackInterface(_) => panic!("No support for lowering callback interfaces yet"),
+            Type::CallbackInterface(_) => format!(
+                "{}Internals.lower({})",
+                class_name_rb(&type_.canonical_name())?,
+                nm
+            ),
             Type::Error(_) => panic!("No support for lowering errors, yet"),
             Type::Enum(_)
             | Type::Record(_)
             | Type::Optional(_)
             | Type::Sequence(_)
-            | Type::Map(_) => format!(
+            | Type::Map(..)
+            | Type::Set(_)
+            | Type::OrderedMap(..)
+            | Type::Tuple(_)
+            | Type::UInt128
+            | Type::Int128
+            | Type::Timestamp
+            | Type::Duration => format!(
                 "RustBuffer.alloc_from_{}({})",
                 class_name_rb(&type_.canonical_name())?,
                 nm
             ),
+            Type::Flags { prim, .. } => lower_rb(nm, prim)?,
             Type::External { .. } => panic!("No support for lowering external types, yet"),
             Type::Wrapped { .. } => panic!("No support for lowering wrapped types, yet"),
         })
@@ -241,20 +304,31 @@ mod filters {
             Type::Float32 | Type::Float64 => format!("{}.to_f", nm),
             Type::Boolean => format!("1 == {}", nm),
             Type::String => format!("{}.consumeIntoString", nm),
-            Type::Timestamp => panic!("No support for timestamps in Ruby, yet"),
-            Type::Duration => panic!("No support for durations in Ruby, yet"),
+            Type::Bytes => format!("{}.consumeIntoBytes", nm),
             Type::Object(name) => format!("{}._uniffi_allocate({})", class_name_rb(name)?, nm),
-            Type::CallbackInterface(_) => panic!("No support for lifting callback interfaces, yet"),
+            Type::CallbackInterface(_) => format!(
+                "{}Internals.lift({})",
+                class_name_rb(&type_.canonical_name())?,
+                nm
+            ),
             Type::Error(_) => panic!("No support for lowering errors, yet"),
             Type::Enum(_)
             | Type::Record(_)
             | Type::Optional(_)
             | Type::Sequence(_)
-            | Type::Map(_) => format!(
+            | Type::Map(..)
+            | Type::Set(_)
+            | Type::OrderedMap(..)
+            | Type::Tuple(_)
+            | Type::UInt128
+            | Type::Int128
+            | Type::Timestamp
+            | Type::Duration => format!(
                 "{}.consumeInto{}",
                 nm,
                 class_name_rb(&type_.canonical_name())?
             ),
+            Type::Flags { prim, .. } => lift_rb(nm, prim)?,
             Type::External { .. } => panic!("No support for lifting external types, yet"),
             Type::Wrapped { .. } => panic!("No support for lifting wrapped types, yet"),
         })
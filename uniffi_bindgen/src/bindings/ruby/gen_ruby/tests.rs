@@ -1,4 +1,4 @@
-use super::{is_reserved_word, Config};
+use super::{filters::docstring_rb, is_reserved_word, Config};
 
 #[test]
 fn when_reserved_word() {
@@ -45,3 +45,12 @@ fn cdylib_path() {
     assert_eq!("/foo/bar", config.cdylib_path());
     assert!(config.custom_cdylib_path());
 }
+
+#[test]
+fn docstring() {
+    assert_eq!("# Hello.\n", docstring_rb("Hello.", &0).unwrap());
+    assert_eq!(
+        "# Hello,\n  #\n  # world.\n  ",
+        docstring_rb("Hello,\n\nworld.", &2).unwrap()
+    );
+}
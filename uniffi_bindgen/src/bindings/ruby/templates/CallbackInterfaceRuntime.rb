@@ -0,0 +1,76 @@
+# A map where inserting, getting and removing data is synchronized with a lock.
+class ConcurrentHandleMap
+  def initialize
+    @lock = Mutex.new
+    @next_handle = 0
+    @objects = {}
+  end
+
+  def insert(obj)
+    @lock.synchronize do
+      handle = @next_handle
+      @next_handle += 1
+      @objects[handle] = obj
+      handle
+    end
+  end
+
+  def get(handle)
+    @lock.synchronize do
+      @objects[handle]
+    end
+  end
+
+  def remove(handle)
+    @lock.synchronize do
+      @objects.delete(handle)
+    end
+  end
+end
+
+# Magic number for the Rust proxy to call using the same mechanism as every other method,
+# to free the callback once it's dropped by Rust.
+IDX_CALLBACK_FREE = 0
+
+# Status codes returned by the foreign callback, telling Rust how to interpret the output buffer.
+UNIFFI_CALLBACK_SUCCESS = 0
+UNIFFI_CALLBACK_ERROR = 1
+UNIFFI_CALLBACK_UNEXPECTED_ERROR = 2
+
+# Holds the handle map of callback objects that have been passed to Rust, along with the
+# FFI callback that Rust uses to invoke their methods.
+class CallbackInternals
+  def initialize(foreign_callback)
+    # We keep a reference to the callback so that it doesn't get garbage collected
+    # while Rust is holding on to it.
+    @foreign_callback = foreign_callback
+    @handle_map = ConcurrentHandleMap.new
+  end
+
+  def register(init_fn_name)
+    {{ ci.namespace()|class_name_rb }}.rust_call(init_fn_name, @foreign_callback)
+  end
+
+  def lift(handle)
+    @handle_map.get(handle)
+  end
+
+  def lower(cb)
+    @handle_map.insert(cb)
+  end
+
+  def drop(handle)
+    @handle_map.remove(handle)
+  end
+
+  # Copy a RustBuffer into the out-pointer that Rust passed to the callback.
+  def self.write_out_buffer(buf_ptr, rbuf)
+    out = RustBuffer.new buf_ptr
+    out[:capacity] = rbuf.capacity
+    out[:len] = rbuf.len
+    out[:data] = rbuf.data
+  end
+end
+
+private_constant :ConcurrentHandleMap, :CallbackInternals, :IDX_CALLBACK_FREE,
+                 :UNIFFI_CALLBACK_SUCCESS, :UNIFFI_CALLBACK_ERROR, :UNIFFI_CALLBACK_UNEXPECTED_ERROR
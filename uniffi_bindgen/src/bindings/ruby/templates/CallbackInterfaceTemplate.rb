@@ -0,0 +1,107 @@
+{%- let type_name = cbi.name()|class_name_rb %}
+{%- let canonical_type_name = cbi.type_().canonical_name()|class_name_rb %}
+{%- let callback_internals = format!("{}Internals", canonical_type_name) %}
+{%- let foreign_callback = format!("foreign_callback_{}", cbi.name())|fn_name_rb %}
+# Declaration for the {{ type_name }} callback interface.
+# Ruby objects passed to Rust only need to respond to these methods, but you can
+# include this module to document that they implement the interface.
+{% call rb::docstring(cbi, 0) %}module {{ type_name }}
+  {%- for meth in cbi.methods() %}
+  {% call rb::docstring(meth, 2) %}def {{ meth.name()|fn_name_rb }}({% call rb::arg_list_decl(meth) %})
+    raise NotImplementedError
+  end
+  {% endfor %}
+end
+{% for meth in cbi.methods() %}
+{%- let method_name = format!("invoke_{}_{}", cbi.name(), meth.name())|fn_name_rb %}
+# Read the arguments for {{ type_name }}.{{ meth.name()|fn_name_rb }}, call it, and return the serialized result.
+def self.{{ method_name }}(ruby_callback, args)
+  rval = nil
+  args.consumeWithStream do |stream|
+    rval = ruby_callback.{{ meth.name()|fn_name_rb }}(
+      {%- for arg in meth.arguments() %}
+      stream.read{{ arg.type_().canonical_name()|class_name_rb }}{% if !loop.last %},{% endif %}
+      {%- endfor %}
+    )
+  end
+  {%- match meth.return_type() %}
+  {%- when Some with (return_type) %}
+
+  RustBuffer.allocWithBuilder do |builder|
+    builder.write_{{ return_type.canonical_name()|class_name_rb }}(rval)
+    builder.finalize
+  end
+  {%- else %}
+
+  # Void methods leave the output buffer empty.
+  nil
+  {%- endmatch %}
+end
+
+private_class_method :{{ method_name }}
+{% endfor %}
+# Called by Rust to invoke a method on the Ruby implementation of {{ type_name }},
+# identified by its handle. It must never raise, since that would unwind through Rust.
+def self.{{ foreign_callback }}(handle, method, args, buf_ptr)
+  if method == IDX_CALLBACK_FREE
+    {{ callback_internals }}.drop(handle)
+    return UNIFFI_CALLBACK_SUCCESS
+  end
+
+  cb = {{ callback_internals }}.lift(handle)
+  if cb.nil?
+    args.free
+    raise InternalError, 'No callback in handlemap; this is a UniFFI bug'
+  end
+
+  case method
+  {%- for meth in cbi.methods() %}
+  {%- let method_name = format!("invoke_{}_{}", cbi.name(), meth.name())|fn_name_rb %}
+  when {{ loop.index }}
+    {%- match meth.throws_type() %}
+    {%- when Some with (throws_type) %}
+    {%- let err = ci.get_error_definition(meth.throws().unwrap()).unwrap() %}
+    begin
+      ret_buf = {{ method_name }}(cb, args)
+    rescue {% for variant in err.variants() %}{{ err.name()|class_name_rb }}::{{ variant.name()|class_name_rb }}{% if !loop.last %}, {% endif %}{% endfor %}=> e
+      # The declared error is serialized and sent back to Rust.
+      err_buf = RustBuffer.allocWithBuilder do |builder|
+        builder.write_{{ throws_type.canonical_name()|class_name_rb }}(e)
+        builder.finalize
+      end
+      CallbackInternals.write_out_buffer(buf_ptr, err_buf)
+      return UNIFFI_CALLBACK_ERROR
+    end
+    {%- else %}
+    ret_buf = {{ method_name }}(cb, args)
+    {%- endmatch %}
+  {%- endfor %}
+  else
+    # This should never happen, because an out of bounds method index won't ever be used.
+    args.free
+    raise InternalError, "Invalid callback method index: #{method}"
+  end
+
+  CallbackInternals.write_out_buffer(buf_ptr, ret_buf) unless ret_buf.nil?
+  UNIFFI_CALLBACK_SUCCESS
+rescue Exception => e # rubocop:disable Lint/RescueException
+  # Anything we didn't expect is reported to Rust as an unexpected error, along with
+  # a description of what went wrong, if we're able to provide one.
+  begin
+    CallbackInternals.write_out_buffer(buf_ptr, RustBuffer.allocFromString(e.inspect))
+  rescue Exception # rubocop:disable Lint/RescueException
+    # If that fails, Rust will report an unknown reason.
+  end
+  UNIFFI_CALLBACK_UNEXPECTED_ERROR
+end
+
+private_class_method :{{ foreign_callback }}
+
+# N.B. the callback is held by a constant so that it isn't garbage-collected while Rust holds it.
+{{ callback_internals }} = CallbackInternals.new(
+  Proc.new do |handle, method, args, buf_ptr|
+    {{ foreign_callback }}(handle, method, args, buf_ptr)
+  end
+)
+{{ callback_internals }}.register(:{{ cbi.ffi_init_callback().name() }})
+private_constant :{{ callback_internals }}
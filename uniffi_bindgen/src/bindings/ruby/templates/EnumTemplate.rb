@@ -1,21 +1,25 @@
 {% if e.is_flat() %}
 
-class {{ e.name()|class_name_rb }}
+{% call rb::docstring(e, 0) %}class {{ e.name()|class_name_rb }}
   {% for variant in e.variants() -%}
-  {{ variant.name()|enum_name_rb }} = {{ loop.index }}
+  {% call rb::docstring(variant, 2) %}{{ variant.name()|enum_name_rb }} = {{ variant.discr() }}
   {% endfor %}
+  {%- if e.is_non_exhaustive() %}
+  # A variant that this version of the bindings doesn't know about.
+  UNKNOWN = :unknown
+  {% endif %}
 end
 
 {% else %}
 
-class {{ e.name()|class_name_rb }}
+{% call rb::docstring(e, 0) %}class {{ e.name()|class_name_rb }}
   def initialize
     raise RuntimeError, '{{ e.name()|class_name_rb }} cannot be instantiated directly'
   end
 
   # Each enum variant is a nested class of the enum itself.
   {% for variant in e.variants() -%}
-  class {{ variant.name()|enum_name_rb }}
+  {% call rb::docstring(variant, 2) %}class {{ variant.name()|enum_name_rb }}
     {% if variant.has_fields() %}
     attr_reader {% for field in variant.fields() %}:{{ field.name()|var_name_rb }}{% if loop.last %}{% else %}, {% endif %}{%- endfor %}
     {% endif %}
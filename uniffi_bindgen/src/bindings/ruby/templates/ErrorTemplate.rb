@@ -21,14 +21,14 @@ CALL_ERROR = 1
 CALL_PANIC = 2
 {%- for e in ci.iter_error_definitions() %}
 {% if e.is_flat() %}
-class {{ e.name()|class_name_rb }}
+{% call rb::docstring(e, 0) %}class {{ e.name()|class_name_rb }}
     {%- for variant in e.variants() %}
-    {{ variant.name()|class_name_rb }} = Class.new StandardError
+    {% call rb::docstring(variant, 4) %}{{ variant.name()|class_name_rb }} = Class.new StandardError
     {%- endfor %}
 {% else %}
-module {{ e.name()|class_name_rb }}
+{% call rb::docstring(e, 0) %}module {{ e.name()|class_name_rb }}
   {%- for variant in e.variants() %}
-  class {{ variant.name()|class_name_rb }} < StandardError
+  {% call rb::docstring(variant, 2) %}class {{ variant.name()|class_name_rb }} < StandardError
     def initialize({% for field in variant.fields() %}{{ field.name()|var_name_rb }}{% if !loop.last %}, {% endif %}{% endfor %})
         {%- for field in variant.fields() %}
         @{{ field.name()|var_name_rb }} = {{ field.name()|var_name_rb }}
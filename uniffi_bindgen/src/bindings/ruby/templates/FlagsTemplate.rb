@@ -0,0 +1,10 @@
+{#
+# Flags are passed around as the integer that holds their bits, so they're just
+# a module of constants, which are combined with `|` and checked with `&`.
+#}
+{% call rb::docstring(f, 0) %}module {{ f.name()|class_name_rb }}
+  {% for flag in f.flags() -%}
+  {% call rb::docstring(flag, 2) %}{{ flag.name()|enum_name_rb }} = {{ flag.value() }}
+  {% endfor %}
+end
+
@@ -9,9 +9,40 @@ module UniFFILib
   ffi_lib '{{ config.cdylib_name() }}'
   {% endif %}
 
+  {%- if ci.has_async_functions() %}
+
+  callback :foreign_waker, [:uint64], :void
+  {%- endif %}
+
+  {%- if ci.iter_callback_interface_definitions().len() > 0 %}
+
+  callback :foreign_callback, [:uint64, :uint32, RustBuffer.by_value, :pointer], :int
+  {%- endif %}
+
   {% for func in ci.iter_ffi_function_definitions() -%}
   attach_function :{{ func.name() }},
     {%- call rb::arg_list_ffi_decl(func) %},
     {% match func.return_type() %}{% when Some with (type_) %}{{ type_|type_ffi }}{% when None %}:void{% endmatch %}
   {% endfor %}
 end
+
+# Check that the library we loaded was built from the same interface as these bindings,
+# so that a mismatch fails loudly here rather than with undefined behaviour on a later call.
+def self.uniffi_check_contract_api_version
+  bindings_contract_version = {{ ci.uniffi_contract_version() }}
+  scaffolding_contract_version = rust_call(:{{ ci.ffi_uniffi_contract_version().name() }})
+  if bindings_contract_version != scaffolding_contract_version
+    raise InternalError, "UniFFI contract version mismatch: bindings expect #{bindings_contract_version} but library has #{scaffolding_contract_version}; bindings do not match library, try regenerating them"
+  end
+end
+
+def self.uniffi_check_api_checksums
+  {%- for checksum in ci.iter_checksums() %}
+  if rust_call(:{{ checksum.ffi_func().name() }}) != {{ checksum.checksum() }}
+    raise InternalError, "UniFFI API checksum mismatch for {{ checksum.item_name() }}: bindings do not match library, try regenerating them"
+  end
+  {%- endfor %}
+end
+
+uniffi_check_contract_api_version
+uniffi_check_api_checksums
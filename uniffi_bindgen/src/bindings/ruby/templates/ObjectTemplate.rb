@@ -1,4 +1,4 @@
-class {{ obj.name()|class_name_rb }}
+{% call rb::docstring(obj, 0) %}class {{ obj.name()|class_name_rb }}
 
   # A private helper for initializing instances of the class from a raw pointer,
   # bypassing any initialization logic and ensuring they are GC'd properly.
@@ -34,7 +34,7 @@ class {{ obj.name()|class_name_rb }}
 
   {%- match obj.primary_constructor() %}
   {%- when Some with (cons) %}
-  def initialize({% call rb::arg_list_decl(cons) -%})
+  {% call rb::docstring(cons, 2) %}def initialize({% call rb::arg_list_decl(cons) -%}){% call rb::deprecated(cons, 4) %}
     {%- call rb::coerce_args_extra_indent(cons) %}
     pointer = {% call rb::to_ffi_call(cons) %}
     @pointer = pointer
@@ -44,7 +44,7 @@ class {{ obj.name()|class_name_rb }}
   {%- endmatch %}
 
   {% for cons in obj.alternate_constructors() -%}
-  def self.{{ cons.name()|fn_name_rb }}({% call rb::arg_list_decl(cons) %})
+  {% call rb::docstring(cons, 2) %}def self.{{ cons.name()|fn_name_rb }}({% call rb::arg_list_decl(cons) %}){% call rb::deprecated(cons, 4) %}
     {%- call rb::coerce_args_extra_indent(cons) %}
     # Call the (fallible) function before creating any half-baked object instances.
     # Lightly yucky way to bypass the usual "initialize" logic
@@ -53,21 +53,91 @@ class {{ obj.name()|class_name_rb }}
   end
   {% endfor %}
 
-  {% for meth in obj.methods() -%}
+  {% for meth in obj.static_methods() %}
+  {% call rb::docstring(meth, 2) %}def self.{{ meth.name()|fn_name_rb }}({% call rb::arg_list_decl(meth) %}){% call rb::deprecated(meth, 4) %}
+    {%- call rb::coerce_args_extra_indent(meth) %}
+    {%- if meth.is_async() %}
+    rust_future = {% call rb::to_ffi_call(meth) %}
+    return {% call rb::rust_future_await("rust_future", meth) %}
+    {%- else %}
+    {%- match meth.return_type() %}
+    {%- when Some with (return_type) %}
+    result = {% call rb::to_ffi_call(meth) %}
+    return {{ "result"|lift_rb(return_type) }}
+    {%- when None %}
+    {% call rb::to_ffi_call(meth) %}
+    {%- endmatch %}
+    {%- endif %}
+  end
+  {% endfor %}
+
+  {% for meth in obj.instance_methods() -%}
+  {%- if meth.is_async() -%}
+  {% call rb::docstring(meth, 2) %}def {{ meth.name()|fn_name_rb }}({% call rb::arg_list_decl(meth) %}){% call rb::deprecated(meth, 4) %}
+    {%- call rb::coerce_args_extra_indent(meth) %}
+    rust_future = {% call rb::to_ffi_call_with_prefix("@pointer", meth) %}
+    return {% call rb::rust_future_await("rust_future", meth) %}
+  end
+  {% else -%}
   {%- match meth.return_type() -%}
 
   {%- when Some with (return_type) -%}
-  def {{ meth.name()|fn_name_rb }}({% call rb::arg_list_decl(meth) %})
+  {% call rb::docstring(meth, 2) %}def {{ meth.name()|fn_name_rb }}({% call rb::arg_list_decl(meth) %}){% call rb::deprecated(meth, 4) %}
     {%- call rb::coerce_args_extra_indent(meth) %}
     result = {% call rb::to_ffi_call_with_prefix("@pointer", meth) %}
     return {{ "result"|lift_rb(return_type) }}
   end
 
   {%- when None -%}
-  def {{ meth.name()|fn_name_rb }}({% call rb::arg_list_decl(meth) %})
+  {% call rb::docstring(meth, 2) %}def {{ meth.name()|fn_name_rb }}({% call rb::arg_list_decl(meth) %}){% call rb::deprecated(meth, 4) %}
       {%- call rb::coerce_args_extra_indent(meth) %}
       {% call rb::to_ffi_call_with_prefix("@pointer", meth) %}
   end
   {% endmatch %}
+  {%- endif %}
+  {% endfor %}
+
+  {%- for prop in obj.properties() %}
+  {% call rb::docstring(prop, 2) %}def {{ prop.name()|var_name_rb }}
+    result = {% call rb::to_ffi_call_with_prefix("@pointer", prop.getter()) %}
+    return {{ "result"|lift_rb(prop.type_()) }}
+  end
+  {%- match prop.setter() %}
+  {%- when Some with (setter) %}
+
+  def {{ prop.name()|var_name_rb }}=(value)
+    {%- call rb::coerce_args_extra_indent(setter) %}
+    {% call rb::to_ffi_call_with_prefix("@pointer", setter) %}
+  end
+  {%- else %}
+  {%- endmatch %}
+  {% endfor %}
+
+  {%- for trait_impl in obj.trait_impls() %}
+  {%- match trait_impl.trait_() %}
+  {%- when ObjectTrait::Debug %}
+  def inspect
+    result = {{ ci.namespace()|class_name_rb }}.rust_call(:{{ trait_impl.ffi_func().name() }}, @pointer)
+    return {{ "result"|lift_rb(trait_impl.return_type()) }}
+  end
+  {%- when ObjectTrait::Display %}
+  def to_s
+    result = {{ ci.namespace()|class_name_rb }}.rust_call(:{{ trait_impl.ffi_func().name() }}, @pointer)
+    return {{ "result"|lift_rb(trait_impl.return_type()) }}
+  end
+  {%- when ObjectTrait::Eq %}
+  def ==(other)
+    return false unless other.is_a? self.class
+    result = {{ ci.namespace()|class_name_rb }}.rust_call(:{{ trait_impl.ffi_func().name() }}, @pointer, self.class._uniffi_lower(other))
+    return {{ "result"|lift_rb(trait_impl.return_type()) }}
+  end
+
+  alias eql? ==
+  {%- when ObjectTrait::Hash %}
+  def hash
+    result = {{ ci.namespace()|class_name_rb }}.rust_call(:{{ trait_impl.ffi_func().name() }}, @pointer)
+    return {{ "result"|lift_rb(trait_impl.return_type()) }}
+  end
+  {%- endmatch %}
   {% endfor %}
 end
@@ -1,6 +1,8 @@
 # Record type {{ rec.name() }}
-class {{ rec.name()|class_name_rb }}
-  attr_reader {% for field in rec.fields() %}:{{ field.name()|var_name_rb }}{% if loop.last %}{% else %}, {% endif %}{%- endfor %}
+{% call rb::docstring(rec, 0) %}class {{ rec.name()|class_name_rb }}
+  {%- for field in rec.fields() %}
+  {% call rb::docstring(field, 2) %}attr_reader :{{ field.name()|var_name_rb }}
+  {%- endfor %}
 
   def initialize({% for field in rec.fields() %}{{ field.name()|var_name_rb }}{% if loop.last %}{% else %}, {% endif %}{% endfor %})
     {%- for field in rec.fields() %}
@@ -105,6 +105,62 @@ class RustBufferBuilder
     write v
   end
 
+  {% when Type::UInt128 -%}
+
+  def write_U128(v)
+    raise RangeError, "#{v} is out of range for a u128" unless (0...2**128).cover?(v)
+
+    pack_into(8, 'Q>', v >> 64)
+    pack_into(8, 'Q>', v & 0xFFFF_FFFF_FFFF_FFFF)
+  end
+
+  {% when Type::Int128 -%}
+
+  def write_I128(v)
+    raise RangeError, "#{v} is out of range for an i128" unless (-2**127...2**127).cover?(v)
+
+    pack_into(8, 'q>', v >> 64)
+    pack_into(8, 'Q>', v & 0xFFFF_FFFF_FFFF_FFFF)
+  end
+
+  {% when Type::Bytes -%}
+
+  def write_Bytes(v)
+    v = v.to_s
+    pack_into 4, 'l>', v.bytesize
+    write v
+  end
+
+  {% when Type::Timestamp -%}
+  # The Timestamp type.
+
+  def write_{{ canonical_type_name }}(v)
+    seconds = v.tv_sec
+    nanoseconds = v.tv_nsec
+
+    # UniFFI conventions assume that nanoseconds count in the same direction as seconds,
+    # but Ruby always counts them forwards from the (floored) second.
+    if seconds.negative? && nanoseconds.positive?
+      seconds += 1
+      nanoseconds = 1_000_000_000 - nanoseconds
+    end
+
+    pack_into(8, 'q>', seconds)
+    pack_into(4, 'L>', nanoseconds)
+  end
+
+  {% when Type::Duration -%}
+  # The Duration type, which accepts any Numeric number of seconds.
+
+  def write_{{ canonical_type_name }}(v)
+    seconds, nanoseconds = (v.to_r * 1_000_000_000).round.divmod(1_000_000_000)
+
+    raise ArgumentError, 'Invalid duration, must be non-negative' if seconds.negative?
+
+    pack_into(8, 'Q>', seconds)
+    pack_into(4, 'L>', nanoseconds)
+  end
+
   {% when Type::Object with (object_name) -%}
   # The Object type {{ object_name }}.
 
@@ -119,11 +175,14 @@ class RustBufferBuilder
 
   def write_{{ canonical_type_name }}(v)
     {%- if e.is_flat() %}
+    {%- if e.is_non_exhaustive() %}
+    raise ArgumentError, "{{ enum_name|class_name_rb }}::UNKNOWN can't be passed to Rust" if v == {{ enum_name|class_name_rb }}::UNKNOWN
+    {%- endif %}
     pack_into(4, 'l>', v)
     {%- else -%}
     {%- for variant in e.variants() %}
     if v.{{ variant.name()|var_name_rb }}?
-      pack_into(4, 'l>', {{ loop.index }})
+      pack_into(4, 'l>', {{ variant.discr() }})
       {%- for field in variant.fields() %}
       self.write_{{ field.type_().canonical_name()|class_name_rb }}(v.{{ field.name() }})
       {%- endfor %}
@@ -132,6 +191,46 @@ class RustBufferBuilder
     {%- endif %}
  end
 
+  {% when Type::Error with (error_name) -%}
+  {%- let e = ci.get_error_definition(error_name).unwrap() %}
+  {%- if ci.is_callback_interface_throws_type(typ) %}
+  # The Error type {{ error_name }}.
+  # Errors are only written when they are raised by a callback interface and sent back to Rust.
+
+  def write_{{ canonical_type_name }}(v)
+    {%- for variant in e.variants() %}
+    if v.is_a?({{ error_name|class_name_rb }}::{{ variant.name()|class_name_rb }})
+      pack_into(4, 'l>', {{ loop.index }})
+      {%- for field in variant.fields() %}
+      self.write_{{ field.type_().canonical_name()|class_name_rb }}(v.{{ field.name() }})
+      {%- endfor %}
+      return
+    end
+    {%- endfor %}
+
+    raise InternalError, "Unexpected value for error {{ error_name }}: #{v.inspect}"
+  end
+  {%- else %}
+  # This type is not yet supported in the Ruby backend.
+  def write_{{ canonical_type_name }}(v)
+    raise InternalError('RustBufferStream.write() not implemented yet for {{ canonical_type_name }}')
+  end
+  {%- endif %}
+
+  {% when Type::CallbackInterface with (interface_name) -%}
+  # The CallbackInterface type {{ interface_name }}.
+
+  def write_{{ canonical_type_name }}(v)
+    pack_into(8, 'Q>', {{ canonical_type_name }}Internals.lower(v))
+  end
+
+  {% when Type::Flags with { name, prim } -%}
+  # The Flags type {{ name }}.
+
+  def write_{{ canonical_type_name }}(v)
+    self.write_{{ prim.canonical_name()|class_name_rb }}(v)
+  end
+
   {% when Type::Record with (record_name) -%}
   {%- let rec = ci.get_record_definition(record_name).unwrap() -%}
   # The Record type {{ record_name }}.
@@ -165,18 +264,50 @@ class RustBufferBuilder
     end
   end
 
-  {% when Type::Map with (inner_type) -%}
-  # The Map<T> type for {{ inner_type.canonical_name() }}.
+  {% when Type::Map with (key_type, value_type) -%}
+  # The Map<K, V> type for {{ key_type.canonical_name() }} and {{ value_type.canonical_name() }}.
 
   def write_{{ canonical_type_name }}(items)
     pack_into(4, 'l>', items.size)
 
     items.each do |k, v|
-      write_String(k)
-      self.write_{{ inner_type.canonical_name()|class_name_rb }}(v)
+      self.write_{{ key_type.canonical_name()|class_name_rb }}(k)
+      self.write_{{ value_type.canonical_name()|class_name_rb }}(v)
+    end
+  end
+
+  {% when Type::Set with (inner_type) -%}
+  # The Set<T> type for {{ inner_type.canonical_name() }}.
+
+  def write_{{ canonical_type_name }}(items)
+    pack_into(4, 'l>', items.size)
+
+    items.each do |item|
+      self.write_{{ inner_type.canonical_name()|class_name_rb }}(item)
+    end
+  end
+
+  {% when Type::OrderedMap with (key_type, value_type) -%}
+  # The ordered Map<K, V> type for {{ key_type.canonical_name() }} and {{ value_type.canonical_name() }}.
+
+  def write_{{ canonical_type_name }}(items)
+    pack_into(4, 'l>', items.size)
+
+    items.sort.each do |k, v|
+      self.write_{{ key_type.canonical_name()|class_name_rb }}(k)
+      self.write_{{ value_type.canonical_name()|class_name_rb }}(v)
     end
   end
 
+  {% when Type::Tuple with (element_types) -%}
+  # The tuple type {{ canonical_type_name }}, as an array.
+
+  def write_{{ canonical_type_name }}(v)
+    {%- for element_type in element_types %}
+    self.write_{{ element_type.canonical_name()|class_name_rb }}(v[{{ loop.index0 }}])
+    {%- endfor %}
+  end
+
   {%- else -%}
   # This type is not yet supported in the Ruby backend.
   def write_{{ canonical_type_name }}(v)
@@ -106,6 +106,54 @@ class RustBufferStream
     read(size).force_encoding(Encoding::UTF_8)
   end
 
+  {% when Type::UInt128 -%}
+
+  def readU128
+    high = unpack_from 8, 'Q>'
+    low = unpack_from 8, 'Q>'
+    (high << 64) | low
+  end
+
+  {% when Type::Int128 -%}
+
+  def readI128
+    high = unpack_from 8, 'q>'
+    low = unpack_from 8, 'Q>'
+    (high << 64) | low
+  end
+
+  {% when Type::Bytes -%}
+
+  def readBytes
+    size = unpack_from 4, 'l>'
+
+    raise InternalError, 'Unexpected negative byte string length' if size.negative?
+
+    read(size).force_encoding(Encoding::BINARY)
+  end
+
+  {% when Type::Timestamp -%}
+  # The Timestamp type.
+  # For pre-epoch timestamps, both the seconds and nanoseconds count backwards from the epoch.
+
+  def read{{ canonical_type_name }}
+    seconds = unpack_from 8, 'q>'
+    nanoseconds = unpack_from 4, 'L>'
+    nanoseconds = -nanoseconds if seconds.negative?
+
+    Time.at(seconds, nanoseconds, :nanosecond).utc
+  end
+
+  {% when Type::Duration -%}
+  # The Duration type, as a Rational number of seconds so that no precision is lost.
+
+  def read{{ canonical_type_name }}
+    seconds = unpack_from 8, 'Q>'
+    nanoseconds = unpack_from 4, 'L>'
+
+    seconds + Rational(nanoseconds, 1_000_000_000)
+  end
+
   {% when Type::Object with (object_name) -%}
   # The Object type {{ object_name }}.
 
@@ -122,15 +170,19 @@ class RustBufferStream
     variant = unpack_from 4, 'l>'
     {% if e.is_flat() -%}
     {%- for variant in e.variants() %}
-    if variant == {{ loop.index }}
+    if variant == {{ variant.discr() }}
       return {{ enum_name|class_name_rb }}::{{ variant.name()|enum_name_rb }}
     end
     {%- endfor %}
 
+    {% if e.is_non_exhaustive() -%}
+    {{ enum_name|class_name_rb }}::UNKNOWN
+    {%- else -%}
     raise InternalError, 'Unexpected variant tag for {{ canonical_type_name }}'
+    {%- endif %}
     {%- else -%}
     {%- for variant in e.variants() %}
-    if variant == {{ loop.index }}
+    if variant == {{ variant.discr() }}
         {%- if variant.has_fields() %}
         return {{ enum_name|class_name_rb }}::{{ variant.name()|enum_name_rb }}.new(
             {%- for field in variant.fields() %}
@@ -182,6 +234,20 @@ class RustBufferStream
     {%- endif %}
   end
 
+  {% when Type::CallbackInterface with (interface_name) -%}
+  # The CallbackInterface type {{ interface_name }}.
+
+  def read{{ canonical_type_name }}
+    {{ canonical_type_name }}Internals.lift(unpack_from 8, 'Q>')
+  end
+
+  {% when Type::Flags with { name, prim } -%}
+  # The Flags type {{ name }}.
+
+  def read{{ canonical_type_name }}
+    read{{ prim.canonical_name()|class_name_rb }}
+  end
+
   {% when Type::Record with (record_name) -%}
   {%- let rec = ci.get_record_definition(record_name).unwrap() -%}
   # The Record type {{ record_name }}.
@@ -226,8 +292,40 @@ class RustBufferStream
     items
   end
 
-  {% when Type::Map with (inner_type) -%}
-  # The Map<T> type for {{ inner_type.canonical_name() }}.
+  {% when Type::Map with (key_type, value_type) -%}
+  # The Map<K, V> type for {{ key_type.canonical_name() }} and {{ value_type.canonical_name() }}.
+
+  def read{{ canonical_type_name }}
+    count = unpack_from 4, 'l>'
+    raise InternalError, 'Unexpected negative map size' if count.negative?
+
+    items = {}
+    count.times do
+      key = read{{ key_type.canonical_name()|class_name_rb }}
+      items[key] = read{{ value_type.canonical_name()|class_name_rb }}
+    end
+
+    items
+  end
+
+  {% when Type::Set with (inner_type) -%}
+  # The Set<T> type for {{ inner_type.canonical_name() }}.
+
+  def read{{ canonical_type_name }}
+    count = unpack_from 4, 'l>'
+    raise InternalError, 'Unexpected negative set size' if count.negative?
+
+    items = Set.new
+    count.times do
+      items.add read{{ inner_type.canonical_name()|class_name_rb }}
+    end
+
+    items
+  end
+
+  {% when Type::OrderedMap with (key_type, value_type) -%}
+  # The ordered Map<K, V> type for {{ key_type.canonical_name() }} and {{ value_type.canonical_name() }}.
+  # Rust writes the entries in key order, and a `Hash` keeps them in that order.
 
   def read{{ canonical_type_name }}
     count = unpack_from 4, 'l>'
@@ -235,12 +333,23 @@ class RustBufferStream
 
     items = {}
     count.times do
-      key = readString
-      items[key] = read{{ inner_type.canonical_name()|class_name_rb }}
+      key = read{{ key_type.canonical_name()|class_name_rb }}
+      items[key] = read{{ value_type.canonical_name()|class_name_rb }}
     end
 
     items
   end
+
+  {% when Type::Tuple with (element_types) -%}
+  # The tuple type {{ canonical_type_name }}, as an array.
+
+  def read{{ canonical_type_name }}
+    [
+      {%- for element_type in element_types %}
+      read{{ element_type.canonical_name()|class_name_rb }}{% if !loop.last %},{% endif %}
+      {%- endfor %}
+    ]
+  end
   {%- else -%}
   # This type is not yet supported in the Ruby backend.
   def read{{ canonical_type_name }}
@@ -80,6 +80,22 @@ class RustBuffer < FFI::Struct
     end
   end
 
+  {% when Type::Bytes -%}
+  # The primitive bytes type, as a binary String.
+
+  def self.allocFromBytes(value)
+    RustBuffer.allocWithBuilder do |builder|
+      builder.write value
+      return builder.finalize
+    end
+  end
+
+  def consumeIntoBytes
+    consumeWithStream do |stream|
+      return stream.read(stream.remaining).force_encoding(Encoding::BINARY)
+    end
+  end
+
   {% when Type::Record with (record_name) -%}
   {%- let rec = ci.get_record_definition(record_name).unwrap() -%}
   # The Record type {{ record_name }}.
@@ -114,6 +130,70 @@ class RustBuffer < FFI::Struct
     end
   end
 
+  {% when Type::UInt128 -%}
+  # The u128 type.
+
+  def self.alloc_from_U128(v)
+    RustBuffer.allocWithBuilder do |builder|
+      builder.write_U128(v)
+      return builder.finalize
+    end
+  end
+
+  def consumeIntoU128
+    consumeWithStream do |stream|
+      return stream.readU128
+    end
+  end
+
+  {% when Type::Int128 -%}
+  # The i128 type.
+
+  def self.alloc_from_I128(v)
+    RustBuffer.allocWithBuilder do |builder|
+      builder.write_I128(v)
+      return builder.finalize
+    end
+  end
+
+  def consumeIntoI128
+    consumeWithStream do |stream|
+      return stream.readI128
+    end
+  end
+
+  {% when Type::Timestamp -%}
+  # The Timestamp type.
+
+  def self.alloc_from_{{ canonical_type_name }}(v)
+    RustBuffer.allocWithBuilder do |builder|
+      builder.write_{{ canonical_type_name }}(v)
+      return builder.finalize
+    end
+  end
+
+  def consumeInto{{ canonical_type_name }}
+    consumeWithStream do |stream|
+      return stream.read{{ canonical_type_name }}
+    end
+  end
+
+  {% when Type::Duration -%}
+  # The Duration type.
+
+  def self.alloc_from_{{ canonical_type_name }}(v)
+    RustBuffer.allocWithBuilder do |builder|
+      builder.write_{{ canonical_type_name }}(v)
+      return builder.finalize
+    end
+  end
+
+  def consumeInto{{ canonical_type_name }}
+    consumeWithStream do |stream|
+      return stream.read{{ canonical_type_name }}
+    end
+  end
+
   {% when Type::Optional with (inner_type) -%}
   # The Optional<T> type for {{ inner_type.canonical_name() }}.
 
@@ -146,8 +226,56 @@ class RustBuffer < FFI::Struct
     end
   end
 
-  {% when Type::Map with (inner_type) -%}
-  # The Map<T> type for {{ inner_type.canonical_name() }}.
+  {% when Type::Map with (key_type, value_type) -%}
+  # The Map<K, V> type for {{ key_type.canonical_name() }} and {{ value_type.canonical_name() }}.
+
+  def self.alloc_from_{{ canonical_type_name }}(v)
+    RustBuffer.allocWithBuilder do |builder|
+      builder.write_{{ canonical_type_name }}(v)
+      return builder.finalize
+    end
+  end
+
+  def consumeInto{{ canonical_type_name }}
+    consumeWithStream do |stream|
+      return stream.read{{ canonical_type_name }}
+    end
+  end
+
+  {% when Type::Set with (inner_type) -%}
+  # The Set<T> type for {{ inner_type.canonical_name() }}.
+
+  def self.alloc_from_{{ canonical_type_name }}(v)
+    RustBuffer.allocWithBuilder do |builder|
+      builder.write_{{ canonical_type_name }}(v)
+      return builder.finalize
+    end
+  end
+
+  def consumeInto{{ canonical_type_name }}
+    consumeWithStream do |stream|
+      return stream.read{{ canonical_type_name }}
+    end
+  end
+
+  {% when Type::OrderedMap with (key_type, value_type) -%}
+  # The ordered Map<K, V> type for {{ key_type.canonical_name() }} and {{ value_type.canonical_name() }}.
+
+  def self.alloc_from_{{ canonical_type_name }}(v)
+    RustBuffer.allocWithBuilder do |builder|
+      builder.write_{{ canonical_type_name }}(v)
+      return builder.finalize
+    end
+  end
+
+  def consumeInto{{ canonical_type_name }}
+    consumeWithStream do |stream|
+      return stream.read{{ canonical_type_name }}
+    end
+  end
+
+  {% when Type::Tuple with (element_types) -%}
+  # The tuple type {{ canonical_type_name }}, as an array.
 
   def self.alloc_from_{{ canonical_type_name }}(v)
     RustBuffer.allocWithBuilder do |builder|
@@ -0,0 +1,57 @@
+# Async functions and methods return a pointer to a `RustFuture`, which we drive to completion
+# using the poll/wake protocol from the `uniffi` runtime. Ruby doesn't have a standard event
+# loop for us to integrate with, so we block the calling thread until the future is ready,
+# and use the waker to signal a queue that it should be polled again.
+
+class RustFutureWakers
+  @lock = Mutex.new
+  @next_handle = 0
+  @queues = {}
+
+  def self.insert(queue)
+    @lock.synchronize do
+      handle = @next_handle
+      @next_handle += 1
+      @queues[handle] = queue
+      handle
+    end
+  end
+
+  def self.remove(handle)
+    @lock.synchronize do
+      @queues.delete(handle)
+    end
+  end
+end
+
+# N.B. this is a constant so that the callback isn't garbage-collected while Rust holds it.
+RUST_FUTURE_WAKER = Proc.new do |handle|
+  queue = RustFutureWakers.remove(handle)
+  queue.push(true) unless queue.nil?
+end
+
+def self.rust_future_await(rust_future, reader_method, error_module)
+  woken = Queue.new
+  loop do
+    handle = RustFutureWakers.insert(woken)
+    begin
+      ready = rust_call(:{{ ci.ffi_rust_future_poll().name() }}, rust_future, RUST_FUTURE_WAKER, handle)
+    rescue StandardError
+      RustFutureWakers.remove(handle)
+      raise
+    end
+    if ready != 0
+      RustFutureWakers.remove(handle)
+      break
+    end
+    woken.pop
+  end
+  result = rust_call_with_error(error_module, :{{ ci.ffi_rust_future_complete().name() }}, rust_future)
+  value = nil
+  result.consumeWithStream do |stream|
+    value = stream.send(reader_method) unless reader_method.nil?
+  end
+  value
+ensure
+  rust_call(:{{ ci.ffi_rust_future_free().name() }}, rust_future)
+end
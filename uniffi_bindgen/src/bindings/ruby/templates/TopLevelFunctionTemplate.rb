@@ -1,7 +1,16 @@
+{%- if func.is_async() %}
+
+{% call rb::docstring(func, 0) %}def self.{{ func.name()|fn_name_rb }}({%- call rb::arg_list_decl(func) -%}){% call rb::deprecated(func, 2) %}
+  {%- call rb::coerce_args(func) %}
+  rust_future = {% call rb::to_ffi_call(func) %}
+  return {% call rb::rust_future_await("rust_future", func) %}
+end
+
+{% else -%}
 {%- match func.return_type() -%}
 {%- when Some with (return_type) %}
 
-def self.{{ func.name()|fn_name_rb }}({%- call rb::arg_list_decl(func) -%})
+{% call rb::docstring(func, 0) %}def self.{{ func.name()|fn_name_rb }}({%- call rb::arg_list_decl(func) -%}){% call rb::deprecated(func, 2) %}
   {%- call rb::coerce_args(func) %}
   result = {% call rb::to_ffi_call(func) %}
   return {{ "result"|lift_rb(return_type) }}
@@ -9,8 +18,9 @@ end
 
 {% when None -%}
 
-def self.{{ func.name()|fn_name_rb }}({%- call rb::arg_list_decl(func) -%})
+{% call rb::docstring(func, 0) %}def self.{{ func.name()|fn_name_rb }}({%- call rb::arg_list_decl(func) -%}){% call rb::deprecated(func, 2) %}
   {%- call rb::coerce_args(func) %}
   {% call rb::to_ffi_call(func) %}
 end
 {% endmatch %}
+{%- endif %}
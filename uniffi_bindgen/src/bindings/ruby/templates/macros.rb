@@ -4,6 +4,30 @@
 // passed to rust via `_arg_list_ffi_call` (we use  `var_name_rb` in `lower_rb`)
 #}
 
+{#
+// Render the doc comment of an item, if it has one. Call it just before the item,
+// passing the indentation of the item in spaces.
+#}
+{%- macro docstring(defn, indent) -%}
+{%- match defn.docstring() -%}
+{%- when Some with (docstring) -%}
+{{ docstring|docstring_rb(indent) }}
+{%- else -%}
+{%- endmatch -%}
+{%- endmacro -%}
+
+{#
+// Warn when a deprecated method is called. Call it at the end of the line that declares
+// the method, passing the indentation of the method body in spaces.
+#}
+{%- macro deprecated(func, indent) -%}
+{%- match func.deprecated() -%}
+{%- when Some with (reason) -%}
+{{ reason|deprecated_rb(indent) }}
+{%- else -%}
+{%- endmatch -%}
+{%- endmacro -%}
+
 {%- macro to_ffi_call(func) -%}
     {%- match func.throws() -%}
     {%- when Some with (e) -%}
@@ -29,6 +53,18 @@
 )
 {%- endmacro -%}
 
+{#-
+// Wait for the `RustFuture` returned from calling an async function or method,
+// reading the result (if any) from the `RustBuffer` that it completes with.
+-#}
+{%- macro rust_future_await(var, func) -%}
+{{ ci.namespace()|class_name_rb }}.rust_future_await(
+    {{- var }},
+    {%- match func.return_type() %}{% when Some with (return_type) %} 'read{{ return_type.canonical_name()|class_name_rb }}'{% else %} nil{% endmatch %},
+    {%- match func.throws() %}{% when Some with (e) %} {{ e|class_name_rb }}{% else %} nil{% endmatch -%}
+)
+{%- endmacro -%}
+
 {%- macro _arg_list_ffi_call(func) %}
     {%- for arg in func.arguments() %}
         {{- arg.name()|lower_rb(arg.type_()) }}
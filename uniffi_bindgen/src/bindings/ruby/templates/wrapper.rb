@@ -14,7 +14,12 @@
 # helpers directly inline like we're doing here.
 
 require 'ffi'
+{%- if ci.contains_set_types() %}
+require 'set'
+{%- endif %}
 
+{% match ci.namespace_docstring() -%}
+{%- when Some with (docstring) %}{{ docstring|docstring_rb(0) }}{% else %}{% endmatch -%}
 module {{ ci.namespace()|class_name_rb }}
   {% include "RustBufferTemplate.rb" %}
   {% include "RustBufferStream.rb" %}
@@ -25,16 +30,34 @@ module {{ ci.namespace()|class_name_rb }}
 
   {% include "NamespaceLibraryTemplate.rb" %}
 
+  {%- if ci.has_async_functions() %}
+
+  {% include "RustFutureTemplate.rb" %}
+  {%- endif %}
+
+  {%- if ci.iter_callback_interface_definitions().len() > 0 %}
+
+  {% include "CallbackInterfaceRuntime.rb" %}
+  {%- endif %}
+
   # Public interface members begin here.
 
   {% for e in ci.iter_enum_definitions() %}
   {% include "EnumTemplate.rb" %}
   {%- endfor -%}
 
+  {%- for f in ci.iter_flags_definitions() %}
+  {% include "FlagsTemplate.rb" %}
+  {%- endfor -%}
+
   {%- for rec in ci.iter_record_definitions() %}
   {% include "RecordTemplate.rb" %}
   {% endfor %}
 
+  {%- for constant in ci.iter_constant_definitions() %}
+  {% call rb::docstring(constant, 2) %}{{ constant.name()|const_name_rb }} = {{ constant.value()|literal_rb }}
+  {%- endfor %}
+
   {% for func in ci.iter_function_definitions() %}
   {% include "TopLevelFunctionTemplate.rb" %}
   {% endfor %}
@@ -42,6 +65,10 @@ module {{ ci.namespace()|class_name_rb }}
   {% for obj in ci.iter_object_definitions() %}
   {% include "ObjectTemplate.rb" %}
   {% endfor %}
+
+  {% for cbi in ci.iter_callback_interface_definitions() %}
+  {% include "CallbackInterfaceTemplate.rb" %}
+  {% endfor %}
 end
 
 {% import "macros.rb" as rb %}
@@ -6,7 +6,9 @@ use anyhow::Result;
 use askama::Template;
 use heck::{CamelCase, MixedCase};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
+use crate::bindings::WrappedTypeConfig;
 use crate::interface::*;
 use crate::MergeWith;
 
@@ -21,6 +23,7 @@ pub struct Config {
     ffi_module_name: Option<String>,
     ffi_module_filename: Option<String>,
     generate_module_map: Option<bool>,
+    wrapped_types: Option<HashMap<String, WrappedTypeConfig>>,
 }
 
 impl Config {
@@ -71,6 +74,27 @@ impl Config {
     pub fn generate_module_map(&self) -> bool {
         self.generate_module_map.unwrap_or(true)
     }
+
+    /// How the named `[Wrapped]` type is represented in Swift.
+    pub fn wrapped_type(&self, name: &str) -> WrappedTypeConfig {
+        self.wrapped_types
+            .as_ref()
+            .and_then(|types| types.get(name))
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// The modules to import for the `[Wrapped]` types in the given interface.
+    pub fn wrapped_type_imports(&self, ci: &ComponentInterface) -> Vec<String> {
+        let mut imports = ci
+            .iter_wrapped_types()
+            .into_iter()
+            .flat_map(|(name, _)| self.wrapped_type(&name).imports())
+            .collect::<Vec<_>>();
+        imports.sort();
+        imports.dedup();
+        imports
+    }
 }
 
 impl From<&ComponentInterface> for Config {
@@ -95,6 +119,7 @@ impl MergeWith for Config {
             generate_module_map: self
                 .generate_module_map
                 .merge_with(&other.generate_module_map),
+            wrapped_types: self.wrapped_types.merge_with(&other.wrapped_types),
         }
     }
 }
@@ -180,18 +205,33 @@ mod filters {
             Type::Float64 => "Double".into(),
             Type::Boolean => "Bool".into(),
             Type::String => "String".into(),
+            Type::UInt128 => "U128".into(),
+            Type::Int128 => "I128".into(),
+            Type::Bytes => "Data".into(),
             Type::Timestamp => "Date".into(),
             Type::Duration => "TimeInterval".into(),
             Type::Enum(name)
             | Type::Record(name)
             | Type::Object(name)
             | Type::Error(name)
-            | Type::CallbackInterface(name) => class_name_swift(name)?,
+            | Type::CallbackInterface(name)
+            | Type::Flags { name, .. }
+            // Wrapped types are a `typealias` for their primitive or configured type.
+            | Type::Wrapped { name, .. } => class_name_swift(name)?,
             Type::Optional(type_) => format!("{}?", type_swift(type_)?),
             Type::Sequence(type_) => format!("[{}]", type_swift(type_)?),
-            Type::Map(type_) => format!("[String:{}]", type_swift(type_)?),
+            Type::Map(key, value) | Type::OrderedMap(key, value) => {
+                format!("[{}:{}]", type_swift(key)?, type_swift(value)?)
+            }
+            Type::Set(type_) => format!("Set<{}>", type_swift(type_)?),
+            Type::Tuple(ts) => format!(
+                "({})",
+                ts.iter()
+                    .map(type_swift)
+                    .collect::<Result<Vec<_>, _>>()?
+                    .join(", ")
+            ),
             Type::External { .. } => panic!("No support for lifting types, yet"),
-            Type::Wrapped { .. } => panic!("No support for lifting types, yet"),
         })
     }
 
@@ -213,6 +253,7 @@ mod filters {
             FFIType::RustBuffer => "RustBuffer".into(),
             FFIType::ForeignBytes => "ForeignBytes".into(),
             FFIType::ForeignCallback => "ForeignCallback".into(),
+            FFIType::ForeignWaker => "ForeignWaker".into(),
         })
     }
 
@@ -263,10 +304,40 @@ mod filters {
         })
     }
 
+    /// Whether a type is passed using a generated `FfiConverter` rather than a protocol.
+    ///
+    /// Swift tuples can't conform to protocols, so we generate a converter for each tuple type,
+    /// and for each optional, sequence or map type that contains one. Ordered maps are plain
+    /// dictionaries that need sorting on the way out, so they get a converter too. Wrapped types
+    /// are an alias for another type, which may already conform, so they also use a converter.
+    pub fn needs_ffi_converter_swift(type_: &Type) -> Result<bool, askama::Error> {
+        Ok(match type_ {
+            Type::Tuple(_) | Type::OrderedMap(..) | Type::Wrapped { .. } => true,
+            Type::Optional(t) | Type::Sequence(t) | Type::Map(_, t) => {
+                needs_ffi_converter_swift(t)?
+            }
+            _ => false,
+        })
+    }
+
+    fn ffi_converter_swift(type_: &Type) -> Result<String, askama::Error> {
+        Ok(format!(
+            "FfiConverter{}",
+            class_name_swift(&type_.canonical_name())?
+        ))
+    }
+
     /// Lower a Swift type into an FFI type.
     ///
     /// This is used to pass arguments over the FFI, from Swift to Rust.
     pub fn lower_swift(name: &dyn fmt::Display, type_: &Type) -> Result<String, askama::Error> {
+        if needs_ffi_converter_swift(type_)? {
+            return Ok(format!(
+                "{}.lower({})",
+                ffi_converter_swift(type_)?,
+                var_name_swift(name)?
+            ));
+        }
         match type_ {
             Type::Duration => Ok(format!(
                 "{}.lower{}()",
@@ -281,6 +352,9 @@ mod filters {
     ///
     /// This is used to receive values over the FFI, from Rust to Swift.
     pub fn lift_swift(name: &dyn fmt::Display, type_: &Type) -> Result<String, askama::Error> {
+        if needs_ffi_converter_swift(type_)? {
+            return Ok(format!("{}.lift({})", ffi_converter_swift(type_)?, name));
+        }
         match type_ {
             Type::Duration => Ok(format!(
                 "{}.lift{}({})",
@@ -297,6 +371,13 @@ mod filters {
     /// This is used to receive values over the FFI, when they're part of a complex type
     /// that is passed by serializing into bytes.
     pub fn read_swift(name: &dyn fmt::Display, type_: &Type) -> Result<String, askama::Error> {
+        if needs_ffi_converter_swift(type_)? {
+            return Ok(format!(
+                "{}.read(from: {})",
+                ffi_converter_swift(type_)?,
+                name
+            ));
+        }
         match type_ {
             Type::Duration => Ok(format!(
                 "{}.read{}(from: {})",
@@ -308,6 +389,34 @@ mod filters {
         }
     }
 
+    /// Write a Swift type into a byte buffer.
+    ///
+    /// This is used to pass values over the FFI, when they're part of a complex type
+    /// that is passed by serializing into bytes.
+    pub fn write_swift(
+        name: &dyn fmt::Display,
+        target: &dyn fmt::Display,
+        type_: &Type,
+    ) -> Result<String, askama::Error> {
+        if needs_ffi_converter_swift(type_)? {
+            return Ok(format!(
+                "{}.write({}, into: {})",
+                ffi_converter_swift(type_)?,
+                name,
+                target
+            ));
+        }
+        match type_ {
+            Type::Duration => Ok(format!(
+                "{}.write{}(into: {})",
+                name,
+                type_.canonical_name(),
+                target
+            )),
+            _ => Ok(format!("{}.write(into: {})", name, target)),
+        }
+    }
+
     /// Render the idiomatic Swift casing for the name of an enum.
     pub fn enum_variant_swift(nm: &dyn fmt::Display) -> Result<String, askama::Error> {
         Ok(nm.to_string().to_mixed_case())
@@ -327,4 +436,19 @@ mod filters {
     pub fn var_name_swift(nm: &dyn fmt::Display) -> Result<String, askama::Error> {
         Ok(nm.to_string().to_mixed_case())
     }
+
+    /// Render a docstring as `///` comments, indenting every line after the first by the given
+    /// number of spaces, and leaving the documented item to follow on its own line.
+    pub fn docstring_swift(docstring: &str, spaces: &usize) -> Result<String, askama::Error> {
+        let indent = " ".repeat(*spaces);
+        let lines = docstring
+            .lines()
+            .map(|line| format!("///{}{}", if line.is_empty() { "" } else { " " }, line))
+            .collect::<Vec<_>>();
+        Ok(format!(
+            "{}\n{}",
+            lines.join(&format!("\n{}", indent)),
+            indent
+        ))
+    }
 }
@@ -11,15 +11,15 @@
 // We ensure they are declared exactly once, with a header guard, UNIFFI_SHARED_H.
 #ifdef UNIFFI_SHARED_H
     // We also try to prevent mixing versions of shared uniffi header structs.
-    // If you add anything to the #else block, you must increment the version suffix in UNIFFI_SHARED_HEADER_V3
-    #ifndef UNIFFI_SHARED_HEADER_V3
+    // If you add anything to the #else block, you must increment the version suffix in UNIFFI_SHARED_HEADER_V4
+    #ifndef UNIFFI_SHARED_HEADER_V4
         #error Combining helper code from multiple versions of uniffi is not supported
-    #endif // ndef UNIFFI_SHARED_HEADER_V3
+    #endif // ndef UNIFFI_SHARED_HEADER_V4
 #else
 #define UNIFFI_SHARED_H
-#define UNIFFI_SHARED_HEADER_V3
+#define UNIFFI_SHARED_HEADER_V4
 // ⚠️ Attention: If you change this #else block (ending in `#endif // def UNIFFI_SHARED_H`) you *must* ⚠️
-// ⚠️ increment the version suffix in all instances of UNIFFI_SHARED_HEADER_V3 in this file.           ⚠️
+// ⚠️ increment the version suffix in all instances of UNIFFI_SHARED_HEADER_V4 in this file.           ⚠️
 
 typedef struct RustBuffer
 {
@@ -41,14 +41,12 @@ typedef struct RustCallStatus
     RustBuffer errorBuf;
 } RustCallStatus;
 
-typedef struct ForeignCallback
-{
-    void* _Nonnull userdata;
-    const RustBuffer (*_Nonnull invoke)(void* _Nonnull userdata, int method, RustBuffer args);
-} ForeignCallback;
+typedef int32_t (*ForeignCallback)(uint64_t handle, int32_t method, RustBuffer args, RustBuffer *_Nonnull buf_ptr);
+
+typedef void (*ForeignWaker)(uint64_t data);
 
 // ⚠️ Attention: If you change this #else block (ending in `#endif // def UNIFFI_SHARED_H`) you *must* ⚠️
-// ⚠️ increment the version suffix in all instances of UNIFFI_SHARED_HEADER_V3 in this file.           ⚠️
+// ⚠️ increment the version suffix in all instances of UNIFFI_SHARED_HEADER_V4 in this file.           ⚠️
 #endif // def UNIFFI_SHARED_H
 
 {% for func in ci.iter_ffi_function_definitions() -%}
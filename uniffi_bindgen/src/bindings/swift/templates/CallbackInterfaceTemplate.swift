@@ -3,9 +3,9 @@
 // ==============================================================================
 
 {% let type_name = cbi.name()|class_name_swift %}
-public protocol {{ type_name }}: AnyObject {
+{% call swift::docstring(cbi, 0) %}public protocol {{ type_name }}: AnyObject {
     {% for meth in cbi.methods() -%}
-    func {{ meth.name()|fn_name_swift }}({% call swift::arg_list_decl(meth) %})
+    {% call swift::docstring(meth, 4) %}{% call swift::deprecated(meth) %}func {{ meth.name()|fn_name_swift }}({% call swift::arg_list_decl(meth) %})
     {% call swift::throws(meth) %}
     {%- match meth.return_type() -%}
     {%- when Some with (return_type) %} -> {{ return_type|type_swift -}}
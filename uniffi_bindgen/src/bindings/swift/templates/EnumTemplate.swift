@@ -1,10 +1,28 @@
 
-// Note that we don't yet support `indirect` for enums.
-// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
-public enum {{ e.name()|class_name_swift }} {
+{#
+// Flat enums have the value that each variant is passed to Rust as for their raw value. Non-exhaustive
+// enums have an extra `unknown` case instead, which can't have a raw value, so they get an optional
+// `rawValue` property.
+#}
+{% call swift::docstring(e, 0) %}public {% if ci.is_recursive_type(e.name()) %}indirect enum{% else %}enum{% endif %} {{ e.name()|class_name_swift }}{% if e.is_flat() && !e.is_non_exhaustive() %}: Int32{% endif %} {
     {% for variant in e.variants() %}
-    case {{ variant.name()|enum_variant_swift }}{% if variant.fields().len() > 0 %}({% call swift::field_list_decl(variant) %}){% endif -%}
+    {% call swift::docstring(variant, 4) %}{% call swift::deprecated(variant) %}case {{ variant.name()|enum_variant_swift }}{% if variant.fields().len() > 0 %}({% call swift::field_list_decl(variant) %}){% endif -%}{% if e.is_flat() && !e.is_non_exhaustive() %} = {{ variant.discr() }}{% endif %}
     {% endfor %}
+    {%- if e.is_non_exhaustive() %}
+    /**
+     * A variant that this version of the bindings doesn't know about.
+     */
+    case unknown
+
+    public var rawValue: Int32? {
+        switch self {
+        {% for variant in e.variants() %}
+        case .{{ variant.name()|enum_variant_swift }}: return {{ variant.discr() }}
+        {%- endfor %}
+        case .unknown: return nil
+        }
+    }
+    {%- endif %}
 }
 
 extension {{ e.name()|class_name_swift }}: ViaFfiUsingByteBuffer, ViaFfi {
@@ -12,13 +30,17 @@ extension {{ e.name()|class_name_swift }}: ViaFfiUsingByteBuffer, ViaFfi {
         let variant: Int32 = try buf.readInt()
         switch variant {
         {% for variant in e.variants() %}
-        case {{ loop.index }}: return .{{ variant.name()|enum_variant_swift }}{% if variant.has_fields() -%}(
+        case {{ variant.discr() }}: return .{{ variant.name()|enum_variant_swift }}{% if variant.has_fields() -%}(
             {% for field in variant.fields() -%}
             {{ field.name()|var_name_swift }}: try {{ "buf"|read_swift(field.type_()) }}{% if loop.last %}{% else %},{% endif %}
             {% endfor -%}
         ){% endif -%}
         {% endfor %}
+        {%- if e.is_non_exhaustive() %}
+        default: return .unknown
+        {%- else %}
         default: throw UniffiInternalError.unexpectedEnumCase
+        {%- endif %}
         }
     }
 
@@ -27,19 +49,23 @@ extension {{ e.name()|class_name_swift }}: ViaFfiUsingByteBuffer, ViaFfi {
         {% for variant in e.variants() %}
         {% if variant.has_fields() %}
         case let .{{ variant.name()|enum_variant_swift }}({% for field in variant.fields() %}{{ field.name()|var_name_swift }}{%- if loop.last -%}{%- else -%},{%- endif -%}{% endfor %}):
-            buf.writeInt(Int32({{ loop.index }}))
+            buf.writeInt(Int32({{ variant.discr() }}))
             {% for field in variant.fields() -%}
-            {{ field.name()|var_name_swift }}.write(into: buf)
+            {{ field.name()|var_name_swift|write_swift("buf", field.type_()) }}
             {% endfor -%}
         {% else %}
         case .{{ variant.name()|enum_variant_swift }}:
-            buf.writeInt(Int32({{ loop.index }}))
+            buf.writeInt(Int32({{ variant.discr() }}))
         {% endif %}
         {%- endfor %}
+        {%- if e.is_non_exhaustive() %}
+        case .unknown:
+            fatalError("{{ e.name()|class_name_swift }}.unknown can't be passed to Rust")
+        {%- endif %}
         }
     }
 }
 
-{% if ! ci.item_contains_object_references(e) %}
+{% if ! ci.item_contains_object_references(e) && ! ci.item_contains_tuple_types(e) %}
 extension {{ e.name()|class_name_swift }}: Equatable, Hashable {}
 {% endif %}
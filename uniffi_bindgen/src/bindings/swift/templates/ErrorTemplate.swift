@@ -43,17 +43,17 @@ fileprivate extension RustCallStatus {
 
 {# Define enums to handle each individual error #}
 {% for e in ci.iter_error_definitions() %}
-public enum {{ e.name()|class_name_swift }} {
+{% call swift::docstring(e, 0) %}public {% if ci.is_recursive_type(e.name()) %}indirect enum{% else %}enum{% endif %} {{ e.name()|class_name_swift }} {
 
     {% if e.is_flat() %}
     {% for variant in e.variants() %}
     // Simple error enums only carry a message
-    case {{ variant.name()|class_name_swift }}(message: String)
+    {% call swift::docstring(variant, 4) %}{% call swift::deprecated(variant) %}case {{ variant.name()|class_name_swift }}(message: String)
     {% endfor %}
 
     {%- else %}
     {% for variant in e.variants() %}
-    case {{ variant.name()|class_name_swift }}{% if variant.fields().len() > 0 %}({% call swift::field_list_decl(variant) %}){% endif -%}
+    {% call swift::docstring(variant, 4) %}{% call swift::deprecated(variant) %}case {{ variant.name()|class_name_swift }}{% if variant.fields().len() > 0 %}({% call swift::field_list_decl(variant) %}){% endif -%}
     {% endfor %}
 
     {%- endif %}
@@ -105,7 +105,7 @@ extension {{ e.name()|class_name_swift }}: ViaFfiUsingByteBuffer, ViaFfi {
         case let .{{ variant.name()|class_name_swift }}({% for field in variant.fields() %}{{ field.name()|var_name_swift }}{%- if loop.last -%}{%- else -%},{%- endif -%}{% endfor %}):
             buf.writeInt(Int32({{ loop.index }}))
             {% for field in variant.fields() -%}
-            {{ field.name()|var_name_swift }}.write(into: buf)
+            {{ field.name()|var_name_swift|write_swift("buf", field.type_()) }}
             {% endfor -%}
         {% else %}
         case .{{ variant.name()|class_name_swift }}:
@@ -118,7 +118,7 @@ extension {{ e.name()|class_name_swift }}: ViaFfiUsingByteBuffer, ViaFfi {
     }
 }
 
-{% if !ci.item_contains_object_references(e) %}
+{% if !ci.item_contains_object_references(e) && !ci.item_contains_tuple_types(e) %}
 extension {{ e.name()|class_name_swift }}: Equatable, Hashable {}
 {% endif %}
 extension {{ e.name()|class_name_swift }}: Error { }
@@ -136,6 +136,7 @@ private func rustCallWithError<T, E: ViaFfiUsingByteBuffer & Error>(_ errorClass
 }
 
 private func makeRustCall<T>(_ callback: (UnsafeMutablePointer<RustCallStatus>) -> T, errorHandler: (RustBuffer) throws -> Error) throws -> T {
+    uniffiEnsureInitialized()
     var callStatus = RustCallStatus.init()
     let returnedVal = callback(&callStatus)
     switch callStatus.code {
@@ -160,3 +161,38 @@ private func makeRustCall<T>(_ callback: (UnsafeMutablePointer<RustCallStatus>)
             throw UniffiInternalError.unexpectedRustCallStatusCode
     }
 }
+
+private enum InitializationResult {
+    case ok
+    case contractVersionMismatch(UInt32)
+    case apiChecksumMismatch(String)
+}
+
+// Check that the library we are linked against was built from the same interface as these bindings,
+// so that a mismatch fails loudly on the first call rather than with undefined behaviour.
+// This is done lazily, and only once, because Swift has no hook for when the library is loaded.
+private let initializationResult: InitializationResult = {
+    // These calls can't go through `rustCall`, which waits for this check to complete.
+    var callStatus = RustCallStatus.init()
+    let scaffoldingContractVersion = {{ ci.ffi_uniffi_contract_version().name() }}(&callStatus)
+    if scaffoldingContractVersion != {{ ci.uniffi_contract_version() }} {
+        return InitializationResult.contractVersionMismatch(scaffoldingContractVersion)
+    }
+    {%- for checksum in ci.iter_checksums() %}
+    if {{ checksum.ffi_func().name() }}(&callStatus) != {{ checksum.checksum() }} {
+        return InitializationResult.apiChecksumMismatch("{{ checksum.item_name() }}")
+    }
+    {%- endfor %}
+    return InitializationResult.ok
+}()
+
+private func uniffiEnsureInitialized() {
+    switch initializationResult {
+    case .ok:
+        break
+    case let .contractVersionMismatch(scaffoldingContractVersion):
+        fatalError("UniFFI contract version mismatch: bindings expect {{ ci.uniffi_contract_version() }} but library has \(scaffoldingContractVersion); bindings do not match library, try regenerating them")
+    case let .apiChecksumMismatch(itemName):
+        fatalError("UniFFI API checksum mismatch for \(itemName): bindings do not match library, try regenerating them")
+    }
+}
@@ -0,0 +1,35 @@
+{#
+// Flags are an `OptionSet`, whose raw value is the integer that's passed to Rust.
+#}
+{%- let type_name = f.name()|class_name_swift %}
+{%- let raw_type = f.prim()|type_swift %}
+{% call swift::docstring(f, 0) %}public struct {{ type_name }}: OptionSet, Hashable {
+    public let rawValue: {{ raw_type }}
+
+    public init(rawValue: {{ raw_type }}) {
+        self.rawValue = rawValue
+    }
+{% for flag in f.flags() %}
+    {% call swift::docstring(flag, 4) %}public static let {{ flag.name()|enum_variant_swift }} = {{ type_name }}(rawValue: {{ flag.value() }})
+    {%- endfor %}
+}
+
+extension {{ type_name }}: ViaFfi {
+    fileprivate typealias FfiType = {{ raw_type }}
+
+    fileprivate static func lift(_ v: {{ raw_type }}) throws -> {{ type_name }} {
+        return {{ type_name }}(rawValue: try {{ "v"|lift_swift(f.prim()) }})
+    }
+
+    fileprivate func lower() -> {{ raw_type }} {
+        return {{ "rawValue"|lower_swift(f.prim()) }}
+    }
+
+    fileprivate static func read(from buf: Reader) throws -> {{ type_name }} {
+        return {{ type_name }}(rawValue: try {{ "buf"|read_swift(f.prim()) }})
+    }
+
+    fileprivate func write(into buf: Writer) {
+        {{ "rawValue"|write_swift("buf", f.prim()) }}
+    }
+}
@@ -1,15 +1,18 @@
 
-public protocol {{ obj.name() }}Protocol {
-    {% for meth in obj.methods() -%}
-    func {{ meth.name()|fn_name_swift }}({% call swift::arg_list_protocol(meth) %}) {% call swift::throws(meth) -%}
+{% call swift::docstring(obj, 0) %}public protocol {{ obj.name() }}Protocol {
+    {% for meth in obj.instance_methods() -%}
+    {% call swift::docstring(meth, 4) %}{% call swift::deprecated(meth) %}func {{ meth.name()|fn_name_swift }}({% call swift::arg_list_protocol(meth) %}) {% if meth.is_async() %}async {% endif %}{% call swift::throws(meth) -%}
     {%- match meth.return_type() -%}
     {%- when Some with (return_type) %} -> {{ return_type|type_swift -}}
     {%- else -%}
     {%- endmatch %}
     {% endfor %}
+    {%- for prop in obj.properties() %}
+    {% call swift::docstring(prop, 4) %}var {{ prop.name()|var_name_swift }}: {{ prop.type_()|type_swift }} { get{% if !prop.is_readonly() %} set{% endif %} }
+    {% endfor %}
 }
 
-public class {{ obj.name()|class_name_swift }}: {{ obj.name() }}Protocol {
+{% call swift::docstring(obj, 0) %}public class {{ obj.name()|class_name_swift }}: {{ obj.name() }}Protocol {
     fileprivate let pointer: UnsafeMutableRawPointer
 
     // TODO: We'd like this to be `private` but for Swifty reasons,
@@ -21,7 +24,7 @@ public class {{ obj.name()|class_name_swift }}: {{ obj.name() }}Protocol {
 
     {%- match obj.primary_constructor() %}
     {%- when Some with (cons) %}
-    public convenience init({% call swift::arg_list_decl(cons) -%}) {% call swift::throws(cons) %} {
+    {% call swift::docstring(cons, 4) %}{% call swift::deprecated(cons) %}public convenience init({% call swift::arg_list_decl(cons) -%}) {% call swift::throws(cons) %} {
         self.init(unsafeFromRawPointer: {% call swift::to_ffi_call(cons) %})
     }
     {%- when None %}
@@ -32,26 +35,74 @@ public class {{ obj.name()|class_name_swift }}: {{ obj.name() }}Protocol {
     }
 
     {% for cons in obj.alternate_constructors() %}
-    public static func {{ cons.name()|fn_name_swift }}({% call swift::arg_list_decl(cons) %}) {% call swift::throws(cons) %} -> {{ obj.name()|class_name_swift }} {
+    {% call swift::docstring(cons, 4) %}{% call swift::deprecated(cons) %}public static func {{ cons.name()|fn_name_swift }}({% call swift::arg_list_decl(cons) %}) {% call swift::throws(cons) %} -> {{ obj.name()|class_name_swift }} {
         return {{ obj.name()|class_name_swift }}(unsafeFromRawPointer: {% call swift::to_ffi_call(cons) %})
     }
     {% endfor %}
 
+    {% for meth in obj.static_methods() -%}
+    {%- if meth.is_async() -%}
+    {% call swift::docstring(meth, 4) %}{% call swift::deprecated(meth) %}public static func {{ meth.name()|fn_name_swift }}({% call swift::arg_list_decl(meth) %}) async {% call swift::throws(meth) %}
+    {%- match meth.return_type() %}{%- when Some with (return_type) %} -> {{ return_type|type_swift }}{%- else %}{%- endmatch %} {
+        let _rustFuture = {% call swift::to_ffi_call(meth) %}
+        return {% call swift::rust_future_await("_rustFuture", meth) %}
+    }
+    {% else -%}
+    {%- match meth.return_type() -%}
+
+    {%- when Some with (return_type) -%}
+    {% call swift::docstring(meth, 4) %}{% call swift::deprecated(meth) %}public static func {{ meth.name()|fn_name_swift }}({% call swift::arg_list_decl(meth) %}) {% call swift::throws(meth) %} -> {{ return_type|type_swift }} {
+        let _retval = {% call swift::to_ffi_call(meth) %}
+        return {% call swift::try(meth) %} {{ "_retval"|lift_swift(return_type) }}
+    }
+
+    {%- when None -%}
+    {% call swift::docstring(meth, 4) %}{% call swift::deprecated(meth) %}public static func {{ meth.name()|fn_name_swift }}({% call swift::arg_list_decl(meth) %}) {% call swift::throws(meth) %} {
+        {% call swift::to_ffi_call(meth) %}
+    }
+    {%- endmatch %}
+    {%- endif %}
+    {% endfor %}
+
     {# // TODO: Maybe merge the two templates (i.e the one with a return type and the one without) #}
-    {% for meth in obj.methods() -%}
+    {% for meth in obj.instance_methods() -%}
+    {%- if meth.is_async() -%}
+    {% call swift::docstring(meth, 4) %}{% call swift::deprecated(meth) %}public func {{ meth.name()|fn_name_swift }}({% call swift::arg_list_decl(meth) %}) async {% call swift::throws(meth) %}
+    {%- match meth.return_type() %}{%- when Some with (return_type) %} -> {{ return_type|type_swift }}{%- else %}{%- endmatch %} {
+        let _rustFuture = {% call swift::to_ffi_call_with_prefix("self.pointer", meth) %}
+        return {% call swift::rust_future_await("_rustFuture", meth) %}
+    }
+    {% else -%}
     {%- match meth.return_type() -%}
 
     {%- when Some with (return_type) -%}
-    public func {{ meth.name()|fn_name_swift }}({% call swift::arg_list_decl(meth) %}) {% call swift::throws(meth) %} -> {{ return_type|type_swift }} {
+    {% call swift::docstring(meth, 4) %}{% call swift::deprecated(meth) %}public func {{ meth.name()|fn_name_swift }}({% call swift::arg_list_decl(meth) %}) {% call swift::throws(meth) %} -> {{ return_type|type_swift }} {
         let _retval = {% call swift::to_ffi_call_with_prefix("self.pointer", meth) %}
         return {% call swift::try(meth) %} {{ "_retval"|lift_swift(return_type) }}
     }
 
     {%- when None -%}
-    public func {{ meth.name()|fn_name_swift }}({% call swift::arg_list_decl(meth) %}) {% call swift::throws(meth) %} {
+    {% call swift::docstring(meth, 4) %}{% call swift::deprecated(meth) %}public func {{ meth.name()|fn_name_swift }}({% call swift::arg_list_decl(meth) %}) {% call swift::throws(meth) %} {
         {% call swift::to_ffi_call_with_prefix("self.pointer", meth) %}
     }
     {%- endmatch %}
+    {%- endif %}
+    {% endfor %}
+
+    {%- for prop in obj.properties() %}
+    {% call swift::docstring(prop, 4) %}public var {{ prop.name()|var_name_swift }}: {{ prop.type_()|type_swift }} {
+        get {
+            let _retval = {% call swift::to_ffi_call_with_prefix("self.pointer", prop.getter()) %}
+            return try! {{ "_retval"|lift_swift(prop.type_()) }}
+        }
+        {%- match prop.setter() %}
+        {%- when Some with (setter) %}
+        set(value) {
+            {% call swift::to_ffi_call_with_prefix("self.pointer", setter) %}
+        }
+        {%- else %}
+        {%- endmatch %}
+    }
     {% endfor %}
 }
 
@@ -90,3 +141,43 @@ fileprivate extension {{ obj.name()|class_name_swift }} {
 // 'private' modifier cannot be used with extensions that declare protocol conformances
 // """
 extension {{ obj.name()|class_name_swift }} : ViaFfi, Serializable {}
+{%- for trait_impl in obj.trait_impls() %}
+{% match trait_impl.trait_() %}
+{%- when ObjectTrait::Debug %}
+extension {{ obj.name()|class_name_swift }}: CustomDebugStringConvertible {
+    public var debugDescription: String {
+        let _retval = try! rustCall() {
+            {{ trait_impl.ffi_func().name() }}(self.pointer, $0)
+        }
+        return try! {{ "_retval"|lift_swift(trait_impl.return_type()) }}
+    }
+}
+{%- when ObjectTrait::Display %}
+extension {{ obj.name()|class_name_swift }}: CustomStringConvertible {
+    public var description: String {
+        let _retval = try! rustCall() {
+            {{ trait_impl.ffi_func().name() }}(self.pointer, $0)
+        }
+        return try! {{ "_retval"|lift_swift(trait_impl.return_type()) }}
+    }
+}
+{%- when ObjectTrait::Eq %}
+extension {{ obj.name()|class_name_swift }}: Equatable {
+    public static func == (lhs: {{ obj.name()|class_name_swift }}, rhs: {{ obj.name()|class_name_swift }}) -> Bool {
+        let _retval = try! rustCall() {
+            {{ trait_impl.ffi_func().name() }}(lhs.pointer, rhs.pointer, $0)
+        }
+        return try! {{ "_retval"|lift_swift(trait_impl.return_type()) }}
+    }
+}
+{%- when ObjectTrait::Hash %}
+extension {{ obj.name()|class_name_swift }}: Hashable {
+    public func hash(into hasher: inout Hasher) {
+        let _retval = try! rustCall() {
+            {{ trait_impl.ffi_func().name() }}(self.pointer, $0)
+        }
+        hasher.combine(try! {{ "_retval"|lift_swift(trait_impl.return_type()) }})
+    }
+}
+{%- endmatch %}
+{%- endfor %}
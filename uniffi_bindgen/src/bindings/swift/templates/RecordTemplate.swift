@@ -1,6 +1,6 @@
-public struct {{ rec.name()|class_name_swift }} {
+{% call swift::docstring(rec, 0) %}{% call swift::deprecated(rec) %}public struct {{ rec.name()|class_name_swift }} {
     {%- for field in rec.fields() %}
-    public var {{ field.name()|var_name_swift }}: {{ field.type_()|type_swift }}
+    {% call swift::docstring(field, 4) %}{% call swift::deprecated(field) %}{% if ci.is_recursive_field(rec.name(), field) %}@UniffiIndirect {% endif %}public var {{ field.name()|var_name_swift }}: {{ field.type_()|type_swift }}
     {%- endfor %}
 
     // Default memberwise initializers are never public by default, so we
@@ -12,7 +12,8 @@ public struct {{ rec.name()|class_name_swift }} {
     }
 }
 
-{% if ! ci.item_contains_object_references(rec) %}
+{# Swift tuples aren't `Equatable` or `Hashable`, so neither is anything that contains one. #}
+{% if ! ci.item_contains_object_references(rec) && ! ci.item_contains_tuple_types(rec) %}
 extension {{ rec.name()|class_name_swift }}: Equatable, Hashable {
     public static func ==(lhs: {{ rec.name()|class_name_swift }}, rhs: {{ rec.name()|class_name_swift }}) -> Bool {
         {%- for field in rec.fields() %}
@@ -42,7 +43,7 @@ fileprivate extension {{ rec.name()|class_name_swift }} {
 
     func write(into buf: Writer) {
         {%- for field in rec.fields() %}
-        {{ field.name()|var_name_swift }}.write(into: buf)
+        {{ field.name()|var_name_swift|write_swift("buf", field.type_()) }}
         {%- endfor %}
     }
 }
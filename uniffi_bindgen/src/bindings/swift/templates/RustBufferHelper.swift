@@ -209,13 +209,14 @@ extension Array: ViaFfiUsingByteBuffer, ViaFfi, Serializable where Element: Seri
 {% endif %}
 
 {% if ci.contains_map_types() %}
-extension Dictionary: ViaFfiUsingByteBuffer, ViaFfi, Serializable where Key == String, Value: Serializable {
+extension Dictionary: ViaFfiUsingByteBuffer, ViaFfi, Serializable where Key: Serializable, Value: Serializable {
     fileprivate static func read(from buf: Reader) throws -> Self {
         let len: Int32 = try buf.readInt()
-        var dict = [String: Value]()
+        var dict = [Key: Value]()
         dict.reserveCapacity(Int(len))
         for _ in 0..<len {
-            dict[try String.read(from: buf)] = try Value.read(from: buf)
+            let key = try Key.read(from: buf)
+            dict[key] = try Value.read(from: buf)
         }
         return dict
     }
@@ -231,6 +232,83 @@ extension Dictionary: ViaFfiUsingByteBuffer, ViaFfi, Serializable where Key == S
 }
 {% endif %}
 
+{% if ci.contains_set_types() %}
+extension Set: ViaFfiUsingByteBuffer, ViaFfi, Serializable where Element: Serializable {
+    fileprivate static func read(from buf: Reader) throws -> Self {
+        let len: Int32 = try buf.readInt()
+        var set = Set<Element>()
+        set.reserveCapacity(Int(len))
+        for _ in 0..<len {
+            set.insert(try Element.read(from: buf))
+        }
+        return set
+    }
+
+    fileprivate func write(into buf: Writer) {
+        let len = Int32(self.count)
+        buf.writeInt(len)
+        for item in self {
+            item.write(into: buf)
+        }
+    }
+}
+{% endif %}
+
+{% if ci.contains_tuple_types() || ci.contains_ordered_map_types() || !ci.iter_wrapped_types().is_empty() %}
+// Swift tuples can't conform to our protocols, so each tuple type gets its own converter
+// conforming to this one instead. So does any optional, sequence or map that contains one,
+// because the protocol extensions above only apply to `Serializable` types. Ordered maps
+// are dictionaries like any other map, so they need a converter to write their keys in order,
+// and wrapped types have their own converter, so collections of them need one too.
+fileprivate protocol FfiConverterUsingByteBuffer {
+    associatedtype SwiftType
+    static func read(from buf: Reader) throws -> SwiftType
+    static func write(_ value: SwiftType, into buf: Writer)
+}
+
+extension FfiConverterUsingByteBuffer {
+    fileprivate static func lift(_ buf: RustBuffer) throws -> SwiftType {
+      let reader = Reader(data: Data(rustBuffer: buf))
+      let value = try read(from: reader)
+      if reader.hasRemaining() {
+          throw UniffiInternalError.incompleteData
+      }
+      buf.deallocate()
+      return value
+    }
+
+    fileprivate static func lower(_ value: SwiftType) -> RustBuffer {
+      let writer = Writer()
+      write(value, into: writer)
+      return RustBuffer(bytes: writer.bytes)
+    }
+}
+{% endif %}
+
+{% if ci.contains_recursive_records() %}
+// Structs can't contain themselves, even in an optional, so the fields of recursive records
+// are stored through this wrapper. It's an `indirect` enum, which Swift puts on the heap.
+@propertyWrapper
+public enum UniffiIndirect<T> {
+    indirect case wrapped(T)
+
+    public init(wrappedValue: T) {
+        self = .wrapped(wrappedValue)
+    }
+
+    public var wrappedValue: T {
+        get {
+            switch self {
+            case let .wrapped(value): return value
+            }
+        }
+        set {
+            self = .wrapped(newValue)
+        }
+    }
+}
+{% endif %}
+
 {% for typ in ci.iter_types() %}
 {% let canonical_type_name = typ.canonical_name()|class_name_swift %}
 {%- match typ -%}
@@ -274,6 +352,97 @@ extension String: ViaFfi {
     }
 }
 
+{% when Type::UInt128 -%}
+/// A 128-bit unsigned integer, split into two 64-bit words.
+public struct U128: Hashable {
+    public var high: UInt64
+    public var low: UInt64
+
+    public init(high: UInt64, low: UInt64) {
+        self.high = high
+        self.low = low
+    }
+
+    public init(_ value: UInt64) {
+        self.init(high: 0, low: value)
+    }
+}
+
+extension U128: ViaFfiUsingByteBuffer, ViaFfi {
+    fileprivate static func read(from buf: Reader) throws -> Self {
+        let high: UInt64 = try buf.readInt()
+        let low: UInt64 = try buf.readInt()
+        return U128(high: high, low: low)
+    }
+
+    fileprivate func write(into buf: Writer) {
+        buf.writeInt(high)
+        buf.writeInt(low)
+    }
+}
+
+{% when Type::Int128 -%}
+/// A 128-bit signed integer in two's complement, split into two 64-bit words.
+public struct I128: Hashable {
+    public var high: Int64
+    public var low: UInt64
+
+    public init(high: Int64, low: UInt64) {
+        self.high = high
+        self.low = low
+    }
+
+    public init(_ value: Int64) {
+        self.init(high: value < 0 ? -1 : 0, low: UInt64(bitPattern: value))
+    }
+}
+
+extension I128: ViaFfiUsingByteBuffer, ViaFfi {
+    fileprivate static func read(from buf: Reader) throws -> Self {
+        let high: Int64 = try buf.readInt()
+        let low: UInt64 = try buf.readInt()
+        return I128(high: high, low: low)
+    }
+
+    fileprivate func write(into buf: Writer) {
+        buf.writeInt(high)
+        buf.writeInt(low)
+    }
+}
+
+{% when Type::Bytes -%}
+extension Data: ViaFfi {
+    fileprivate typealias FfiType = RustBuffer
+
+    fileprivate static func lift(_ v: FfiType) throws -> Self {
+        defer {
+            try! rustCall { {{ ci.ffi_rustbuffer_free().name() }}(v, $0) }
+        }
+        if v.data == nil {
+            return Data()
+        }
+        return Data(bytes: v.data!, count: Int(v.len))
+    }
+
+    fileprivate func lower() -> FfiType {
+        return self.withUnsafeBytes { ptr in
+            let bytes = ForeignBytes(bufferPointer: ptr.bindMemory(to: UInt8.self))
+            return try! rustCall { {{ ci.ffi_rustbuffer_from_bytes().name() }}(bytes, $0) }
+        }
+    }
+
+    fileprivate static func read(from buf: Reader) throws -> Self {
+        let len: Int32 = try buf.readInt()
+        return Data(try buf.readBytes(count: Int(len)))
+    }
+
+    fileprivate func write(into buf: Writer) {
+        let len = Int32(self.count)
+        buf.writeInt(len)
+        buf.writeBytes(self)
+    }
+}
+
 {% when Type::Boolean -%}
 extension Bool: ViaFfi {
     fileprivate typealias FfiType = Int8
@@ -479,6 +648,139 @@ extension Double: Primitive, ViaFfi {
     }
 }
 
+{% when Type::Tuple with (element_types) -%}
+fileprivate enum FfiConverter{{ canonical_type_name }}: FfiConverterUsingByteBuffer {
+    fileprivate static func read(from buf: Reader) throws -> {{ typ|type_swift }} {
+        return (
+            {%- for element_type in element_types %}
+            try {{ "buf"|read_swift(element_type) }}{% if !loop.last %},{% endif %}
+            {%- endfor %}
+        )
+    }
+
+    fileprivate static func write(_ value: {{ typ|type_swift }}, into buf: Writer) {
+        {%- for element_type in element_types %}
+        {{ "value.{}"|format(loop.index0)|write_swift("buf", element_type) }}
+        {%- endfor %}
+    }
+}
+
+{% when Type::Optional with (inner_type) -%}
+{% if typ|needs_ffi_converter_swift -%}
+fileprivate enum FfiConverter{{ canonical_type_name }}: FfiConverterUsingByteBuffer {
+    fileprivate static func read(from buf: Reader) throws -> {{ typ|type_swift }} {
+        switch try buf.readInt() as Int8 {
+        case 0: return nil
+        case 1: return try {{ "buf"|read_swift(inner_type) }}
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+
+    fileprivate static func write(_ value: {{ typ|type_swift }}, into buf: Writer) {
+        guard let value = value else {
+            buf.writeInt(Int8(0))
+            return
+        }
+        buf.writeInt(Int8(1))
+        {{ "value"|write_swift("buf", inner_type) }}
+    }
+}
+{%- endif %}
+
+{% when Type::Sequence with (inner_type) -%}
+{% if typ|needs_ffi_converter_swift -%}
+fileprivate enum FfiConverter{{ canonical_type_name }}: FfiConverterUsingByteBuffer {
+    fileprivate static func read(from buf: Reader) throws -> {{ typ|type_swift }} {
+        let len: Int32 = try buf.readInt()
+        var seq = {{ typ|type_swift }}()
+        seq.reserveCapacity(Int(len))
+        for _ in 0..<len {
+            seq.append(try {{ "buf"|read_swift(inner_type) }})
+        }
+        return seq
+    }
+
+    fileprivate static func write(_ value: {{ typ|type_swift }}, into buf: Writer) {
+        buf.writeInt(Int32(value.count))
+        for item in value {
+            {{ "item"|write_swift("buf", inner_type) }}
+        }
+    }
+}
+{%- endif %}
+
+{% when Type::Map with (key_type, value_type) -%}
+{% if typ|needs_ffi_converter_swift -%}
+fileprivate enum FfiConverter{{ canonical_type_name }}: FfiConverterUsingByteBuffer {
+    fileprivate static func read(from buf: Reader) throws -> {{ typ|type_swift }} {
+        let len: Int32 = try buf.readInt()
+        var dict = {{ typ|type_swift }}()
+        dict.reserveCapacity(Int(len))
+        for _ in 0..<len {
+            let key = try {{ "buf"|read_swift(key_type) }}
+            dict[key] = try {{ "buf"|read_swift(value_type) }}
+        }
+        return dict
+    }
+
+    fileprivate static func write(_ value: {{ typ|type_swift }}, into buf: Writer) {
+        buf.writeInt(Int32(value.count))
+        for (key, value) in value {
+            {{ "key"|write_swift("buf", key_type) }}
+            {{ "value"|write_swift("buf", value_type) }}
+        }
+    }
+}
+{%- endif %}
+
+{% when Type::Wrapped with { name, prim } -%}
+{%- let wrapped = config.wrapped_type(name) %}
+{%- let prim_type_name = prim|type_swift %}
+// The wrapped type {{ name }} is passed as a {{ prim_type_name }}, and converted to and from it here.
+fileprivate enum FfiConverter{{ canonical_type_name }} {
+    fileprivate static func lift(_ v: {{ prim_type_name }}.FfiType) throws -> {{ typ|type_swift }} {
+        let builtinValue = try {{ "v"|lift_swift(prim) }}
+        return {{ wrapped.lift("builtinValue") }}
+    }
+
+    fileprivate static func lower(_ value: {{ typ|type_swift }}) -> {{ prim_type_name }}.FfiType {
+        let builtinValue = {{ wrapped.lower("value") }}
+        return {{ "builtinValue"|lower_swift(prim) }}
+    }
+
+    fileprivate static func read(from buf: Reader) throws -> {{ typ|type_swift }} {
+        let builtinValue = try {{ "buf"|read_swift(prim) }}
+        return {{ wrapped.lift("builtinValue") }}
+    }
+
+    fileprivate static func write(_ value: {{ typ|type_swift }}, into buf: Writer) {
+        let builtinValue = {{ wrapped.lower("value") }}
+        {{ "builtinValue"|write_swift("buf", prim) }}
+    }
+}
+
+{% when Type::OrderedMap with (key_type, value_type) -%}
+fileprivate enum FfiConverter{{ canonical_type_name }}: FfiConverterUsingByteBuffer {
+    fileprivate static func read(from buf: Reader) throws -> {{ typ|type_swift }} {
+        let len: Int32 = try buf.readInt()
+        var dict = {{ typ|type_swift }}()
+        dict.reserveCapacity(Int(len))
+        for _ in 0..<len {
+            let key = try {{ "buf"|read_swift(key_type) }}
+            dict[key] = try {{ "buf"|read_swift(value_type) }}
+        }
+        return dict
+    }
+
+    fileprivate static func write(_ value: {{ typ|type_swift }}, into buf: Writer) {
+        buf.writeInt(Int32(value.count))
+        for (key, value) in value.sorted(by: { $0.key < $1.key }) {
+            {{ "key"|write_swift("buf", key_type) }}
+            {{ "value"|write_swift("buf", value_type) }}
+        }
+    }
+}
+
 {% else %}
 {# The methods for lifting/lowering/serializing this type are implemented inline with the type itself #}
 
@@ -0,0 +1,61 @@
+// Async functions and methods return a pointer to a `RustFuture`, which we drive to completion
+// from a Swift task using the poll/wake protocol from the `uniffi` runtime.
+//
+// Each time we poll the future, we suspend the calling task and register its continuation under
+// a fresh handle. Rust calls `rustFutureWake` with that handle (possibly on another thread) once
+// the future can make progress, which resumes the task to poll again.
+
+fileprivate class RustFutureWakers {
+    private let lock = NSLock()
+    private var nextHandle: UInt64 = 0
+    private var continuations: [UInt64: CheckedContinuation<Bool, Never>] = [:]
+
+    func insert(_ continuation: CheckedContinuation<Bool, Never>) -> UInt64 {
+        lock.lock()
+        defer { lock.unlock() }
+        let handle = nextHandle
+        nextHandle += 1
+        continuations[handle] = continuation
+        return handle
+    }
+
+    func remove(_ handle: UInt64) -> CheckedContinuation<Bool, Never>? {
+        lock.lock()
+        defer { lock.unlock() }
+        return continuations.removeValue(forKey: handle)
+    }
+}
+
+fileprivate let rustFutureWakers = RustFutureWakers()
+
+fileprivate func rustFutureWake(_ handle: UInt64) {
+    rustFutureWakers.remove(handle)?.resume(returning: false)
+}
+
+fileprivate func rustFutureAwait<T>(
+    _ rustFuture: UnsafeMutableRawPointer,
+    complete: (UnsafeMutableRawPointer) throws -> RustBuffer,
+    read: (Reader) throws -> T
+) async throws -> T {
+    defer {
+        try! rustCall { {{ ci.ffi_rust_future_free().name() }}(rustFuture, $0) }
+    }
+    var ready = false
+    while !ready {
+        ready = await withCheckedContinuation { continuation in
+            let handle = rustFutureWakers.insert(continuation)
+            let poll = try! rustCall { {{ ci.ffi_rust_future_poll().name() }}(rustFuture, rustFutureWake, handle, $0) }
+            if poll != 0 {
+                rustFutureWakers.remove(handle)?.resume(returning: true)
+            }
+        }
+    }
+    let buf = try complete(rustFuture)
+    defer { buf.deallocate() }
+    let reader = Reader(data: Data(rustBuffer: buf))
+    let value = try read(reader)
+    if reader.hasRemaining() {
+        throw UniffiInternalError.incompleteData
+    }
+    return value
+}
@@ -1,14 +1,24 @@
+{%- if func.is_async() %}
+
+{% call swift::docstring(func, 0) %}{% call swift::deprecated(func) %}public func {{ func.name()|fn_name_swift }}({%- call swift::arg_list_decl(func) -%}) async {% call swift::throws(func) %}
+{%- match func.return_type() %}{%- when Some with (return_type) %} -> {{ return_type|type_swift }}{%- else %}{%- endmatch %} {
+    let _rustFuture = {% call swift::to_ffi_call(func) %}
+    return {% call swift::rust_future_await("_rustFuture", func) %}
+}
+
+{% else -%}
 {%- match func.return_type() -%}
 {%- when Some with (return_type) %}
 
-public func {{ func.name()|fn_name_swift }}({%- call swift::arg_list_decl(func) -%}) {% call swift::throws(func) %} -> {{ return_type|type_swift }} {
+{% call swift::docstring(func, 0) %}{% call swift::deprecated(func) %}public func {{ func.name()|fn_name_swift }}({%- call swift::arg_list_decl(func) -%}) {% call swift::throws(func) %} -> {{ return_type|type_swift }} {
     let _retval = {% call swift::to_ffi_call(func) %}
     return {% call swift::try(func) %} {{ "_retval"|lift_swift(return_type) }}
 }
 
 {% when None -%}
 
-public func {{ func.name()|fn_name_swift }}({% call swift::arg_list_decl(func) %}) {% call swift::throws(func) %} {
+{% call swift::docstring(func, 0) %}{% call swift::deprecated(func) %}public func {{ func.name()|fn_name_swift }}({% call swift::arg_list_decl(func) %}) {% call swift::throws(func) %} {
     {% call swift::to_ffi_call(func) %}
 }
-{% endmatch %}
\ No newline at end of file
+{% endmatch %}
+{%- endif %}
@@ -4,6 +4,29 @@
 // passed to rust via `_arg_list_ffi_call` (we use  `var_name_swift` in `lower_swift`)
 #}
 
+{#
+// Render the doc comment of an item, if it has one. Call it just before the item,
+// passing the indentation of the item in spaces.
+#}
+{%- macro docstring(defn, indent) -%}
+{%- match defn.docstring() -%}
+{%- when Some with (docstring) -%}
+{{ docstring|docstring_swift(indent) }}
+{%- else -%}
+{%- endmatch -%}
+{%- endmacro -%}
+
+{#
+// Mark a deprecated item with `@available`. Call it on the same line as the item.
+#}
+{%- macro deprecated(defn) -%}
+{%- match defn.deprecated() -%}
+{%- when Some with (reason) -%}
+@available(*, deprecated, message: "{{ reason }}"){{ " " }}
+{%- else -%}
+{%- endmatch -%}
+{%- endmacro -%}
+
 {%- macro to_ffi_call(func) -%}
 {% call try(func) %}
     {% match func.throws() %}
@@ -30,6 +53,32 @@
 }
 {%- endmacro %}
 
+{#-
+// Await the `RustFuture` returned from calling an async function or method,
+// reading the result (if any) from the `RustBuffer` that it completes with.
+-#}
+{%- macro rust_future_await(var, func) -%}
+{% call try(func) %} await rustFutureAwait(
+        {{ var }},
+        complete: { rustFuture in
+            {%- match func.throws() %}
+            {%- when Some with (e) %}
+            try rustCallWithError({{ e|class_name_swift }}.self) { {{ ci.ffi_rust_future_complete().name() }}(rustFuture, $0) }
+            {%- else %}
+            try rustCall { {{ ci.ffi_rust_future_complete().name() }}(rustFuture, $0) }
+            {%- endmatch %}
+        },
+        read: { reader in
+            {%- match func.return_type() %}
+            {%- when Some with (return_type) %}
+            try {{ "reader"|read_swift(return_type) }}
+            {%- else %}
+            ()
+            {%- endmatch %}
+        }
+    )
+{%- endmacro -%}
+
 {%- macro _arg_list_ffi_call(func) %}
     {%- for arg in func.arguments() %}
         {{- arg.name()|lower_swift(arg.type_()) }}
@@ -1,7 +1,13 @@
 // This file was autogenerated by some hot garbage in the `uniffi` crate.
 // Trust me, you don't want to mess with it!
 
+{% match ci.namespace_docstring() -%}
+{%- when Some with (docstring) %}{{ docstring|docstring_swift(0) }}
+{% else %}{% endmatch -%}
 import Foundation
+{%- for import in config.wrapped_type_imports(ci) %}
+import {{ import }}
+{%- endfor %}
 
 // Depending on the consumer's build setup, the low-level FFI code
 // might be in a separate module, or it might be compiled inline into
@@ -15,16 +21,38 @@ import {{ config.ffi_module_name() }}
 
 // Public interface members begin here.
 
+{%- for (name, prim) in ci.iter_wrapped_types() %}
+{%- match config.wrapped_type(name).type_name() %}
+{%- when Some with (type_name) %}
+public typealias {{ name|class_name_swift }} = {{ type_name }}
+{%- when None %}
+public typealias {{ name|class_name_swift }} = {{ prim|type_swift }}
+{%- endmatch %}
+{%- endfor %}
+
 {% for e in ci.iter_enum_definitions() %}
 {% include "EnumTemplate.swift" %}
 {%- endfor -%}
 
+{%- for f in ci.iter_flags_definitions() %}
+{% include "FlagsTemplate.swift" %}
+{%- endfor %}
+
 {% include "ErrorTemplate.swift" %}
 
+{%- if ci.has_async_functions() %}
+
+{% include "RustFutureTemplate.swift" %}
+{%- endif %}
+
 {%- for rec in ci.iter_record_definitions() %}
 {% include "RecordTemplate.swift" %}
 {% endfor %}
 
+{%- for constant in ci.iter_constant_definitions() %}
+{% call swift::docstring(constant, 0) %}public let {{ constant.name()|var_name_swift }}: {{ constant.type_()|type_swift }} = {{ constant.value()|literal_swift }}
+{%- endfor %}
+
 {% for func in ci.iter_function_definitions() %}
 {% include "TopLevelFunctionTemplate.swift" %}
 {% endfor %}
@@ -18,6 +18,8 @@ use std::convert::{TryFrom, TryInto};
 
 use anyhow::{bail, Result};
 
+use super::object::ObjectTrait;
+
 /// Represents an attribute parsed from UDL, like `[ByRef]` or `[Throws]`.
 ///
 /// This is a convenience enum for parsing UDL attributes and erroring out if we encounter
@@ -25,13 +27,27 @@ use anyhow::{bail, Result};
 /// may influence the properties of things like functions and arguments.
 #[derive(Debug, Clone, Hash)]
 pub(super) enum Attribute {
+    Async,
     ByRef,
+    // `[Deprecated="reason"]` - Mark the item as deprecated in the bindings.
+    Deprecated(String),
     Enum,
     Error,
     Name(String),
+    // `[Flags=u32]` - The enum is a set of flags, held in the bits of an unsigned integer type.
+    Flags(String),
+    // `[NonExhaustive]` - The bindings read values they don't know as an extra `Unknown` variant.
+    NonExhaustive,
     SelfType(SelfType),
+    // `[Static]` - The method is called on the object's type, rather than on an instance.
+    Static,
     Threadsafe, // N.B. the `[Threadsafe]` attribute is deprecated and will be removed
     Throws(String),
+    // `[Trait]` - The interface is implemented by a Rust trait, rather than a struct.
+    Trait,
+    // `[Traits=(Debug, Display, Eq, Hash)]` - Std traits implemented by the object, which
+    // the bindings expose using their own equivalents.
+    Traits(Vec<ObjectTrait>),
     // `[External="crate_name"]` - We can `use crate_name::...` for the type.
     External(String),
     // Something hand-written in this crate which wraps a primitive type.
@@ -45,6 +61,9 @@ impl Attribute {
     pub fn is_enum(&self) -> bool {
         matches!(self, Attribute::Enum)
     }
+    pub fn is_async(&self) -> bool {
+        matches!(self, Attribute::Async)
+    }
 }
 
 /// Convert a weedle `ExtendedAttribute` into an `Attribute` for a `ComponentInterface` member,
@@ -57,10 +76,14 @@ impl TryFrom<&weedle::attribute::ExtendedAttribute<'_>> for Attribute {
         match weedle_attribute {
             // Matches plain named attributes like "[ByRef"].
             weedle::attribute::ExtendedAttribute::NoArgs(attr) => match (attr.0).0 {
+                "Async" => Ok(Attribute::Async),
                 "ByRef" => Ok(Attribute::ByRef),
                 "Enum" => Ok(Attribute::Enum),
                 "Error" => Ok(Attribute::Error),
+                "NonExhaustive" => Ok(Attribute::NonExhaustive),
+                "Static" => Ok(Attribute::Static),
                 "Threadsafe" => Ok(Attribute::Threadsafe),
+                "Trait" => Ok(Attribute::Trait),
                 "Wrapped" => Ok(Attribute::Wrapped),
                 _ => anyhow::bail!("ExtendedAttributeNoArgs not supported: {:?}", (attr.0).0),
             },
@@ -68,15 +91,37 @@ impl TryFrom<&weedle::attribute::ExtendedAttribute<'_>> for Attribute {
             weedle::attribute::ExtendedAttribute::Ident(identity) => {
                 match identity.lhs_identifier.0 {
                     "Name" => Ok(Attribute::Name(name_from_id_or_string(&identity.rhs))),
+                    "Deprecated" => {
+                        Ok(Attribute::Deprecated(name_from_id_or_string(&identity.rhs)))
+                    }
                     "Throws" => Ok(Attribute::Throws(name_from_id_or_string(&identity.rhs))),
                     "Self" => Ok(Attribute::SelfType(SelfType::try_from(&identity.rhs)?)),
                     "External" => Ok(Attribute::External(name_from_id_or_string(&identity.rhs))),
+                    "Flags" => Ok(Attribute::Flags(name_from_id_or_string(&identity.rhs))),
                     _ => anyhow::bail!(
                         "Attribute identity Identifier not supported: {:?}",
                         identity.lhs_identifier.0
                     ),
                 }
             }
+            // Matches list-style attributes like ["Traits=(Debug, Eq)"]
+            weedle::attribute::ExtendedAttribute::IdentList(attr_list) => {
+                match attr_list.identifier.0 {
+                    "Traits" => Ok(Attribute::Traits(
+                        attr_list
+                            .list
+                            .body
+                            .list
+                            .iter()
+                            .map(|identifier| ObjectTrait::try_from(identifier.0))
+                            .collect::<Result<_>>()?,
+                    )),
+                    _ => anyhow::bail!(
+                        "Attribute identity list not supported: {:?}",
+                        attr_list.identifier.0
+                    ),
+                }
+            }
             _ => anyhow::bail!("Attribute not supported: {:?}", weedle_attribute),
         }
     }
@@ -89,6 +134,14 @@ fn name_from_id_or_string(nm: &weedle::attribute::IdentifierOrString<'_>) -> Str
     }
 }
 
+/// Find the reason given by a `[Deprecated]` attribute, if there is one.
+fn get_deprecated(attrs: &[Attribute]) -> Option<&str> {
+    attrs.iter().find_map(|attr| match attr {
+        Attribute::Deprecated(reason) => Some(reason.as_ref()),
+        _ => None,
+    })
+}
+
 /// Parse a weedle `ExtendedAttributeList` into a list of `Attribute`s,
 /// erroring out on duplicates.
 fn parse_attributes<F>(
@@ -120,7 +173,9 @@ where
 }
 
 /// Attributes that can be attached to an `enum` definition in the UDL.
-/// There's only one case here: using `[Error]` to mark an enum as an error class.
+/// These are `[Error]` to mark an enum as an error class, `[NonExhaustive]`
+/// to let newer versions of the component add variants that older bindings don't know,
+/// and `[Flags=u32]` to declare a set of flags rather than an enum.
 #[derive(Debug, Clone, Hash, Default)]
 pub(super) struct EnumAttributes(Vec<Attribute>);
 
@@ -128,6 +183,20 @@ impl EnumAttributes {
     pub fn contains_error_attr(&self) -> bool {
         self.0.iter().any(|attr| attr.is_error())
     }
+
+    pub fn is_non_exhaustive(&self) -> bool {
+        self.0
+            .iter()
+            .any(|attr| matches!(attr, Attribute::NonExhaustive))
+    }
+
+    /// The name of the integer type that holds the bits of a `[Flags]` enum, if it is one.
+    pub fn get_flags_type(&self) -> Option<&str> {
+        self.0.iter().find_map(|attr| match attr {
+            Attribute::Flags(prim) => Some(prim.as_str()),
+            _ => None,
+        })
+    }
 }
 
 impl TryFrom<&weedle::attribute::ExtendedAttributeList<'_>> for EnumAttributes {
@@ -137,9 +206,24 @@ impl TryFrom<&weedle::attribute::ExtendedAttributeList<'_>> for EnumAttributes {
     ) -> Result<Self, Self::Error> {
         let attrs = parse_attributes(weedle_attributes, |attr| match attr {
             Attribute::Error => Ok(()),
+            Attribute::NonExhaustive => Ok(()),
+            Attribute::Flags(prim) => match prim.as_str() {
+                "u8" | "u16" | "u32" | "u64" => Ok(()),
+                _ => bail!("Flags must be held in a u8, u16, u32 or u64, not {}", prim),
+            },
             _ => bail!(format!("{:?} not supported for enums", attr)),
         })?;
-        Ok(Self(attrs))
+        let attrs = Self(attrs);
+        // Errors are thrown rather than returned, so there's nothing to read an unknown one into.
+        if attrs.contains_error_attr() && attrs.is_non_exhaustive() {
+            bail!("Errors can't be [NonExhaustive]");
+        }
+        if attrs.get_flags_type().is_some()
+            && (attrs.contains_error_attr() || attrs.is_non_exhaustive())
+        {
+            bail!("Flags can't be [Error] or [NonExhaustive]");
+        }
+        Ok(attrs)
     }
 }
 
@@ -156,11 +240,16 @@ impl<T: TryInto<EnumAttributes, Error = anyhow::Error>> TryFrom<Option<T>> for E
 /// Represents UDL attributes that might appear on a function.
 ///
 /// This supports the `[Throws=ErrorName]` attribute for functions that
-/// can produce an error.
+/// can produce an error, the `[Async]` attribute for functions that
+/// are implemented as an `async fn` in Rust, and `[Deprecated="reason"]`.
 #[derive(Debug, Clone, Hash, Default)]
 pub(super) struct FunctionAttributes(Vec<Attribute>);
 
 impl FunctionAttributes {
+    pub(super) fn is_async(&self) -> bool {
+        self.0.iter().any(|attr| attr.is_async())
+    }
+
     pub(super) fn get_throws_err(&self) -> Option<&str> {
         self.0.iter().find_map(|attr| match attr {
             // This will hopefully return a helpful compilation error
@@ -169,6 +258,10 @@ impl FunctionAttributes {
             _ => None,
         })
     }
+
+    pub(super) fn get_deprecated(&self) -> Option<&str> {
+        get_deprecated(&self.0)
+    }
 }
 
 impl TryFrom<&weedle::attribute::ExtendedAttributeList<'_>> for FunctionAttributes {
@@ -177,6 +270,8 @@ impl TryFrom<&weedle::attribute::ExtendedAttributeList<'_>> for FunctionAttribut
         weedle_attributes: &weedle::attribute::ExtendedAttributeList<'_>,
     ) -> Result<Self, Self::Error> {
         let attrs = parse_attributes(weedle_attributes, |attr| match attr {
+            Attribute::Async => Ok(()),
+            Attribute::Deprecated(_) => Ok(()),
             Attribute::Throws(_) => Ok(()),
             _ => bail!(format!("{:?} not supported for functions", attr)),
         })?;
@@ -252,6 +347,20 @@ impl InterfaceAttributes {
             .iter()
             .any(|attr| matches!(attr, Attribute::Threadsafe))
     }
+
+    pub fn is_trait(&self) -> bool {
+        self.0.iter().any(|attr| matches!(attr, Attribute::Trait))
+    }
+
+    pub fn get_traits(&self) -> Vec<ObjectTrait> {
+        self.0
+            .iter()
+            .find_map(|attr| match attr {
+                Attribute::Traits(traits) => Some(traits.clone()),
+                _ => None,
+            })
+            .unwrap_or_default()
+    }
 }
 
 impl TryFrom<&weedle::attribute::ExtendedAttributeList<'_>> for InterfaceAttributes {
@@ -263,10 +372,18 @@ impl TryFrom<&weedle::attribute::ExtendedAttributeList<'_>> for InterfaceAttribu
             Attribute::Enum => Ok(()),
             Attribute::Error => Ok(()),
             Attribute::Threadsafe => Ok(()),
+            Attribute::Trait => Ok(()),
+            Attribute::Traits(_) => Ok(()),
             _ => bail!(format!("{:?} not supported for interface definition", attr)),
         })?;
-        // Can't be both `[Threadsafe]` and an `[Enum]`.
-        if attrs.len() > 1 {
+        // Can't be both `[Threadsafe]` and an `[Enum]`, and only objects can have `[Traits]`.
+        let (traits, others): (Vec<_>, Vec<_>) = attrs
+            .iter()
+            .partition(|attr| matches!(attr, Attribute::Traits(_)));
+        if traits.len() > 1
+            || others.len() > 1
+            || (!traits.is_empty() && others.iter().any(|attr| attr.is_enum() || attr.is_error()))
+        {
             bail!("conflicting attributes on interface definition");
         }
         Ok(Self(attrs))
@@ -288,7 +405,7 @@ impl<T: TryInto<InterfaceAttributes, Error = anyhow::Error>> TryFrom<Option<T>>
 /// Represents UDL attributes that might appear on a constructor.
 ///
 /// This supports the `[Throws=ErrorName]` attribute for constructors that can produce
-/// an error, and the `[Name=MethodName]` for non-default constructors.
+/// an error, the `[Name=MethodName]` for non-default constructors, and `[Deprecated="reason"]`.
 #[derive(Debug, Clone, Hash, Default)]
 pub(super) struct ConstructorAttributes(Vec<Attribute>);
 
@@ -308,6 +425,10 @@ impl ConstructorAttributes {
             _ => None,
         })
     }
+
+    pub(super) fn get_deprecated(&self) -> Option<&str> {
+        get_deprecated(&self.0)
+    }
 }
 
 impl TryFrom<&weedle::attribute::ExtendedAttributeList<'_>> for ConstructorAttributes {
@@ -316,6 +437,7 @@ impl TryFrom<&weedle::attribute::ExtendedAttributeList<'_>> for ConstructorAttri
         weedle_attributes: &weedle::attribute::ExtendedAttributeList<'_>,
     ) -> Result<Self, Self::Error> {
         let attrs = parse_attributes(weedle_attributes, |attr| match attr {
+            Attribute::Deprecated(_) => Ok(()),
             Attribute::Throws(_) => Ok(()),
             Attribute::Name(_) => Ok(()),
             _ => bail!(format!("{:?} not supported for constructors", attr)),
@@ -327,11 +449,16 @@ impl TryFrom<&weedle::attribute::ExtendedAttributeList<'_>> for ConstructorAttri
 /// Represents UDL attributes that might appear on a method.
 ///
 /// This supports the `[Throws=ErrorName]` attribute for methods that can produce
-/// an error, and the `[Self=ByArc]` attribute for methods that take `Arc<Self>` as receiver.
+/// an error, the `[Self=ByArc]` attribute for methods that take `Arc<Self>` as receiver,
+/// the `[Async]` attribute for methods implemented as an `async fn`, and `[Deprecated="reason"]`.
 #[derive(Debug, Clone, Hash, Default)]
 pub(super) struct MethodAttributes(Vec<Attribute>);
 
 impl MethodAttributes {
+    pub(super) fn is_async(&self) -> bool {
+        self.0.iter().any(|attr| attr.is_async())
+    }
+
     pub(super) fn get_throws_err(&self) -> Option<&str> {
         self.0.iter().find_map(|attr| match attr {
             // This will hopefully return a helpful compilation error
@@ -346,6 +473,14 @@ impl MethodAttributes {
             .iter()
             .any(|attr| matches!(attr, Attribute::SelfType(SelfType::ByArc)))
     }
+
+    pub(super) fn is_static(&self) -> bool {
+        self.0.iter().any(|attr| matches!(attr, Attribute::Static))
+    }
+
+    pub(super) fn get_deprecated(&self) -> Option<&str> {
+        get_deprecated(&self.0)
+    }
 }
 
 impl TryFrom<&weedle::attribute::ExtendedAttributeList<'_>> for MethodAttributes {
@@ -354,11 +489,35 @@ impl TryFrom<&weedle::attribute::ExtendedAttributeList<'_>> for MethodAttributes
         weedle_attributes: &weedle::attribute::ExtendedAttributeList<'_>,
     ) -> Result<Self, Self::Error> {
         let attrs = parse_attributes(weedle_attributes, |attr| match attr {
+            Attribute::Async => Ok(()),
+            Attribute::Deprecated(_) => Ok(()),
             Attribute::SelfType(_) => Ok(()),
+            Attribute::Static => Ok(()),
             Attribute::Throws(_) => Ok(()),
             _ => bail!(format!("{:?} not supported for methods", attr)),
         })?;
-        Ok(Self(attrs))
+        let attrs = Self(attrs);
+        if attrs.is_static()
+            && attrs
+                .0
+                .iter()
+                .any(|attr| matches!(attr, Attribute::SelfType(_)))
+        {
+            bail!("Static methods don't take `self`, so can't have a [Self] attribute");
+        }
+        Ok(attrs)
+    }
+}
+
+/// Methods from proc-macro metadata don't have attributes as such, but the type of their
+/// receiver is represented by an attribute for consistency with the UDL.
+impl From<&uniffi_meta::MethodMetadata> for MethodAttributes {
+    fn from(meta: &uniffi_meta::MethodMetadata) -> Self {
+        if meta.takes_self_by_arc {
+            Self(vec![Attribute::SelfType(SelfType::ByArc)])
+        } else {
+            Self(vec![])
+        }
     }
 }
 
@@ -372,6 +531,113 @@ impl<T: TryInto<MethodAttributes, Error = anyhow::Error>> TryFrom<Option<T>> for
     }
 }
 
+/// Represents UDL attributes that might appear on a `dictionary` definition.
+///
+/// This supports the `[Deprecated="reason"]` attribute.
+#[derive(Debug, Clone, Hash, Default)]
+pub(super) struct RecordAttributes(Vec<Attribute>);
+
+impl RecordAttributes {
+    pub(super) fn get_deprecated(&self) -> Option<&str> {
+        get_deprecated(&self.0)
+    }
+}
+
+impl TryFrom<&weedle::attribute::ExtendedAttributeList<'_>> for RecordAttributes {
+    type Error = anyhow::Error;
+    fn try_from(
+        weedle_attributes: &weedle::attribute::ExtendedAttributeList<'_>,
+    ) -> Result<Self, Self::Error> {
+        let attrs = parse_attributes(weedle_attributes, |attr| match attr {
+            Attribute::Deprecated(_) => Ok(()),
+            _ => bail!(format!("{:?} not supported for dictionaries", attr)),
+        })?;
+        Ok(Self(attrs))
+    }
+}
+
+impl<T: TryInto<RecordAttributes, Error = anyhow::Error>> TryFrom<Option<T>> for RecordAttributes {
+    type Error = anyhow::Error;
+    fn try_from(value: Option<T>) -> Result<Self, Self::Error> {
+        match value {
+            None => Ok(Default::default()),
+            Some(v) => v.try_into(),
+        }
+    }
+}
+
+/// Represents UDL attributes that might appear on a `dictionary` member.
+///
+/// This supports the `[Deprecated="reason"]` attribute.
+#[derive(Debug, Clone, Hash, Default)]
+pub(super) struct FieldAttributes(Vec<Attribute>);
+
+impl FieldAttributes {
+    pub(super) fn get_deprecated(&self) -> Option<&str> {
+        get_deprecated(&self.0)
+    }
+}
+
+impl TryFrom<&weedle::attribute::ExtendedAttributeList<'_>> for FieldAttributes {
+    type Error = anyhow::Error;
+    fn try_from(
+        weedle_attributes: &weedle::attribute::ExtendedAttributeList<'_>,
+    ) -> Result<Self, Self::Error> {
+        let attrs = parse_attributes(weedle_attributes, |attr| match attr {
+            Attribute::Deprecated(_) => Ok(()),
+            _ => bail!(format!("{:?} not supported for dictionary members", attr)),
+        })?;
+        Ok(Self(attrs))
+    }
+}
+
+impl<T: TryInto<FieldAttributes, Error = anyhow::Error>> TryFrom<Option<T>> for FieldAttributes {
+    type Error = anyhow::Error;
+    fn try_from(value: Option<T>) -> Result<Self, Self::Error> {
+        match value {
+            None => Ok(Default::default()),
+            Some(v) => v.try_into(),
+        }
+    }
+}
+
+/// Represents UDL attributes that might appear on a variant of an `[Enum] interface` or `[Error] interface`.
+///
+/// This supports the `[Deprecated="reason"]` attribute.
+#[derive(Debug, Clone, Hash, Default)]
+pub(super) struct VariantAttributes(Vec<Attribute>);
+
+impl VariantAttributes {
+    pub(super) fn get_deprecated(&self) -> Option<&str> {
+        get_deprecated(&self.0)
+    }
+}
+
+impl TryFrom<&weedle::attribute::ExtendedAttributeList<'_>> for VariantAttributes {
+    type Error = anyhow::Error;
+    fn try_from(
+        weedle_attributes: &weedle::attribute::ExtendedAttributeList<'_>,
+    ) -> Result<Self, Self::Error> {
+        let attrs = parse_attributes(weedle_attributes, |attr| match attr {
+            Attribute::Deprecated(_) => Ok(()),
+            _ => bail!(format!("{:?} not supported for enum variants", attr)),
+        })?;
+        Ok(Self(attrs))
+    }
+}
+
+impl<T: TryInto<VariantAttributes, Error = anyhow::Error>> TryFrom<Option<T>>
+    for VariantAttributes
+{
+    type Error = anyhow::Error;
+    fn try_from(value: Option<T>) -> Result<Self, Self::Error> {
+        match value {
+            None => Ok(Default::default()),
+            Some(v) => v.try_into(),
+        }
+    }
+}
+
 /// Represents the different possible types of method call receiver.
 ///
 /// Actually we only support one of these right now, `[Self=ByArc]`.
@@ -451,6 +717,15 @@ mod test {
     use super::*;
     use weedle::Parse;
 
+    #[test]
+    fn test_async() -> Result<()> {
+        let (_, node) = weedle::attribute::ExtendedAttribute::parse("Async").unwrap();
+        let attr = Attribute::try_from(&node)?;
+        assert!(matches!(attr, Attribute::Async));
+        assert!(attr.is_async());
+        Ok(())
+    }
+
     #[test]
     fn test_byref() -> Result<()> {
         let (_, node) = weedle::attribute::ExtendedAttribute::parse("ByRef").unwrap();
@@ -504,6 +779,14 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_static() -> Result<()> {
+        let (_, node) = weedle::attribute::ExtendedAttribute::parse("Static").unwrap();
+        let attr = Attribute::try_from(&node)?;
+        assert!(matches!(attr, Attribute::Static));
+        Ok(())
+    }
+
     #[test]
     fn test_threadsafe() -> Result<()> {
         let (_, node) = weedle::attribute::ExtendedAttribute::parse("Threadsafe").unwrap();
@@ -528,6 +811,23 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_deprecated() -> Result<()> {
+        let (_, node) =
+            weedle::attribute::ExtendedAttribute::parse(r#"Deprecated="Use bar instead""#).unwrap();
+        let attr = Attribute::try_from(&node)?;
+        assert!(matches!(attr, Attribute::Deprecated(reason) if reason == "Use bar instead"));
+
+        let (_, node) = weedle::attribute::ExtendedAttribute::parse("Deprecated").unwrap();
+        let err = Attribute::try_from(&node).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "ExtendedAttributeNoArgs not supported: \"Deprecated\""
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn test_unsupported() {
         let (_, node) =
@@ -554,6 +854,54 @@ mod test {
         assert_eq!(err.to_string(), "ByRef not supported for enums");
     }
 
+    #[test]
+    fn test_non_exhaustive_attribute() {
+        let (_, node) = weedle::attribute::ExtendedAttributeList::parse("[NonExhaustive]").unwrap();
+        let attrs = EnumAttributes::try_from(&node).unwrap();
+        assert!(attrs.is_non_exhaustive());
+        assert!(!attrs.contains_error_attr());
+
+        let (_, node) =
+            weedle::attribute::ExtendedAttributeList::parse("[Error, NonExhaustive]").unwrap();
+        let err = EnumAttributes::try_from(&node).unwrap_err();
+        assert_eq!(err.to_string(), "Errors can't be [NonExhaustive]");
+
+        let (_, node) =
+            weedle::attribute::ExtendedAttributeList::parse("[Enum, NonExhaustive]").unwrap();
+        let err = InterfaceAttributes::try_from(&node).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "NonExhaustive not supported for interface definition"
+        );
+    }
+
+    #[test]
+    fn test_flags_attribute() {
+        let (_, node) = weedle::attribute::ExtendedAttributeList::parse("[Flags=u32]").unwrap();
+        let attrs = EnumAttributes::try_from(&node).unwrap();
+        assert_eq!(attrs.get_flags_type(), Some("u32"));
+        assert!(!attrs.contains_error_attr());
+
+        let (_, node) = weedle::attribute::ExtendedAttributeList::parse("[Flags=i32]").unwrap();
+        let err = EnumAttributes::try_from(&node).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Flags must be held in a u8, u16, u32 or u64, not i32"
+        );
+
+        let (_, node) =
+            weedle::attribute::ExtendedAttributeList::parse("[Flags=u8, NonExhaustive]").unwrap();
+        let err = EnumAttributes::try_from(&node).unwrap_err();
+        assert_eq!(err.to_string(), "Flags can't be [Error] or [NonExhaustive]");
+
+        let (_, node) = weedle::attribute::ExtendedAttributeList::parse("[Flags]").unwrap();
+        let err = EnumAttributes::try_from(&node).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "ExtendedAttributeNoArgs not supported: \"Flags\""
+        );
+    }
+
     #[test]
     fn test_throws_attribute() {
         let (_, node) = weedle::attribute::ExtendedAttributeList::parse("[Throws=Error]").unwrap();
@@ -563,6 +911,13 @@ mod test {
         let (_, node) = weedle::attribute::ExtendedAttributeList::parse("[]").unwrap();
         let attrs = FunctionAttributes::try_from(&node).unwrap();
         assert!(matches!(attrs.get_throws_err(), None));
+        assert!(!attrs.is_async());
+
+        let (_, node) =
+            weedle::attribute::ExtendedAttributeList::parse("[Async, Throws=Error]").unwrap();
+        let attrs = FunctionAttributes::try_from(&node).unwrap();
+        assert!(matches!(attrs.get_throws_err(), Some("Error")));
+        assert!(attrs.is_async());
     }
 
     #[test]
@@ -603,6 +958,26 @@ mod test {
         let attrs = MethodAttributes::try_from(&node).unwrap();
         assert!(attrs.get_self_by_arc());
         assert!(attrs.get_throws_err().is_none());
+        assert!(!attrs.is_async());
+
+        let (_, node) = weedle::attribute::ExtendedAttributeList::parse("[Async]").unwrap();
+        let attrs = MethodAttributes::try_from(&node).unwrap();
+        assert!(attrs.is_async());
+        assert!(!attrs.is_static());
+
+        let (_, node) =
+            weedle::attribute::ExtendedAttributeList::parse("[Static, Throws=Error]").unwrap();
+        let attrs = MethodAttributes::try_from(&node).unwrap();
+        assert!(attrs.is_static());
+        assert!(attrs.get_throws_err().is_some());
+
+        let (_, node) =
+            weedle::attribute::ExtendedAttributeList::parse("[Static, Self=ByArc]").unwrap();
+        let err = MethodAttributes::try_from(&node).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Static methods don't take `self`, so can't have a [Self] attribute"
+        );
     }
 
     #[test]
@@ -698,6 +1073,69 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_trait_attribute() {
+        let (_, node) = weedle::attribute::ExtendedAttributeList::parse("[Trait]").unwrap();
+        let attrs = InterfaceAttributes::try_from(&node).unwrap();
+        assert!(attrs.is_trait());
+
+        let (_, node) = weedle::attribute::ExtendedAttributeList::parse("[]").unwrap();
+        let attrs = InterfaceAttributes::try_from(&node).unwrap();
+        assert!(!attrs.is_trait());
+
+        let (_, node) = weedle::attribute::ExtendedAttributeList::parse("[Trait, Enum]").unwrap();
+        let err = InterfaceAttributes::try_from(&node).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "conflicting attributes on interface definition"
+        );
+    }
+
+    #[test]
+    fn test_traits_attribute() {
+        let (_, node) =
+            weedle::attribute::ExtendedAttributeList::parse("[Traits=(Debug, Display, Eq, Hash)]")
+                .unwrap();
+        let attrs = InterfaceAttributes::try_from(&node).unwrap();
+        assert_eq!(
+            attrs.get_traits(),
+            vec![
+                ObjectTrait::Debug,
+                ObjectTrait::Display,
+                ObjectTrait::Eq,
+                ObjectTrait::Hash
+            ]
+        );
+
+        let (_, node) = weedle::attribute::ExtendedAttributeList::parse("[]").unwrap();
+        let attrs = InterfaceAttributes::try_from(&node).unwrap();
+        assert!(attrs.get_traits().is_empty());
+
+        let (_, node) =
+            weedle::attribute::ExtendedAttributeList::parse("[Trait, Traits=(Display)]").unwrap();
+        let attrs = InterfaceAttributes::try_from(&node).unwrap();
+        assert!(attrs.is_trait());
+        assert_eq!(attrs.get_traits(), vec![ObjectTrait::Display]);
+
+        let (_, node) =
+            weedle::attribute::ExtendedAttributeList::parse("[Traits=(Clone)]").unwrap();
+        let err = InterfaceAttributes::try_from(&node).unwrap_err();
+        assert_eq!(err.to_string(), "Unsupported trait: \"Clone\"");
+
+        let (_, node) =
+            weedle::attribute::ExtendedAttributeList::parse("[Enum, Traits=(Debug)]").unwrap();
+        let err = InterfaceAttributes::try_from(&node).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "conflicting attributes on interface definition"
+        );
+
+        let (_, node) =
+            weedle::attribute::ExtendedAttributeList::parse("[Traits=(Debug)]").unwrap();
+        let err = MethodAttributes::try_from(&node).unwrap_err();
+        assert_eq!(err.to_string(), "Traits([Debug]) not supported for methods");
+    }
+
     #[test]
     fn test_other_attributes_not_supported_for_interfaces() {
         let (_, node) =
@@ -745,4 +1183,50 @@ mod test {
         let err = TypedefAttributes::try_from(&node).unwrap_err();
         assert_eq!(err.to_string(), "ByRef not supported for typedefs");
     }
+
+    #[test]
+    fn test_deprecated_attribute() {
+        let (_, node) =
+            weedle::attribute::ExtendedAttributeList::parse(r#"[Throws=Error, Deprecated="Old"]"#)
+                .unwrap();
+        let attrs = FunctionAttributes::try_from(&node).unwrap();
+        assert!(matches!(attrs.get_deprecated(), Some("Old")));
+        let attrs = MethodAttributes::try_from(&node).unwrap();
+        assert!(matches!(attrs.get_deprecated(), Some("Old")));
+        let attrs = ConstructorAttributes::try_from(&node).unwrap();
+        assert!(matches!(attrs.get_deprecated(), Some("Old")));
+
+        let (_, node) =
+            weedle::attribute::ExtendedAttributeList::parse(r#"[Deprecated="Old"]"#).unwrap();
+        let attrs = RecordAttributes::try_from(&node).unwrap();
+        assert!(matches!(attrs.get_deprecated(), Some("Old")));
+        let attrs = FieldAttributes::try_from(&node).unwrap();
+        assert!(matches!(attrs.get_deprecated(), Some("Old")));
+        let attrs = VariantAttributes::try_from(&node).unwrap();
+        assert!(matches!(attrs.get_deprecated(), Some("Old")));
+
+        let (_, node) = weedle::attribute::ExtendedAttributeList::parse("[]").unwrap();
+        let attrs = FunctionAttributes::try_from(&node).unwrap();
+        assert!(matches!(attrs.get_deprecated(), None));
+    }
+
+    #[test]
+    fn test_other_attributes_not_supported_for_deprecatable_items() {
+        let (_, node) = weedle::attribute::ExtendedAttributeList::parse("[Throws=Error]").unwrap();
+        let err = RecordAttributes::try_from(&node).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Throws(\"Error\") not supported for dictionaries"
+        );
+        let err = FieldAttributes::try_from(&node).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Throws(\"Error\") not supported for dictionary members"
+        );
+        let err = VariantAttributes::try_from(&node).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Throws(\"Error\") not supported for enum variants"
+        );
+    }
 }
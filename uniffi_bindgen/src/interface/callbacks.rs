@@ -47,6 +47,7 @@ pub struct CallbackInterface {
     pub(super) name: String,
     pub(super) methods: Vec<Method>,
     pub(super) ffi_init_callback: FFIFunction,
+    pub(super) docstring: Option<String>,
 }
 
 impl CallbackInterface {
@@ -55,6 +56,7 @@ impl CallbackInterface {
             name,
             methods: Default::default(),
             ffi_init_callback: Default::default(),
+            docstring: None,
         }
     }
 
@@ -74,6 +76,10 @@ impl CallbackInterface {
         &self.ffi_init_callback
     }
 
+    pub fn docstring(&self) -> Option<&str> {
+        self.docstring.as_deref()
+    }
+
     pub(super) fn derive_ffi_funcs(&mut self, ci_prefix: &str) {
         self.ffi_init_callback.name = format!("ffi_{}_{}_init_callback", ci_prefix, self.name);
         self.ffi_init_callback.arguments = vec![FFIArgument {
@@ -92,12 +98,8 @@ impl IterTypes for CallbackInterface {
 
 impl Hash for CallbackInterface {
     fn hash<H: Hasher>(&self, state: &mut H) {
-        // We don't include the FFIFunc in the hash calculation, because:
-        //  - it is entirely determined by the other fields,
-        //    so excluding it is safe.
-        //  - its `name` property includes a checksum derived from  the very
-        //    hash value we're trying to calculate here, so excluding it
-        //    avoids a weird circular depenendency in the calculation.
+        // We don't include the FFIFunc in the hash calculation, because
+        // it is entirely determined by the other fields, so excluding it is safe.
         self.name.hash(state);
         self.methods.hash(state);
     }
@@ -116,6 +118,9 @@ impl APIConverter<CallbackInterface> for weedle::CallbackInterfaceDefinition<'_>
             match member {
                 weedle::interface::InterfaceMember::Operation(t) => {
                     let mut method: Method = t.convert(ci)?;
+                    if method.is_async() {
+                        bail!("callback interface methods cannot be async");
+                    }
                     method.object_name.push_str(object.name.as_str());
                     object.methods.push(method);
                 }
@@ -175,4 +180,56 @@ mod test {
         assert_eq!(callbacks_two.methods()[0].name(), "two");
         assert_eq!(callbacks_two.methods()[1].name(), "too");
     }
+
+    #[test]
+    fn test_throwing_methods() {
+        const UDL: &str = r#"
+            namespace test{};
+            [Error]
+            interface TestError { Oops(string reason); };
+            callback interface Testing {
+                [Throws=TestError]
+                string fallible();
+                string infallible();
+            };
+        "#;
+        let ci = ComponentInterface::from_webidl(UDL).unwrap();
+        let callbacks = ci.get_callback_interface_definition("Testing").unwrap();
+        assert_eq!(callbacks.methods()[0].throws(), Some("TestError"));
+        assert_eq!(callbacks.methods()[1].throws(), None);
+    }
+
+    #[test]
+    fn test_flat_errors_not_supported() {
+        const UDL: &str = r#"
+            namespace test{};
+            [Error]
+            enum TestError { "Oops" };
+            callback interface Testing {
+                [Throws=TestError]
+                string fallible();
+            };
+        "#;
+        let err = ComponentInterface::from_webidl(UDL).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Callback interface method Testing.fallible can't throw the flat error TestError; declare it as an `[Error] interface` instead"
+        );
+    }
+
+    #[test]
+    fn test_async_methods_not_supported() {
+        const UDL: &str = r#"
+            namespace test{};
+            callback interface Testing {
+                [Async]
+                string hello();
+            };
+        "#;
+        let err = ComponentInterface::from_webidl(UDL).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "callback interface methods cannot be async"
+        );
+    }
 }
@@ -0,0 +1,268 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! # Constant definitions for a `ComponentInterface`.
+//!
+//! This module converts constant declarations in the namespace of the UDL into structures
+//! that can be added to a `ComponentInterface`. A declaration in the UDL like this:
+//!
+//! ```
+//! # let ci = uniffi_bindgen::interface::ComponentInterface::from_webidl(r##"
+//! namespace example {
+//!     const u32 MAX_ITEMS = 500;
+//!     const string API_URL = "https://example.com/api";
+//! };
+//! # "##)?;
+//! # Ok::<(), anyhow::Error>(())
+//! ```
+//!
+//! Will result in a [`Constant`] member being added to the resulting [`ComponentInterface`]
+//! for each declaration:
+//!
+//! ```
+//! # use uniffi_bindgen::interface::{Literal, Type};
+//! # let ci = uniffi_bindgen::interface::ComponentInterface::from_webidl(r##"
+//! # namespace example {
+//! #     const u32 MAX_ITEMS = 500;
+//! #     const string API_URL = "https://example.com/api";
+//! # };
+//! # "##)?;
+//! let constant = ci.get_constant_definition("MAX_ITEMS").unwrap();
+//! assert_eq!(constant.name(), "MAX_ITEMS");
+//! assert_eq!(constant.type_(), Type::UInt32);
+//! assert!(matches!(constant.value(), Literal::UInt(500, _, Type::UInt32)));
+//! # Ok::<(), anyhow::Error>(())
+//! ```
+//!
+//! Only primitive types and strings can be used for constants. The Rust code is expected to
+//! define a `const` with the same name and value, which the scaffolding checks at compile time.
+//!
+//! WebIDL doesn't allow constants in a namespace, so weedle can't parse them for us. Instead
+//! they're taken out of the UDL before handing it to weedle (see [`super::preprocess`]), and
+//! we parse each one separately.
+
+use std::hash::{Hash, Hasher};
+
+use anyhow::{bail, Result};
+
+use super::literal::{convert_default_value, Literal};
+use super::types::{IterTypes, Type, TypeIterator};
+use super::{APIConverter, ComponentInterface};
+
+/// Represents a constant value exposed by the component.
+///
+/// Each `Constant` corresponds to a `const` item in the rust module, and is
+/// generated as a native constant in the foreign language bindings.
+#[derive(Debug, Clone)]
+pub struct Constant {
+    pub(super) name: String,
+    pub(super) type_: Type,
+    pub(super) value: Literal,
+    pub(super) docstring: Option<String>,
+}
+
+impl Constant {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn type_(&self) -> Type {
+        self.type_.clone()
+    }
+
+    pub fn value(&self) -> Literal {
+        self.value.clone()
+    }
+
+    pub fn docstring(&self) -> Option<&str> {
+        self.docstring.as_deref()
+    }
+}
+
+impl IterTypes for Constant {
+    fn iter_types(&self) -> TypeIterator<'_> {
+        self.type_.iter_types()
+    }
+}
+
+impl Hash for Constant {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.name.hash(state);
+        self.type_.hash(state);
+        self.value.hash(state);
+    }
+}
+
+/// A constant declaration as parsed from the UDL, before its type has been resolved.
+#[derive(Debug)]
+pub(super) struct ConstantDeclaration<'a> {
+    type_: weedle::types::Type<'a>,
+    identifier: weedle::common::Identifier<'a>,
+    value: weedle::literal::DefaultValue<'a>,
+}
+
+impl<'a> ConstantDeclaration<'a> {
+    /// Parse a declaration of the form `const <type> <name> = <value>;`.
+    pub(super) fn parse(text: &'a str) -> Result<Self> {
+        use weedle::Parse;
+        let parse_error = || anyhow::anyhow!("Failed to parse constant declaration: {}", text);
+        let rest = match text.trim().strip_prefix("const") {
+            Some(rest) => rest,
+            None => return Err(parse_error()),
+        };
+        let (rest, type_) =
+            weedle::types::Type::parse(rest.trim_start()).map_err(|_| parse_error())?;
+        let (rest, identifier) =
+            weedle::common::Identifier::parse(rest.trim_start()).map_err(|_| parse_error())?;
+        let rest = match rest.trim_start().strip_prefix('=') {
+            Some(rest) => rest,
+            None => return Err(parse_error()),
+        };
+        let (rest, value) =
+            weedle::literal::DefaultValue::parse(rest.trim_start()).map_err(|_| parse_error())?;
+        if rest.trim() != ";" {
+            return Err(parse_error());
+        }
+        Ok(Self {
+            type_,
+            identifier,
+            value,
+        })
+    }
+}
+
+impl APIConverter<Constant> for ConstantDeclaration<'_> {
+    fn convert(&self, ci: &mut ComponentInterface) -> Result<Constant> {
+        let name = self.identifier.0.to_string();
+        let type_ = ci.resolve_type_expression(&self.type_)?;
+        match type_ {
+            Type::UInt8
+            | Type::Int8
+            | Type::UInt16
+            | Type::Int16
+            | Type::UInt32
+            | Type::Int32
+            | Type::UInt64
+            | Type::Int64
+            | Type::Float32
+            | Type::Float64
+            | Type::Boolean
+            | Type::String => {}
+            _ => bail!(
+                "Constant \"{}\" has unsupported type {:?}; only primitive types and strings can be constants",
+                name,
+                type_
+            ),
+        }
+        let value = convert_default_value(&self.value, &type_)?;
+        Ok(Constant {
+            name,
+            type_,
+            value,
+            docstring: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::Radix;
+    use super::*;
+
+    #[test]
+    fn test_constants() {
+        const UDL: &str = r#"
+            namespace test {
+                const u32 MAX_ITEMS = 500;
+                const i8 MIN_OFFSET = -0x10;
+                const u16 MASK = 0xff;
+                const f64 RATIO = 0.5;
+                const boolean ENABLED = true;
+                const string API_URL = "https://example.com/api";
+            };
+        "#;
+        let ci = ComponentInterface::from_webidl(UDL).unwrap();
+        let constants = ci.iter_constant_definitions();
+        let names: Vec<_> = constants.iter().map(|c| c.name()).collect();
+        assert_eq!(
+            names,
+            vec![
+                "MAX_ITEMS",
+                "MIN_OFFSET",
+                "MASK",
+                "RATIO",
+                "ENABLED",
+                "API_URL"
+            ]
+        );
+        assert!(matches!(
+            ci.get_constant_definition("MIN_OFFSET").unwrap().value(),
+            Literal::Int(-16, Radix::Decimal, Type::Int8)
+        ));
+        assert!(matches!(
+            ci.get_constant_definition("MASK").unwrap().value(),
+            Literal::UInt(0xff, Radix::Hexadecimal, Type::UInt16)
+        ));
+        assert!(matches!(
+            ci.get_constant_definition("RATIO").unwrap().value(),
+            Literal::Float(v, Type::Float64) if v == "0.5"
+        ));
+        assert!(matches!(
+            ci.get_constant_definition("ENABLED").unwrap().value(),
+            Literal::Boolean(true)
+        ));
+        assert!(matches!(
+            ci.get_constant_definition("API_URL").unwrap().value(),
+            Literal::String(v) if v == "https://example.com/api"
+        ));
+    }
+
+    #[test]
+    fn test_constant_errors() {
+        const UDL1: &str = r#"
+            namespace test {
+                const u32 MAX_ITEMS = "lots";
+            };
+        "#;
+        let err = ComponentInterface::from_webidl(UDL1).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "No support for String(StringLit(\"lots\")) literal yet"
+        );
+
+        const UDL2: &str = r#"
+            namespace test {
+                const sequence<u32> ITEMS = [];
+            };
+        "#;
+        let err = ComponentInterface::from_webidl(UDL2).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Constant \"ITEMS\" has unsupported type Sequence(UInt32); only primitive types and strings can be constants"
+        );
+
+        const UDL3: &str = r#"
+            namespace test {
+                const u32 MAX_ITEMS = 1;
+                const u32 MAX_ITEMS = 2;
+            };
+        "#;
+        let err = ComponentInterface::from_webidl(UDL3).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "duplicate constant definition: \"MAX_ITEMS\""
+        );
+
+        const UDL4: &str = r#"
+            namespace test {
+                const u32 MAX_ITEMS 1;
+            };
+        "#;
+        let err = ComponentInterface::from_webidl(UDL4).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Failed to parse constant declaration: const u32 MAX_ITEMS 1;"
+        );
+    }
+}
@@ -0,0 +1,441 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! # Doc comments for a `ComponentInterface`.
+//!
+//! Any `///` comments in the UDL are passed through to the generated bindings, as the
+//! documentation for the item that they precede. For example:
+//!
+//! ```
+//! # let ci = uniffi_bindgen::interface::ComponentInterface::from_webidl(r##"
+//! /// The example component.
+//! namespace example {
+//!     /// Say hello.
+//!     ///
+//!     /// It's polite.
+//!     string hello();
+//! };
+//!
+//! /// A point on the plane.
+//! dictionary Point {
+//!     /// The distance along the x axis.
+//!     double x;
+//!     double y;
+//! };
+//! # "##)?;
+//! # Ok::<(), anyhow::Error>(())
+//! ```
+//!
+//! Will set the `docstring()` of each of those items:
+//!
+//! ```
+//! # let ci = uniffi_bindgen::interface::ComponentInterface::from_webidl(r##"
+//! # /// The example component.
+//! # namespace example {
+//! #     /// Say hello.
+//! #     ///
+//! #     /// It's polite.
+//! #     string hello();
+//! # };
+//! # /// A point on the plane.
+//! # dictionary Point {
+//! #     /// The distance along the x axis.
+//! #     double x;
+//! #     double y;
+//! # };
+//! # "##)?;
+//! assert_eq!(ci.namespace_docstring(), Some("The example component."));
+//! let func = ci.get_function_definition("hello").unwrap();
+//! assert_eq!(func.docstring(), Some("Say hello.\n\nIt's polite."));
+//! let rec = ci.get_record_definition("Point").unwrap();
+//! assert_eq!(rec.docstring(), Some("A point on the plane."));
+//! assert_eq!(rec.fields()[0].docstring(), Some("The distance along the x axis."));
+//! assert_eq!(rec.fields()[1].docstring(), None);
+//! # Ok::<(), anyhow::Error>(())
+//! ```
+//!
+//! Weedle treats comments as whitespace, so we find the doc comments in the tokens from
+//! [`super::preprocess`]. This only needs to understand enough of the syntax to work out the name
+//! of each definition and member, which we use to attach the comments once the UDL has been parsed.
+
+use std::collections::HashMap;
+
+use super::preprocess::{Token, Tokens};
+use super::ComponentInterface;
+
+/// The doc comments in some UDL, keyed by the name of the item they document.
+///
+/// Top-level definitions are keyed by their name, and their members by the name of the
+/// definition together with the name of the member. Members of the namespace, such as
+/// functions, are keyed by the name of the namespace.
+#[derive(Debug, Default)]
+pub(super) struct Docstrings {
+    docs: HashMap<(String, Option<String>), String>,
+}
+
+impl Docstrings {
+    /// Find the doc comments in some UDL.
+    pub(super) fn extract(idl: &str) -> Self {
+        let mut docstrings = Self::default();
+        let mut tokens = Tokens::new(idl);
+        while !tokens.is_empty() {
+            let (docstring, _) = tokens.take_docstring_and_attributes();
+            // The name of a definition is the identifier after the keyword, which
+            // may be preceded by other keywords such as `callback`.
+            let mut name = None;
+            let mut after_keyword = false;
+            while let Some(token) = tokens.next() {
+                match token {
+                    Token::Ident(ident) if after_keyword && name.is_none() => {
+                        name = Some(ident.to_string())
+                    }
+                    Token::Ident("namespace")
+                    | Token::Ident("dictionary")
+                    | Token::Ident("enum")
+                    | Token::Ident("interface") => after_keyword = true,
+                    Token::Punct('{') => {
+                        if let Some(name) = &name {
+                            docstrings.insert(name, None, docstring);
+                            docstrings.extract_members(name, &mut tokens);
+                        } else {
+                            tokens.skip_block();
+                        }
+                        break;
+                    }
+                    Token::Punct(';') => break,
+                    _ => {}
+                }
+            }
+        }
+        docstrings
+    }
+
+    /// Find the doc comments on the members of a definition, up to the end of its body.
+    fn extract_members(&mut self, parent: &str, tokens: &mut Tokens<'_>) {
+        loop {
+            let (docstring, name_attribute) = tokens.take_docstring_and_attributes();
+            // Each member ends with a separator. Its name is usually the last identifier before
+            // any arguments or default value, but enum variants are strings and constructors
+            // are named by an attribute.
+            let mut name = None;
+            let mut seen_args_or_value = false;
+            let mut nesting = 0;
+            loop {
+                match tokens.next() {
+                    None => return,
+                    Some(Token::Punct('}')) if nesting == 0 => {
+                        if let Some(name) = name {
+                            self.insert(parent, Some(name), docstring);
+                        }
+                        return;
+                    }
+                    Some(Token::Punct(';')) | Some(Token::Punct(',')) if nesting == 0 => break,
+                    Some(Token::Punct('(')) => {
+                        seen_args_or_value = true;
+                        nesting += 1;
+                    }
+                    Some(Token::Punct('<')) => nesting += 1,
+                    Some(Token::Punct(')')) | Some(Token::Punct('>')) => nesting -= 1,
+                    Some(Token::Punct('=')) => seen_args_or_value = true,
+                    Some(Token::Str(s)) if name.is_none() => name = Some(s.to_string()),
+                    Some(Token::Ident("constructor")) if !seen_args_or_value => {
+                        name = Some(name_attribute.clone().unwrap_or_else(|| "new".to_string()));
+                        seen_args_or_value = true;
+                    }
+                    Some(Token::Ident(ident)) if nesting == 0 && !seen_args_or_value => {
+                        name = Some(ident.to_string())
+                    }
+                    _ => {}
+                }
+            }
+            if let Some(name) = name {
+                self.insert(parent, Some(name), docstring);
+            }
+        }
+    }
+
+    fn insert(&mut self, parent: &str, member: Option<String>, docstring: Option<String>) {
+        if let Some(docstring) = docstring {
+            self.docs.insert((parent.to_string(), member), docstring);
+        }
+    }
+
+    fn get(&self, parent: &str, member: Option<&str>) -> Option<String> {
+        self.docs
+            .get(&(parent.to_string(), member.map(str::to_string)))
+            .cloned()
+    }
+
+    /// Set the docstrings of the items in a `ComponentInterface` parsed from the same UDL.
+    pub(super) fn apply(&self, ci: &mut ComponentInterface) {
+        let namespace = ci.namespace.clone();
+        ci.namespace_docstring = self.get(&namespace, None);
+        for func in ci.functions.iter_mut() {
+            func.docstring = self.get(&namespace, Some(&func.name));
+        }
+        for constant in ci.constants.iter_mut() {
+            constant.docstring = self.get(&namespace, Some(&constant.name));
+        }
+        for rec in ci.records.iter_mut() {
+            rec.docstring = self.get(&rec.name, None);
+            for field in rec.fields.iter_mut() {
+                field.docstring = self.get(&rec.name, Some(&field.name));
+            }
+        }
+        let enums = ci
+            .enums
+            .iter_mut()
+            .chain(ci.errors.iter_mut().map(|e| &mut e.enum_));
+        for e in enums {
+            e.docstring = self.get(&e.name, None);
+            for variant in e.variants.iter_mut() {
+                variant.docstring = self.get(&e.name, Some(&variant.name));
+            }
+        }
+        for flags in ci.flags.iter_mut() {
+            flags.docstring = self.get(&flags.name, None);
+            for flag in flags.flags.iter_mut() {
+                flag.docstring = self.get(&flags.name, Some(&flag.name));
+            }
+        }
+        for obj in ci.objects.iter_mut() {
+            obj.docstring = self.get(&obj.name, None);
+            for cons in obj.constructors.iter_mut() {
+                cons.docstring = self.get(&obj.name, Some(&cons.name));
+            }
+            for meth in obj.methods.iter_mut() {
+                meth.docstring = self.get(&obj.name, Some(&meth.name));
+            }
+            for prop in obj.properties.iter_mut() {
+                prop.docstring = self.get(&obj.name, Some(&prop.name));
+            }
+        }
+        for cbi in ci.callback_interfaces.iter_mut() {
+            cbi.docstring = self.get(&cbi.name, None);
+            for meth in cbi.methods.iter_mut() {
+                meth.docstring = self.get(&cbi.name, Some(&meth.name));
+            }
+        }
+    }
+}
+
+impl Tokens<'_> {
+    /// Take any doc comments at the current position, joining their lines together.
+    fn take_docstring(&mut self) -> Option<String> {
+        let mut lines = Vec::new();
+        while let Some(Token::Doc(line)) = self.peek() {
+            // Remove the space that usually follows the slashes.
+            lines.push(line.strip_prefix(' ').unwrap_or(line).trim_end());
+            self.next();
+        }
+        if lines.is_empty() {
+            None
+        } else {
+            Some(lines.join("\n"))
+        }
+    }
+
+    /// Take the doc comments and skip the attribute lists at the current position, in any order.
+    ///
+    /// Returns the docstring, along with the value of any `Name` attribute.
+    fn take_docstring_and_attributes(&mut self) -> (Option<String>, Option<String>) {
+        let mut docstring = None;
+        let mut name = None;
+        loop {
+            if let Some(doc) = self.take_docstring() {
+                docstring = Some(doc);
+            }
+            if self.peek() != Some(Token::Punct('[')) {
+                return (docstring, name);
+            }
+            let mut nesting = 0;
+            while let Some(token) = self.next() {
+                match token {
+                    Token::Punct('[') => nesting += 1,
+                    Token::Punct(']') => {
+                        nesting -= 1;
+                        if nesting == 0 {
+                            break;
+                        }
+                    }
+                    Token::Ident("Name") => {
+                        if let (Some(Token::Punct('=')), Some(Token::Str(s))) =
+                            (self.peek(), self.peek_nth(1))
+                        {
+                            name = Some(s.to_string());
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_docstrings() {
+        const UDL: &str = r#"
+            /// The namespace.
+            namespace test {
+                /// A function.
+                void hello(string greeting = "hi; there");
+                //// Not a doc comment.
+                u32 goodbye();
+            };
+
+            /// A record.
+            dictionary Rec {
+                /// A field.
+                sequence<string> items;
+                record<DOMString, u32> counts;
+                /// A field with a default.
+                u32 count = 0;
+            };
+
+            /// An enum.
+            enum Color {
+                /// Red.
+                "Red",
+                "Green",
+                /// Blue.
+                "Blue"
+            };
+
+            /// An object.
+            [Threadsafe]
+            interface Obj {
+                /// The constructor.
+                constructor();
+                /// Another constructor.
+                [Name="from_name"]
+                constructor(string name);
+                [Throws=Oops]
+                /// A method.
+                void method(u32 value);
+                /// A property.
+                readonly attribute string label;
+            };
+
+            /// An error.
+            [Error]
+            interface Oops {
+                /// A variant.
+                Bad(string reason);
+            };
+
+            /// A callback interface.
+            callback interface Callback {
+                /// A callback method.
+                void call();
+            };
+        "#;
+        let docs = Docstrings::extract(UDL);
+        let doc = |parent: &str, member: Option<&str>| docs.get(parent, member);
+        assert_eq!(doc("test", None).unwrap(), "The namespace.");
+        assert_eq!(doc("test", Some("hello")).unwrap(), "A function.");
+        assert_eq!(doc("test", Some("goodbye")), None);
+        assert_eq!(doc("Rec", None).unwrap(), "A record.");
+        assert_eq!(doc("Rec", Some("items")).unwrap(), "A field.");
+        assert_eq!(doc("Rec", Some("counts")), None);
+        assert_eq!(
+            doc("Rec", Some("count")).unwrap(),
+            "A field with a default."
+        );
+        assert_eq!(doc("Color", None).unwrap(), "An enum.");
+        assert_eq!(doc("Color", Some("Red")).unwrap(), "Red.");
+        assert_eq!(doc("Color", Some("Green")), None);
+        assert_eq!(doc("Color", Some("Blue")).unwrap(), "Blue.");
+        assert_eq!(doc("Obj", None).unwrap(), "An object.");
+        assert_eq!(doc("Obj", Some("new")).unwrap(), "The constructor.");
+        assert_eq!(
+            doc("Obj", Some("from_name")).unwrap(),
+            "Another constructor."
+        );
+        assert_eq!(doc("Obj", Some("method")).unwrap(), "A method.");
+        assert_eq!(doc("Obj", Some("label")).unwrap(), "A property.");
+        assert_eq!(doc("Oops", None).unwrap(), "An error.");
+        assert_eq!(doc("Oops", Some("Bad")).unwrap(), "A variant.");
+        assert_eq!(doc("Callback", None).unwrap(), "A callback interface.");
+        assert_eq!(doc("Callback", Some("call")).unwrap(), "A callback method.");
+        assert_eq!(docs.docs.len(), 17);
+    }
+
+    #[test]
+    fn test_docstrings_in_interface() {
+        const UDL: &str = r#"
+            /// The namespace.
+            ///
+            /// With a second paragraph.
+            namespace test {
+                /// A constant.
+                const u32 MAX = 1;
+            };
+            /// An error.
+            [Error]
+            enum Oops {
+                /// A variant.
+                "Bad"
+            };
+            interface Obj {
+                ///   Indented.
+                void method();
+                /// A property.
+                readonly attribute string label; /// Not this one.
+            };
+        "#;
+        let ci = ComponentInterface::from_webidl(UDL).unwrap();
+        assert_eq!(
+            ci.namespace_docstring(),
+            Some("The namespace.\n\nWith a second paragraph.")
+        );
+        assert_eq!(
+            ci.get_constant_definition("MAX").unwrap().docstring(),
+            Some("A constant.")
+        );
+        let err = ci.get_error_definition("Oops").unwrap();
+        assert_eq!(err.docstring(), Some("An error."));
+        assert_eq!(err.variants()[0].docstring(), Some("A variant."));
+        let obj = ci.get_object_definition("Obj").unwrap();
+        assert_eq!(obj.docstring(), None);
+        assert_eq!(obj.methods()[0].docstring(), Some("  Indented."));
+        assert_eq!(obj.properties()[0].docstring(), Some("A property."));
+    }
+
+    #[test]
+    fn test_docstrings_dont_change_checksums() {
+        let ci1 = ComponentInterface::from_webidl(
+            r#"
+            namespace test {
+                u32 hello();
+            };
+            dictionary Rec { u32 field; };
+        "#,
+        )
+        .unwrap();
+        let ci2 = ComponentInterface::from_webidl(
+            r#"
+            /// The namespace.
+            namespace test {
+                /// A function.
+                u32 hello();
+            };
+            /// A record.
+            dictionary Rec {
+                /// A field.
+                u32 field;
+            };
+        "#,
+        )
+        .unwrap();
+        assert_eq!(ci1.checksum(), ci2.checksum());
+        assert_eq!(
+            ci1.get_function_definition("hello").unwrap().checksum(),
+            ci2.get_function_definition("hello").unwrap().checksum()
+        );
+    }
+}
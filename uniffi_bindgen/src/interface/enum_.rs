@@ -75,9 +75,39 @@
 //! assert_eq!(e.variants()[1].fields()[0].name(), "first");
 //! # Ok::<(), anyhow::Error>(())
 //! ```
+//!
+//! The variants of an enum without associated data are passed across the FFI as their
+//! 1-based index by default. They can be given explicit values instead, so that the values
+//! stay the same when variants are added or reordered, and the enum can be marked as
+//! `[NonExhaustive]` so that the bindings read any value they don't know as an extra
+//! `Unknown` variant rather than failing:
+//!
+//! ```
+//! # let ci = uniffi_bindgen::interface::ComponentInterface::from_webidl(r##"
+//! # namespace example {};
+//! [NonExhaustive]
+//! enum Level {
+//!   "Low" = 10,
+//!   "Medium",
+//!   "High" = 20
+//! };
+//! # "##)?;
+//! let e = ci.get_enum_definition("Level").unwrap();
+//! assert!(e.is_non_exhaustive());
+//! assert_eq!(e.variants()[0].discr(), 10);
+//! assert_eq!(e.variants()[1].discr(), 11);
+//! assert_eq!(e.variants()[2].discr(), 20);
+//! # Ok::<(), anyhow::Error>(())
+//! ```
+
+use std::collections::HashSet;
+use std::convert::TryFrom;
+use std::hash::{Hash, Hasher};
 
 use anyhow::{bail, Result};
 
+use super::attributes::{EnumAttributes, VariantAttributes};
+use super::preprocess::variant_value;
 use super::record::Field;
 use super::types::{IterTypes, Type, TypeIterator};
 use super::{APIConverter, ComponentInterface};
@@ -87,12 +117,15 @@ use super::{APIConverter, ComponentInterface};
 ///
 /// Enums are passed across the FFI by serializing to a bytebuffer, with a
 /// i32 indicating the variant followed by the serialization of each field.
-#[derive(Debug, Clone, Hash)]
+#[derive(Debug, Clone)]
 pub struct Enum {
     pub(super) name: String,
     pub(super) variants: Vec<Variant>,
     // "Flat" enums do not have, and will never have, variants with associated data.
     pub(super) flat: bool,
+    // Non-exhaustive enums are read as an extra `Unknown` variant if the value isn't one we know.
+    pub(super) non_exhaustive: bool,
+    pub(super) docstring: Option<String>,
 }
 
 impl Enum {
@@ -113,6 +146,22 @@ impl Enum {
     pub fn is_flat(&self) -> bool {
         self.flat
     }
+
+    pub fn is_non_exhaustive(&self) -> bool {
+        self.non_exhaustive
+    }
+
+    /// Whether each variant is passed as its 1-based index, which is how errors are passed.
+    pub(super) fn has_default_discriminants(&self) -> bool {
+        self.variants
+            .iter()
+            .enumerate()
+            .all(|(index, v)| i64::from(v.discr) == index as i64 + 1)
+    }
+
+    pub fn docstring(&self) -> Option<&str> {
+        self.docstring.as_deref()
+    }
 }
 
 impl IterTypes for Enum {
@@ -121,27 +170,76 @@ impl IterTypes for Enum {
     }
 }
 
+impl Hash for Enum {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.name.hash(state);
+        self.variants.hash(state);
+        self.flat.hash(state);
+        self.non_exhaustive.hash(state);
+    }
+}
+
 // Note that we have two `APIConverter` impls here - one for the `enum` case
 // and one for the `[Enum] interface` case.
 
 impl APIConverter<Enum> for weedle::EnumDefinition<'_> {
     fn convert(&self, _ci: &mut ComponentInterface) -> Result<Enum> {
-        Ok(Enum {
-            name: self.identifier.0.to_string(),
-            variants: self
-                .values
-                .body
-                .list
-                .iter()
-                .map::<Result<_>, _>(|v| {
-                    Ok(Variant {
-                        name: v.0.to_string(),
-                        ..Default::default()
-                    })
+        let name = self.identifier.0.to_string();
+        let attributes = EnumAttributes::try_from(self.attributes.as_ref())?;
+        let mut seen = HashSet::new();
+        // Variants without an explicit value follow on from the one before, like in Rust.
+        let mut next_discr = 1;
+        let variants = self
+            .values
+            .body
+            .list
+            .iter()
+            .map::<Result<_>, _>(|v| {
+                let (variant_name, discr) = match variant_value(v) {
+                    (variant_name, Some(value)) => match value.parse::<i64>() {
+                        Ok(discr) => (variant_name, discr),
+                        Err(_) => bail!("Invalid value for {}::{}: {}", name, variant_name, value),
+                    },
+                    (variant_name, None) => (variant_name, next_discr),
+                };
+                let discr = match i32::try_from(discr) {
+                    Ok(discr) => discr,
+                    Err(_) => bail!(
+                        "The value of {}::{} must fit in an i32, but it's {}",
+                        name,
+                        variant_name,
+                        discr
+                    ),
+                };
+                if !seen.insert(discr) {
+                    bail!(
+                        "{}::{} has the same value as another variant",
+                        name,
+                        variant_name
+                    );
+                }
+                if attributes.is_non_exhaustive() && variant_name.eq_ignore_ascii_case("unknown") {
+                    bail!(
+                        "{}::{} clashes with the variant that [NonExhaustive] adds",
+                        name,
+                        variant_name
+                    );
+                }
+                next_discr = i64::from(discr) + 1;
+                Ok(Variant {
+                    name: variant_name.to_string(),
+                    discr,
+                    ..Default::default()
                 })
-                .collect::<Result<Vec<_>>>()?,
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Enum {
+            name,
+            variants,
             // Enums declared using the `enum` syntax can never have variants with fields.
             flat: true,
+            non_exhaustive: attributes.is_non_exhaustive(),
+            docstring: None,
         })
     }
 }
@@ -159,8 +257,12 @@ impl APIConverter<Enum> for weedle::InterfaceDefinition<'_> {
                 .members
                 .body
                 .iter()
-                .map::<Result<Variant>, _>(|member| match member {
-                    weedle::interface::InterfaceMember::Operation(t) => Ok(t.convert(ci)?),
+                .enumerate()
+                .map::<Result<Variant>, _>(|(index, member)| match member {
+                    weedle::interface::InterfaceMember::Operation(t) => Ok(Variant {
+                        discr: index as i32 + 1,
+                        ..t.convert(ci)?
+                    }),
                     _ => bail!(
                         "interface member type {:?} not supported in enum interface",
                         member
@@ -169,6 +271,9 @@ impl APIConverter<Enum> for weedle::InterfaceDefinition<'_> {
                 .collect::<Result<Vec<_>>>()?,
             // Enums declared using the `[Enum] interface` syntax might have variants with fields.
             flat: false,
+            // An unknown variant might have fields, and we wouldn't know how to skip over them.
+            non_exhaustive: false,
+            docstring: None,
         })
     }
 }
@@ -176,16 +281,25 @@ impl APIConverter<Enum> for weedle::InterfaceDefinition<'_> {
 /// Represents an individual variant in an Enum.
 ///
 /// Each variant has a name and zero or more fields.
-#[derive(Debug, Clone, Default, Hash)]
+#[derive(Debug, Clone, Default)]
 pub struct Variant {
     pub(super) name: String,
+    // The value that identifies the variant when it's passed across the FFI.
+    pub(super) discr: i32,
     pub(super) fields: Vec<Field>,
+    pub(super) deprecated: Option<String>,
+    pub(super) docstring: Option<String>,
 }
 
 impl Variant {
     pub fn name(&self) -> &str {
         &self.name
     }
+
+    pub fn discr(&self) -> i32 {
+        self.discr
+    }
+
     pub fn fields(&self) -> Vec<&Field> {
         self.fields.iter().collect()
     }
@@ -193,6 +307,14 @@ impl Variant {
     pub fn has_fields(&self) -> bool {
         !self.fields.is_empty()
     }
+
+    pub fn deprecated(&self) -> Option<&str> {
+        self.deprecated.as_deref()
+    }
+
+    pub fn docstring(&self) -> Option<&str> {
+        self.docstring.as_deref()
+    }
 }
 
 impl IterTypes for Variant {
@@ -201,6 +323,15 @@ impl IterTypes for Variant {
     }
 }
 
+impl Hash for Variant {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.name.hash(state);
+        self.discr.hash(state);
+        self.fields.hash(state);
+        self.deprecated.hash(state);
+    }
+}
+
 impl APIConverter<Variant> for weedle::interface::OperationInterfaceMember<'_> {
     fn convert(&self, ci: &mut ComponentInterface) -> Result<Variant> {
         if self.special.is_some() {
@@ -225,8 +356,10 @@ impl APIConverter<Variant> for weedle::interface::OperationInterfaceMember<'_> {
                 _ => bail!("enum interface members must have plain identifers as names"),
             }
         };
+        let attributes = VariantAttributes::try_from(self.attributes.as_ref())?;
         Ok(Variant {
             name,
+            discr: 0,
             fields: self
                 .args
                 .body
@@ -234,6 +367,8 @@ impl APIConverter<Variant> for weedle::interface::OperationInterfaceMember<'_> {
                 .iter()
                 .map(|arg| arg.convert(ci))
                 .collect::<Result<Vec<_>>>()?,
+            deprecated: attributes.get_deprecated().map(String::from),
+            docstring: None,
         })
     }
 }
@@ -266,6 +401,8 @@ impl APIConverter<Field> for weedle::argument::SingleArgument<'_> {
             type_,
             required: false,
             default: None,
+            deprecated: None,
+            docstring: None,
         })
     }
 }
@@ -291,6 +428,23 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_deprecated_variants() {
+        const UDL: &str = r#"
+            namespace test{};
+            [Enum]
+            interface Shape {
+                [Deprecated="Use Polygon"]
+                Triangle(double base, double height);
+                Polygon(sequence<double> sides);
+            };
+        "#;
+        let ci = ComponentInterface::from_webidl(UDL).unwrap();
+        let e = ci.get_enum_definition("Shape").unwrap();
+        assert_eq!(e.variants()[0].deprecated(), Some("Use Polygon"));
+        assert_eq!(e.variants()[1].deprecated(), None);
+    }
+
     #[test]
     fn test_associated_data() {
         const UDL: &str = r##"
@@ -415,4 +569,102 @@ mod test {
             Some(FFIType::RustBuffer)
         ));
     }
+
+    #[test]
+    fn test_discriminants() {
+        const UDL: &str = r#"
+            namespace test{};
+            enum Implicit { "one", "two" };
+            // The values can be given in any order, and the others count on from them.
+            enum Explicit { "minus" = -1, "zero", "ten" = 10, "eleven", "five"=5 };
+            [Enum]
+            interface WithData {
+                Zero();
+                One(u32 first);
+            };
+        "#;
+        let ci = ComponentInterface::from_webidl(UDL).unwrap();
+        let discrs = |name| {
+            ci.get_enum_definition(name)
+                .unwrap()
+                .variants()
+                .iter()
+                .map(|v| (v.name().to_string(), v.discr()))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            discrs("Implicit"),
+            vec![("one".to_string(), 1), ("two".to_string(), 2)]
+        );
+        assert_eq!(
+            discrs("Explicit"),
+            vec![
+                ("minus".to_string(), -1),
+                ("zero".to_string(), 0),
+                ("ten".to_string(), 10),
+                ("eleven".to_string(), 11),
+                ("five".to_string(), 5)
+            ]
+        );
+        assert_eq!(
+            discrs("WithData"),
+            vec![("Zero".to_string(), 1), ("One".to_string(), 2)]
+        );
+        assert!(!ci
+            .get_enum_definition("Explicit")
+            .unwrap()
+            .is_non_exhaustive());
+
+        // The values are part of the API, so they change the checksum.
+        let ci2 =
+            ComponentInterface::from_webidl(&UDL.replace("\"ten\" = 10", "\"ten\" = 9")).unwrap();
+        assert_ne!(ci.checksum(), ci2.checksum());
+    }
+
+    #[test]
+    fn test_non_exhaustive() {
+        const UDL: &str = r#"
+            namespace test{};
+            [NonExhaustive]
+            enum Level { "Low" = 10, "High" = 20 };
+        "#;
+        let ci = ComponentInterface::from_webidl(UDL).unwrap();
+        let e = ci.get_enum_definition("Level").unwrap();
+        assert!(e.is_flat());
+        assert!(e.is_non_exhaustive());
+        assert_eq!(e.variants().len(), 2);
+    }
+
+    #[test]
+    fn test_discriminant_errors() {
+        let err = |udl: &str| {
+            ComponentInterface::from_webidl(&format!("namespace test{{}}; {}", udl))
+                .unwrap_err()
+                .to_string()
+        };
+        assert_eq!(
+            err(r#"enum Testing { "one" = 2, "two" = 2 };"#),
+            "Testing::two has the same value as another variant"
+        );
+        assert_eq!(
+            err(r#"enum Testing { "one" = 1, "two" = 0, "three" };"#),
+            "Testing::three has the same value as another variant"
+        );
+        assert_eq!(
+            err(r#"enum Testing { "one" = 2147483647, "two" };"#),
+            "The value of Testing::two must fit in an i32, but it's 2147483648"
+        );
+        assert_eq!(
+            err(r#"enum Testing { "one" = 99999999999999999999 };"#),
+            "Invalid value for Testing::one: 99999999999999999999"
+        );
+        assert_eq!(
+            err(r#"[NonExhaustive] enum Testing { "one", "Unknown" };"#),
+            "Testing::Unknown clashes with the variant that [NonExhaustive] adds"
+        );
+        assert_eq!(
+            err(r#"[Error] enum Testing { "one" = 3 };"#),
+            "Error variants can't have explicit values"
+        );
+    }
 }
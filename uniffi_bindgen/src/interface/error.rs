@@ -82,7 +82,7 @@
 //! # Ok::<(), anyhow::Error>(())
 //! ```
 
-use anyhow::Result;
+use anyhow::{bail, Result};
 
 use super::enum_::{Enum, Variant};
 use super::types::{IterTypes, Type, TypeIterator};
@@ -97,7 +97,7 @@ use super::{APIConverter, ComponentInterface};
 #[derive(Debug, Clone, Hash)]
 pub struct Error {
     pub name: String,
-    enum_: Enum,
+    pub(super) enum_: Enum,
 }
 
 impl Error {
@@ -129,6 +129,10 @@ impl Error {
     pub fn is_flat(&self) -> bool {
         self.enum_.is_flat()
     }
+
+    pub fn docstring(&self) -> Option<&str> {
+        self.enum_.docstring()
+    }
 }
 
 impl IterTypes for Error {
@@ -139,7 +143,11 @@ impl IterTypes for Error {
 
 impl APIConverter<Error> for weedle::EnumDefinition<'_> {
     fn convert(&self, ci: &mut ComponentInterface) -> Result<Error> {
-        Ok(Error::from_enum(APIConverter::<Enum>::convert(self, ci)?))
+        let enum_ = APIConverter::<Enum>::convert(self, ci)?;
+        if !enum_.has_default_discriminants() {
+            bail!("Error variants can't have explicit values");
+        }
+        Ok(Error::from_enum(enum_))
     }
 }
 
@@ -45,6 +45,10 @@ pub enum FFIType {
     /// A pointer to a single function in to the foreign language.
     /// This function contains all the machinery to make callbacks work on the foreign language side.
     ForeignCallback,
+    /// A pointer to a foreign language function that is used to wake a pending async call.
+    /// Rust invokes it with an opaque `u64` supplied by the foreign language code when it polled
+    /// the future, to signal that the future is ready to make progress and should be polled again.
+    ForeignWaker,
     // TODO: you can imagine a richer structural typesystem here, e.g. `Ref<String>` or something.
     // We don't need that yet and it's possible we never will, so it isn't here for now.
 }
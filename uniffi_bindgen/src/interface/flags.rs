@@ -0,0 +1,289 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! # Flags definitions for a `ComponentInterface`.
+//!
+//! This module converts sets of flags from UDL into structures that can be added to a
+//! `ComponentInterface`. Flags are declared as an `enum` with a `[Flags]` attribute naming
+//! the unsigned integer type that holds them, and an explicit value for each flag:
+//!
+//! ```
+//! # let ci = uniffi_bindgen::interface::ComponentInterface::from_webidl(r##"
+//! # namespace example {};
+//! [Flags=u32]
+//! enum Permissions {
+//!   "READ" = 1,
+//!   "WRITE" = 2,
+//!   "EXECUTE" = 0x4
+//! };
+//! # "##)?;
+//! # Ok::<(), anyhow::Error>(())
+//! ```
+//!
+//! Will result in a [`Flags`] member being added to the resulting [`ComponentInterface`]:
+//!
+//! ```
+//! # let ci = uniffi_bindgen::interface::ComponentInterface::from_webidl(r##"
+//! # namespace example {};
+//! # [Flags=u32]
+//! # enum Permissions {
+//! #   "READ" = 1,
+//! #   "WRITE" = 2,
+//! #   "EXECUTE" = 0x4
+//! # };
+//! # "##)?;
+//! # use uniffi_bindgen::interface::Type;
+//! let f = ci.get_flags_definition("Permissions").unwrap();
+//! assert_eq!(f.name(), "Permissions");
+//! assert_eq!(f.prim(), Type::UInt32);
+//! assert_eq!(f.flags().len(), 3);
+//! assert_eq!(f.flags()[0].name(), "READ");
+//! assert_eq!(f.flags()[2].value(), 4);
+//! # Ok::<(), anyhow::Error>(())
+//! ```
+//!
+//! On the Rust side, this is expected to be a type defined with the `bitflags!` macro
+//! (or anything with the same `bits()` and `from_bits()` methods and flag constants).
+//! It's passed across the FFI as the integer that holds its bits.
+
+use std::collections::HashSet;
+use std::convert::TryFrom;
+use std::hash::{Hash, Hasher};
+
+use anyhow::{bail, Result};
+
+use super::attributes::EnumAttributes;
+use super::ffi::FFIType;
+use super::preprocess::variant_value;
+use super::types::{IterTypes, Type, TypeIterator};
+use super::{APIConverter, ComponentInterface};
+
+/// Represents a set of named flags, each of which is one or more bits of an unsigned integer.
+#[derive(Debug, Clone)]
+pub struct Flags {
+    pub(super) name: String,
+    pub(super) prim: Type,
+    pub(super) flags: Vec<Flag>,
+    pub(super) docstring: Option<String>,
+}
+
+impl Flags {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn type_(&self) -> Type {
+        Type::Flags {
+            name: self.name.clone(),
+            prim: Box::new(self.prim.clone()),
+        }
+    }
+
+    /// The unsigned integer type that holds the bits of the flags.
+    pub fn prim(&self) -> Type {
+        self.prim.clone()
+    }
+
+    /// The type that the flags are passed across the FFI as.
+    pub fn ffi_type(&self) -> FFIType {
+        FFIType::from(&self.prim)
+    }
+
+    pub fn flags(&self) -> Vec<&Flag> {
+        self.flags.iter().collect()
+    }
+
+    pub fn docstring(&self) -> Option<&str> {
+        self.docstring.as_deref()
+    }
+}
+
+impl IterTypes for Flags {
+    fn iter_types(&self) -> TypeIterator<'_> {
+        self.prim.iter_types()
+    }
+}
+
+impl Hash for Flags {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.name.hash(state);
+        self.prim.hash(state);
+        self.flags.hash(state);
+    }
+}
+
+impl APIConverter<Flags> for weedle::EnumDefinition<'_> {
+    fn convert(&self, _ci: &mut ComponentInterface) -> Result<Flags> {
+        let name = self.identifier.0.to_string();
+        let attributes = EnumAttributes::try_from(self.attributes.as_ref())?;
+        let prim = match attributes.get_flags_type() {
+            Some("u8") => Type::UInt8,
+            Some("u16") => Type::UInt16,
+            Some("u32") => Type::UInt32,
+            Some("u64") => Type::UInt64,
+            _ => bail!("{} is missing its [Flags] attribute", name),
+        };
+        let max_value = match prim {
+            Type::UInt8 => u64::from(u8::MAX),
+            Type::UInt16 => u64::from(u16::MAX),
+            Type::UInt32 => u64::from(u32::MAX),
+            _ => u64::MAX,
+        };
+        let mut seen = HashSet::new();
+        let flags = self
+            .values
+            .body
+            .list
+            .iter()
+            .map::<Result<_>, _>(|v| {
+                let (flag_name, value) = match variant_value(v) {
+                    (flag_name, Some(value)) => (flag_name, value),
+                    (flag_name, None) => bail!(
+                        "{}::{} needs a value, like \"{}\" = 1",
+                        name,
+                        flag_name,
+                        flag_name
+                    ),
+                };
+                let value = match parse_flag_value(value) {
+                    Some(value) if value <= max_value => value,
+                    Some(_) => bail!(
+                        "The value of {}::{} must fit in a {}, but it's {}",
+                        name,
+                        flag_name,
+                        prim.canonical_name(),
+                        value
+                    ),
+                    None => bail!("Invalid value for {}::{}: {}", name, flag_name, value),
+                };
+                if !seen.insert(flag_name) {
+                    bail!("{}::{} is declared more than once", name, flag_name);
+                }
+                Ok(Flag {
+                    name: flag_name.to_string(),
+                    value,
+                    docstring: None,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Flags {
+            name,
+            prim,
+            flags,
+            docstring: None,
+        })
+    }
+}
+
+/// Parse the value of a flag, which can be decimal or hex, with `_` between the digits.
+fn parse_flag_value(value: &str) -> Option<u64> {
+    let digits = value.replace('_', "");
+    match digits.strip_prefix("0x") {
+        Some(hex) => u64::from_str_radix(hex, 16).ok(),
+        None => digits.parse().ok(),
+    }
+}
+
+/// Represents an individual flag in a set of flags.
+#[derive(Debug, Clone)]
+pub struct Flag {
+    pub(super) name: String,
+    pub(super) value: u64,
+    pub(super) docstring: Option<String>,
+}
+
+impl Flag {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The bits that are set for this flag.
+    pub fn value(&self) -> u64 {
+        self.value
+    }
+
+    pub fn docstring(&self) -> Option<&str> {
+        self.docstring.as_deref()
+    }
+}
+
+impl Hash for Flag {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.name.hash(state);
+        self.value.hash(state);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_flags() {
+        const UDL: &str = r#"
+            namespace test{
+                Permissions combine(Permissions a, Permissions b);
+            };
+            [Flags=u16]
+            enum Permissions {
+                "READ" = 1,
+                "WRITE" = 0x02,
+                "ALL" = 0b11
+            };
+        "#;
+        let err = ComponentInterface::from_webidl(UDL).unwrap_err();
+        assert_eq!(err.to_string(), "Invalid value for Permissions::ALL: 0b11");
+
+        let ci = ComponentInterface::from_webidl(&UDL.replace("0b11", "0x_ffff")).unwrap();
+        assert_eq!(ci.iter_flags_definitions().len(), 1);
+        assert_eq!(ci.iter_enum_definitions().len(), 0);
+        let f = ci.get_flags_definition("Permissions").unwrap();
+        assert_eq!(f.prim(), Type::UInt16);
+        assert_eq!(
+            f.flags()
+                .iter()
+                .map(|flag| (flag.name(), flag.value()))
+                .collect::<Vec<_>>(),
+            vec![("READ", 1), ("WRITE", 2), ("ALL", 0xffff)]
+        );
+
+        // Flags are passed as the integer that holds them, rather than in a buffer.
+        let func = ci.get_function_definition("combine").unwrap();
+        assert_eq!(func.arguments()[0].type_(), f.type_());
+        assert!(matches!(f.ffi_type(), FFIType::UInt16));
+        assert!(matches!(
+            func.ffi_func().arguments()[0].type_(),
+            FFIType::UInt16
+        ));
+        assert!(matches!(
+            func.ffi_func().return_type(),
+            Some(FFIType::UInt16)
+        ));
+    }
+
+    #[test]
+    fn test_flags_errors() {
+        let err = |udl: &str| {
+            ComponentInterface::from_webidl(&format!("namespace test{{}}; {}", udl))
+                .unwrap_err()
+                .to_string()
+        };
+        assert_eq!(
+            err(r#"[Flags=u8] enum Testing { "A" = 1, "B" };"#),
+            "Testing::B needs a value, like \"B\" = 1"
+        );
+        assert_eq!(
+            err(r#"[Flags=u8] enum Testing { "A" = 256 };"#),
+            "The value of Testing::A must fit in a u8, but it's 256"
+        );
+        assert_eq!(
+            err(r#"[Flags=u32] enum Testing { "A" = -1 };"#),
+            "Invalid value for Testing::A: -1"
+        );
+        assert_eq!(
+            err(r#"[Flags=u32] enum Testing { "A" = 1, "A" = 2 };"#),
+            "Testing::A is declared more than once"
+        );
+    }
+}
@@ -37,7 +37,7 @@ use std::hash::{Hash, Hasher};
 use anyhow::{bail, Result};
 
 use super::attributes::{ArgumentAttributes, FunctionAttributes};
-use super::ffi::{FFIArgument, FFIFunction};
+use super::ffi::{FFIArgument, FFIFunction, FFIType};
 use super::literal::{convert_default_value, Literal};
 use super::types::{IterTypes, Type, TypeIterator};
 use super::{APIConverter, ComponentInterface};
@@ -54,7 +54,10 @@ pub struct Function {
     pub(super) arguments: Vec<Argument>,
     pub(super) return_type: Option<Type>,
     pub(super) ffi_func: FFIFunction,
+    pub(super) checksum_func: FFIFunction,
+    pub(super) checksum: u16,
     pub(super) attributes: FunctionAttributes,
+    pub(super) docstring: Option<String>,
 }
 
 impl Function {
@@ -78,6 +81,16 @@ impl Function {
         &self.ffi_func
     }
 
+    /// The FFI function that returns the checksum of this function's API, as seen by the library.
+    pub fn checksum_func(&self) -> &FFIFunction {
+        &self.checksum_func
+    }
+
+    /// The checksum of this function's API, as seen by the bindings.
+    pub fn checksum(&self) -> u16 {
+        self.checksum
+    }
+
     pub fn throws(&self) -> Option<&str> {
         self.attributes.get_throws_err()
     }
@@ -88,12 +101,40 @@ impl Function {
             .map(|name| Type::Error(name.to_owned()))
     }
 
+    pub fn deprecated(&self) -> Option<&str> {
+        self.attributes.get_deprecated()
+    }
+
+    pub fn is_async(&self) -> bool {
+        self.attributes.is_async()
+    }
+
+    pub fn docstring(&self) -> Option<&str> {
+        self.docstring.as_deref()
+    }
+
     pub fn derive_ffi_func(&mut self, ci_prefix: &str) -> Result<()> {
         self.ffi_func.name.push_str(ci_prefix);
         self.ffi_func.name.push('_');
         self.ffi_func.name.push_str(&self.name);
+        self.checksum_func.name = format!("uniffi_{}_checksum_func_{}", ci_prefix, self.name);
+        self.checksum = uniffi_meta::item_checksum(self);
+        self.derive_ffi_signature()
+    }
+
+    /// Derive the arguments and return type of the FFI function, whose name has already been set.
+    ///
+    /// Functions from proc-macro metadata come with the name of their FFI function, so only need this part.
+    pub(super) fn derive_ffi_signature(&mut self) -> Result<()> {
+        self.checksum_func.return_type = Some(FFIType::UInt16);
         self.ffi_func.arguments = self.arguments.iter().map(|arg| arg.into()).collect();
-        self.ffi_func.return_type = self.return_type.as_ref().map(|rt| rt.into());
+        // Async functions return a handle to a `RustFuture`, which the foreign language
+        // code drives to completion using the `ffi_*_rust_future_*` builtin functions.
+        self.ffi_func.return_type = if self.is_async() {
+            Some(FFIType::RustArcPtr)
+        } else {
+            self.return_type.as_ref().map(|rt| rt.into())
+        };
         Ok(())
     }
 }
@@ -112,12 +153,9 @@ impl IterTypes for Function {
 
 impl Hash for Function {
     fn hash<H: Hasher>(&self, state: &mut H) {
-        // We don't include the FFIFunc in the hash calculation, because:
-        //  - it is entirely determined by the other fields,
-        //    so excluding it is safe.
-        //  - its `name` property includes a checksum derived from  the very
-        //    hash value we're trying to calculate here, so excluding it
-        //    avoids a weird circular depenendency in the calculation.
+        // We don't include the FFIFuncs or checksum in the hash calculation, because
+        // they are entirely determined by the other fields, so excluding them is safe.
+        // The checksum is itself derived from this hash, so we must exclude that anyway.
         self.name.hash(state);
         self.arguments.hash(state);
         self.return_type.hash(state);
@@ -145,7 +183,36 @@ impl APIConverter<Function> for weedle::namespace::OperationNamespaceMember<'_>
             return_type,
             arguments: self.args.body.list.convert(ci)?,
             ffi_func: Default::default(),
+            checksum_func: Default::default(),
+            checksum: 0,
             attributes: FunctionAttributes::try_from(self.attributes.as_ref())?,
+            docstring: None,
+        })
+    }
+}
+
+impl APIConverter<Function> for uniffi_meta::FnMetadata {
+    fn convert(&self, ci: &mut ComponentInterface) -> Result<Function> {
+        let return_type = self
+            .return_type
+            .as_ref()
+            .map(|t| ci.resolve_type_expression(t))
+            .transpose()?;
+        Ok(Function {
+            name: self.name.clone(),
+            return_type,
+            arguments: self.inputs.convert(ci)?,
+            ffi_func: FFIFunction {
+                name: self.ffi_symbol_name(),
+                ..Default::default()
+            },
+            checksum_func: FFIFunction {
+                name: self.checksum_symbol_name(),
+                ..Default::default()
+            },
+            checksum: uniffi_meta::item_checksum(self),
+            attributes: Default::default(),
+            docstring: None,
         })
     }
 }
@@ -219,6 +286,18 @@ impl APIConverter<Argument> for weedle::argument::SingleArgument<'_> {
     }
 }
 
+impl APIConverter<Argument> for uniffi_meta::FnParamMetadata {
+    fn convert(&self, ci: &mut ComponentInterface) -> Result<Argument> {
+        Ok(Argument {
+            name: self.name.clone(),
+            type_: ci.resolve_type_expression(&self.ty)?,
+            by_ref: false,
+            optional: false,
+            default: None,
+        })
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -263,4 +342,57 @@ mod test {
         );
         Ok(())
     }
+
+    #[test]
+    fn test_async_function() -> Result<()> {
+        let ci = ComponentInterface::from_webidl(
+            r##"
+            namespace test {
+                string sync_func();
+                [Async]
+                string async_func(u32 arg);
+            };
+        "##,
+        )?;
+
+        let func1 = ci.get_function_definition("sync_func").unwrap();
+        assert!(!func1.is_async());
+        assert!(matches!(
+            func1.ffi_func().return_type(),
+            Some(FFIType::RustBuffer)
+        ));
+
+        let func2 = ci.get_function_definition("async_func").unwrap();
+        assert!(func2.is_async());
+        assert!(matches!(func2.return_type(), Some(Type::String)));
+        assert_eq!(func2.ffi_func().arguments().len(), 1);
+        assert!(matches!(
+            func2.ffi_func().return_type(),
+            Some(FFIType::RustArcPtr)
+        ));
+        assert!(ci.has_async_functions());
+        Ok(())
+    }
+
+    #[test]
+    fn test_deprecated_function() -> Result<()> {
+        let ci = ComponentInterface::from_webidl(
+            r##"
+            namespace test {
+                /// Still documented.
+                [Deprecated="Use new_func instead"]
+                void old_func();
+                void new_func();
+            };
+        "##,
+        )?;
+        let func = ci.get_function_definition("old_func").unwrap();
+        assert_eq!(func.deprecated(), Some("Use new_func instead"));
+        assert_eq!(func.docstring(), Some("Still documented."));
+        assert_eq!(
+            ci.get_function_definition("new_func").unwrap().deprecated(),
+            None
+        );
+        Ok(())
+    }
 }
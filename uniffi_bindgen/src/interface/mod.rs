@@ -45,13 +45,13 @@
 //!   * Error messages and general developer experience leave a lot to be desired.
 
 use std::{
-    collections::{hash_map::DefaultHasher, HashSet},
+    collections::HashSet,
     convert::TryFrom,
     hash::{Hash, Hasher},
     str::FromStr,
 };
 
-use anyhow::{bail, Result};
+use anyhow::{anyhow, bail, Result};
 
 pub mod types;
 pub use types::Type;
@@ -60,10 +60,15 @@ use types::{IterTypes, TypeIterator, TypeUniverse};
 mod attributes;
 mod callbacks;
 pub use callbacks::CallbackInterface;
+mod constant;
+pub use constant::Constant;
+mod docstring;
 mod enum_;
 pub use enum_::Enum;
 mod error;
 pub use error::Error;
+mod flags;
+pub use flags::{Flag, Flags};
 mod function;
 pub use function::{Argument, Function};
 mod literal;
@@ -71,13 +76,19 @@ pub use literal::{Literal, Radix};
 mod namespace;
 pub use namespace::Namespace;
 mod object;
-pub use object::{Constructor, Method, Object};
+pub use object::{Constructor, Method, Object, ObjectImpl, ObjectTrait, Property, TraitImpl};
+mod preprocess;
 mod record;
 pub use record::{Field, Record};
 
 pub mod ffi;
 pub use ffi::{FFIArgument, FFIFunction, FFIType};
 
+/// The version of the FFI contract between the scaffolding and the bindings.
+///
+/// See [`ComponentInterface::uniffi_contract_version`].
+const UNIFFI_CONTRACT_VERSION: u32 = 1;
+
 /// The main public interface for this module, representing the complete details of an interface exposed
 /// by a rust component and the details of consuming it via an extern-C FFI layer.
 ///
@@ -91,13 +102,19 @@ pub struct ComponentInterface {
     types: TypeUniverse,
     /// The unique prefix that we'll use for namespacing when exposing this component's API.
     namespace: String,
+    /// The doc comment on the namespace, which documents the component as a whole.
+    namespace_docstring: Option<String>,
+    /// The prefix for the names of FFI functions, fixed once the UDL has been processed.
+    ffi_namespace: String,
     /// The high-level API provided by the component.
     enums: Vec<Enum>,
+    flags: Vec<Flags>,
     records: Vec<Record>,
     functions: Vec<Function>,
     objects: Vec<Object>,
     callback_interfaces: Vec<CallbackInterface>,
     errors: Vec<Error>,
+    constants: Vec<Constant>,
 }
 
 impl<'ci> ComponentInterface {
@@ -113,12 +130,25 @@ impl<'ci> ComponentInterface {
         // Note we use `weedle::Definitions::parse` instead of `weedle::parse` so
         // on parse errors we can see how far weedle got, which helps locate the problem.
         use weedle::Parse; // this trait must be in scope for parse to work.
+
+        // Weedle ignores comments, so we find the doc comments ourselves.
+        let docstrings = docstring::Docstrings::extract(idl);
+        // WebIDL doesn't have constants in namespaces, generic types like tuples and sets, or
+        // values for enum variants, so we rewrite those into something weedle can parse.
+        let preprocess::Preprocessed {
+            idl,
+            constants,
+            generic_types,
+        } = preprocess::preprocess(idl);
         let (remaining, defns) = weedle::Definitions::parse(idl.trim()).unwrap();
         if !remaining.is_empty() {
             println!("Error parsing the IDL. Text remaining to be parsed is:");
             println!("{}", remaining);
             bail!("parse error");
         }
+        for (name, generic) in generic_types {
+            ci.types.add_generic_type(&name, generic);
+        }
         // Unconditionally add the String type, which is used by the panic handling
         let _ = ci.types.add_known_type(Type::String);
         // We process the WebIDL definitions in two passes.
@@ -126,12 +156,82 @@ impl<'ci> ComponentInterface {
         ci.types.add_type_definitions_from(defns.as_slice())?;
         // With those names resolved, we can build a complete representation of the API.
         APIBuilder::process(&defns, &mut ci)?;
+        for text in constants {
+            let defn = constant::ConstantDeclaration::parse(text)?.convert(&mut ci)?;
+            ci.add_constant_definition(defn)?;
+        }
+        docstrings.apply(&mut ci);
         ci.check_consistency()?;
         // Now that the high-level API is settled, we can derive the low-level FFI.
+        ci.ffi_namespace = ci.derive_ffi_namespace();
         ci.derive_ffi_funcs()?;
         Ok(ci)
     }
 
+    /// Add the items described by proc-macro metadata to a `ComponentInterface` parsed from UDL.
+    ///
+    /// These items are defined with attributes such as `#[uniffi::export]` in the Rust code,
+    /// rather than in the UDL. The metadata for each includes the names of its FFI functions,
+    /// so adding them does not affect the names of any existing FFI functions.
+    pub fn add_metadata(&mut self, metadata: Vec<uniffi_meta::Metadata>) -> Result<()> {
+        use uniffi_meta::Metadata;
+        // Like with the UDL, we first add all the named types so they can refer to each other.
+        for meta in metadata.iter() {
+            match meta {
+                Metadata::Record(r) => self
+                    .types
+                    .add_type_definition(&r.name, Type::Record(r.name.clone()))?,
+                Metadata::Object(o) => self
+                    .types
+                    .add_type_definition(&o.name, Type::Object(o.name.clone()))?,
+                _ => {}
+            }
+        }
+        for meta in metadata.iter() {
+            match meta {
+                Metadata::Record(r) => {
+                    let defn = r.convert(self)?;
+                    self.add_record_definition(defn);
+                }
+                Metadata::Object(o) => {
+                    let defn = o.convert(self)?;
+                    self.add_object_definition(defn);
+                }
+                _ => {}
+            }
+        }
+        // With the types defined, we can add the functions and methods that use them.
+        for meta in metadata.iter() {
+            match meta {
+                Metadata::Func(f) => {
+                    let mut defn: Function = f.convert(self)?;
+                    defn.derive_ffi_signature()?;
+                    self.add_function_definition(defn)?;
+                }
+                Metadata::Constructor(c) => {
+                    let mut defn: Constructor = c.convert(self)?;
+                    defn.derive_ffi_signature();
+                    let object = self.get_object_definition_mut(&c.self_name)?;
+                    if object.has_member(&defn.name) {
+                        bail!("Duplicate interface member name: \"{}\"", defn.name())
+                    }
+                    object.constructors.push(defn);
+                }
+                Metadata::Method(m) => {
+                    let mut defn: Method = m.convert(self)?;
+                    defn.derive_ffi_signature()?;
+                    let object = self.get_object_definition_mut(&m.self_name)?;
+                    if object.has_member(&defn.name) {
+                        bail!("Duplicate interface member name: \"{}\"", defn.name())
+                    }
+                    object.methods.push(defn);
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
     /// The string namespace within which this API should be presented to the caller.
     ///
     /// This string would typically be used to prefix function names in the FFI, to build
@@ -140,6 +240,11 @@ impl<'ci> ComponentInterface {
         self.namespace.as_str()
     }
 
+    /// The doc comment on the namespace, if any.
+    pub fn namespace_docstring(&self) -> Option<&str> {
+        self.namespace_docstring.as_deref()
+    }
+
     /// List the definitions for every Enum type in the interface.
     pub fn iter_enum_definitions(&self) -> Vec<Enum> {
         self.enums.to_vec()
@@ -151,6 +256,16 @@ impl<'ci> ComponentInterface {
         self.enums.iter().find(|e| e.name == name)
     }
 
+    /// List the definitions for every set of Flags in the interface.
+    pub fn iter_flags_definitions(&self) -> Vec<Flags> {
+        self.flags.to_vec()
+    }
+
+    /// Get a Flags definition by name, or None if no such Flags are defined.
+    pub fn get_flags_definition(&self, name: &str) -> Option<&Flags> {
+        self.flags.iter().find(|f| f.name == name)
+    }
+
     /// List the definitions for every Record type in the interface.
     pub fn iter_record_definitions(&self) -> Vec<Record> {
         self.records.to_vec()
@@ -195,6 +310,16 @@ impl<'ci> ComponentInterface {
         self.callback_interfaces.iter().find(|o| o.name == name)
     }
 
+    /// Check whether the given type is thrown by any callback interface method.
+    ///
+    /// Such errors need to be read back into Rust after being thrown by the foreign language code.
+    pub fn is_callback_interface_throws_type(&self, type_: &Type) -> bool {
+        self.callback_interfaces
+            .iter()
+            .flat_map(|cbi| cbi.methods())
+            .any(|m| m.throws_type().as_ref() == Some(type_))
+    }
+
     /// List the definitions for every Error type in the interface.
     pub fn iter_error_definitions(&self) -> Vec<Error> {
         self.errors.to_vec()
@@ -206,6 +331,17 @@ impl<'ci> ComponentInterface {
         self.errors.iter().find(|e| e.name == name)
     }
 
+    /// List the definitions for every Constant in the interface.
+    pub fn iter_constant_definitions(&self) -> Vec<Constant> {
+        self.constants.to_vec()
+    }
+
+    /// Get a Constant definition by name, or None if no such Constant is defined.
+    pub fn get_constant_definition(&self, name: &str) -> Option<&Constant> {
+        // TODO: probably we could store these internally in a HashMap to make this easier?
+        self.constants.iter().find(|c| c.name == name)
+    }
+
     /// Get details about all `Type::External` types
     pub fn iter_external_types(&self) -> Vec<(String, String)> {
         self.types
@@ -259,12 +395,87 @@ impl<'ci> ComponentInterface {
             .any(|t| matches!(t, Type::Object(_)))
     }
 
+    /// Check whether the given item contains any (possibly nested) tuple types
+    pub fn item_contains_tuple_types<T: IterTypes>(&self, item: &T) -> bool {
+        self.iter_types_in_item(item)
+            .any(|t| matches!(t, Type::Tuple(_)))
+    }
+
     /// Check whether the given item contains any (possibly nested) unsigned types
     pub fn item_contains_unsigned_types<T: IterTypes>(&self, item: &T) -> bool {
         self.iter_types_in_item(item)
             .any(|t| matches!(t, Type::UInt8 | Type::UInt16 | Type::UInt32 | Type::UInt64))
     }
 
+    /// Check whether the named record, enum or error can contain itself, either directly or
+    /// through the fields of other records and enums.
+    ///
+    /// Containment through a sequence, map or object doesn't count, since those already have
+    /// their contents on the heap. Recursive types need some indirection in Rust (a `Box`) and
+    /// in Swift (an `indirect` enum, or an indirect property for records).
+    pub fn is_recursive_type(&self, name: &str) -> bool {
+        self.can_contain_by_value(name, name)
+    }
+
+    /// Check whether any of the records in the interface are recursive.
+    pub fn contains_recursive_records(&self) -> bool {
+        self.records
+            .iter()
+            .any(|r| self.is_recursive_type(r.name()))
+    }
+
+    /// Check whether the given type, used in a field of the named record, enum or error, is a
+    /// reference to a type that can contain that record, enum or error. These are the references
+    /// that the scaffolding expects to be boxed.
+    pub fn is_recursive_reference(&self, parent: &str, type_: &Type) -> bool {
+        match type_ {
+            Type::Record(nm) | Type::Enum(nm) | Type::Error(nm) => {
+                self.can_contain_by_value(nm, parent)
+            }
+            _ => false,
+        }
+    }
+
+    /// Check whether a field of the named record, enum or error contains any recursive
+    /// references, possibly nested in an optional or tuple.
+    pub fn is_recursive_field(&self, parent: &str, field: &Field) -> bool {
+        by_value_types(&field.type_)
+            .into_iter()
+            .any(|t| self.is_recursive_reference(parent, t))
+    }
+
+    /// Check whether a value of the named record, enum or error type can contain a value
+    /// of the `target` type without any indirection.
+    fn can_contain_by_value(&self, name: &str, target: &str) -> bool {
+        let mut seen = HashSet::new();
+        let mut pending = vec![name];
+        while let Some(name) = pending.pop() {
+            let fields = match (
+                self.get_record_definition(name),
+                self.get_enum_definition(name),
+                self.get_error_definition(name),
+            ) {
+                (Some(rec), _, _) => rec.fields(),
+                (_, Some(e), _) => e.variants().into_iter().flat_map(|v| v.fields()).collect(),
+                (_, _, Some(e)) => e.variants().into_iter().flat_map(|v| v.fields()).collect(),
+                _ => continue,
+            };
+            for field in fields {
+                for type_ in by_value_types(&field.type_) {
+                    if let Type::Record(nm) | Type::Enum(nm) | Type::Error(nm) = type_ {
+                        if nm == target {
+                            return true;
+                        }
+                        if seen.insert(nm.as_str()) {
+                            pending.push(nm);
+                        }
+                    }
+                }
+            }
+        }
+        false
+    }
+
     /// Check whether the interface contains any optional types
     pub fn contains_optional_types(&self) -> bool {
         self.types
@@ -283,7 +494,28 @@ impl<'ci> ComponentInterface {
     pub fn contains_map_types(&self) -> bool {
         self.types
             .iter_known_types()
-            .any(|t| matches!(t, Type::Map(_)))
+            .any(|t| matches!(t, Type::Map(..)))
+    }
+
+    /// Check whether the interface contains any set types
+    pub fn contains_set_types(&self) -> bool {
+        self.types
+            .iter_known_types()
+            .any(|t| matches!(t, Type::Set(_)))
+    }
+
+    /// Check whether the interface contains any ordered map types
+    pub fn contains_ordered_map_types(&self) -> bool {
+        self.types
+            .iter_known_types()
+            .any(|t| matches!(t, Type::OrderedMap(..)))
+    }
+
+    /// Check whether the interface contains any tuple types
+    pub fn contains_tuple_types(&self) -> bool {
+        self.types
+            .iter_known_types()
+            .any(|t| matches!(t, Type::Tuple(_)))
     }
 
     /// Calculate a numeric checksum for this ComponentInterface.
@@ -292,28 +524,23 @@ impl<'ci> ComponentInterface {
     /// generated from one version of an interface with the compiled Rust code from a different
     /// version of that interface. It offers the following properties:
     ///
-    ///   - Two ComponentIntefaces generated from the same WebIDL file, using the same version of uniffi
-    ///     and the same version of Rust, will always have the same checksum value.
+    ///   - Two ComponentIntefaces generated from the same WebIDL file, using the same version of uniffi,
+    ///     will always have the same checksum value, regardless of the platform or the version of Rust.
     ///   - Two ComponentInterfaces will, with high probability, have different checksum values if:
     ///         - They were generated from two different WebIDL files.
     ///         - They were generated by two different versions of uniffi
     ///
-    /// The checksum may or may not change depending on the version of Rust used; since we expect
-    /// consumers to be using the same executable to generate both the scaffolding and the bindings,
-    /// assuming the same version of Rust seems acceptable.
+    /// The checksum is computed with [`uniffi_meta::checksum`], which documents the algorithm.
+    ///
+    /// Doc comments aren't part of the API, so the `Hash` impls of the items in the interface
+    /// leave out their docstrings, and changing a doc comment doesn't change the checksum.
     ///
     /// Note that this is designed to prevent accidents, not attacks, so there is no need for the
     /// checksum to be cryptographically secure.
-    ///
-    /// TODO: it's not clear to me if the derivation of `Hash` is actually deterministic enough to
-    /// ensure the guarantees above, or if it might be sensitive to e.g. compiler-driven re-ordering
-    /// of struct field. Let's see how it goes...
     pub fn checksum(&self) -> u64 {
-        let mut hasher = DefaultHasher::new();
         // Our implementation of `Hash` mixes in all of the public API of the component,
         // as well as the version string of uniffi.
-        self.hash(&mut hasher);
-        hasher.finish()
+        uniffi_meta::checksum(self)
     }
 
     /// The namespace to use in FFI-level function definitions.
@@ -321,19 +548,62 @@ impl<'ci> ComponentInterface {
     /// The value returned by this method is used as a prefix to namespace all FFI-level functions
     /// used in this ComponentInterface.
     ///
-    /// Since these names are an internal implementation detail that is not typically visible to
-    /// consumers, we take the opportunity to add an additional safety guard by including a 4-hex-char
-    /// checksum in each name. If foreign-language bindings attempt to load and use a version of the
-    /// Rust code compiled from a different UDL definition than the one used for the bindings themselves,
-    /// then there is a high probability of checksum mismatch and they will fail to link against the
-    /// compiled Rust code. The result will be an ugly inscrutable link-time error, but that is a lot
-    /// better than triggering potentially arbitrary memory unsafety!
+    /// It doesn't depend on the details of the interface, so that the bindings can always find the
+    /// functions that check whether they match the library, and report a mismatch clearly. See
+    /// [`ComponentInterface::iter_checksums`] and [`ComponentInterface::ffi_uniffi_contract_version`].
     pub fn ffi_namespace(&self) -> String {
-        format!(
-            "{}_{:x}",
-            self.namespace,
-            (self.checksum() & 0x000000000000FFFF) as u16
-        )
+        self.ffi_namespace.clone()
+    }
+
+    fn derive_ffi_namespace(&self) -> String {
+        self.namespace.clone()
+    }
+
+    /// The version of the FFI contract that these bindings and scaffolding were generated for.
+    ///
+    /// This should be incremented whenever the way the bindings and scaffolding talk to each other
+    /// changes incompatibly, e.g. the layout of `RustBuffer` or the naming of FFI functions.
+    pub fn uniffi_contract_version(&self) -> u32 {
+        UNIFFI_CONTRACT_VERSION
+    }
+
+    /// Builtin FFI function returning the version of the FFI contract that the library was built with.
+    ///
+    /// The bindings call this when they load the library, to check that it matches
+    /// [`ComponentInterface::uniffi_contract_version`].
+    pub fn ffi_uniffi_contract_version(&self) -> FFIFunction {
+        FFIFunction {
+            name: format!("ffi_{}_uniffi_contract_version", self.ffi_namespace()),
+            arguments: vec![],
+            return_type: Some(FFIType::UInt32),
+        }
+    }
+
+    /// List the checksums of every function, constructor and method in the interface.
+    ///
+    /// The library exports a function returning the checksum of each one, as seen when generating
+    /// its scaffolding. The bindings compare these with their own checksums when they load the
+    /// library, so they can report exactly which item doesn't match.
+    pub fn iter_checksums(&self) -> Vec<ApiChecksum> {
+        let functions = self.functions.iter().map(|f| ApiChecksum {
+            item_name: format!("function {}", f.name()),
+            ffi_func: f.checksum_func().clone(),
+            checksum: f.checksum(),
+        });
+        let objects = self.objects.iter().flat_map(|obj| {
+            let constructors = obj.constructors.iter().map(move |cons| ApiChecksum {
+                item_name: format!("constructor {}.{}", obj.name(), cons.name()),
+                ffi_func: cons.checksum_func().clone(),
+                checksum: cons.checksum(),
+            });
+            let methods = obj.ffi_methods().into_iter().map(move |meth| ApiChecksum {
+                item_name: format!("method {}.{}", obj.name(), meth.name()),
+                ffi_func: meth.checksum_func().clone(),
+                checksum: meth.checksum(),
+            });
+            constructors.chain(methods)
+        });
+        functions.chain(objects).collect()
     }
 
     /// Builtin FFI function for allocating a new `RustBuffer`.
@@ -398,6 +668,71 @@ impl<'ci> ComponentInterface {
         }
     }
 
+    /// Check whether the interface contains any async functions or methods.
+    ///
+    /// The builtin `RustFuture` FFI helpers are only needed when this is the case.
+    pub fn has_async_functions(&self) -> bool {
+        self.functions.iter().any(|f| f.is_async())
+            || self
+                .objects
+                .iter()
+                .any(|o| o.methods.iter().any(|m| m.is_async()))
+    }
+
+    /// Builtin FFI function for polling a `RustFuture` returned from an async function.
+    /// The foreign language bindings pass a waker callback and some opaque data which
+    /// identifies the waiting call; it returns non-zero if the future is ready to be
+    /// completed, and otherwise arranges for the waker to be invoked when the future
+    /// should be polled again.
+    pub fn ffi_rust_future_poll(&self) -> FFIFunction {
+        FFIFunction {
+            name: format!("ffi_{}_rust_future_poll", self.ffi_namespace()),
+            arguments: vec![
+                FFIArgument {
+                    name: "future".to_string(),
+                    type_: FFIType::RustArcPtr,
+                },
+                FFIArgument {
+                    name: "waker".to_string(),
+                    type_: FFIType::ForeignWaker,
+                },
+                FFIArgument {
+                    name: "waker_data".to_string(),
+                    type_: FFIType::UInt64,
+                },
+            ],
+            return_type: Some(FFIType::Int8),
+        }
+    }
+
+    /// Builtin FFI function for taking the result of a `RustFuture` that is ready.
+    /// The return value is serialized into a `RustBuffer`, and errors are reported
+    /// through the `RustCallStatus` in the same way as for synchronous calls.
+    pub fn ffi_rust_future_complete(&self) -> FFIFunction {
+        FFIFunction {
+            name: format!("ffi_{}_rust_future_complete", self.ffi_namespace()),
+            arguments: vec![FFIArgument {
+                name: "future".to_string(),
+                type_: FFIType::RustArcPtr,
+            }],
+            return_type: Some(FFIType::RustBuffer),
+        }
+    }
+
+    /// Builtin FFI function for freeing a `RustFuture`.
+    /// The foreign language bindings must call this exactly once for each future,
+    /// whether or not it was polled to completion.
+    pub fn ffi_rust_future_free(&self) -> FFIFunction {
+        FFIFunction {
+            name: format!("ffi_{}_rust_future_free", self.ffi_namespace()),
+            arguments: vec![FFIArgument {
+                name: "future".to_string(),
+                type_: FFIType::RustArcPtr,
+            }],
+            return_type: None,
+        }
+    }
+
     /// List the definitions of all FFI functions in the interface.
     ///
     /// The set of FFI functions is derived automatically from the set of higher-level types
@@ -409,7 +744,8 @@ impl<'ci> ComponentInterface {
                 vec![obj.ffi_object_free().clone()]
                     .into_iter()
                     .chain(obj.constructors.iter().map(|f| f.ffi_func.clone()))
-                    .chain(obj.methods.iter().map(|f| f.ffi_func.clone()))
+                    .chain(obj.ffi_methods().into_iter().map(|f| f.ffi_func.clone()))
+                    .chain(obj.traits.iter().map(|t| t.ffi_func.clone()))
             })
             .flatten()
             .chain(
@@ -428,6 +764,17 @@ impl<'ci> ComponentInterface {
                 .iter()
                 .cloned(),
             )
+            .chain(if self.has_async_functions() {
+                vec![
+                    self.ffi_rust_future_poll(),
+                    self.ffi_rust_future_complete(),
+                    self.ffi_rust_future_free(),
+                ]
+            } else {
+                vec![]
+            })
+            .chain(self.iter_checksums().into_iter().map(|c| c.ffi_func))
+            .chain(std::iter::once(self.ffi_uniffi_contract_version()))
             .collect()
     }
 
@@ -483,6 +830,12 @@ impl<'ci> ComponentInterface {
         self.enums.push(defn);
     }
 
+    /// Called by `APIBuilder` impls to add a newly-parsed flags definition to the `ComponentInterface`.
+    fn add_flags_definition(&mut self, defn: Flags) {
+        // Note that there will be no duplicates thanks to the previous type-finding pass.
+        self.flags.push(defn);
+    }
+
     /// Called by `APIBuilder` impls to add a newly-parsed record definition to the `ComponentInterface`.
     fn add_record_definition(&mut self, defn: Record) {
         // Note that there will be no duplicates thanks to the previous type-finding pass.
@@ -503,12 +856,29 @@ impl<'ci> ComponentInterface {
         Ok(())
     }
 
+    /// Add a newly-parsed constant definition to the `ComponentInterface`.
+    fn add_constant_definition(&mut self, defn: Constant) -> Result<()> {
+        if self.constants.iter().any(|c| c.name == defn.name) {
+            bail!("duplicate constant definition: \"{}\"", defn.name);
+        }
+        self.constants.push(defn);
+        Ok(())
+    }
+
     /// Called by `APIBuilder` impls to add a newly-parsed object definition to the `ComponentInterface`.
     fn add_object_definition(&mut self, defn: Object) {
         // Note that there will be no duplicates thanks to the previous type-finding pass.
         self.objects.push(defn);
     }
 
+    /// Get a mutable reference to an object definition, for adding members from proc-macro metadata.
+    fn get_object_definition_mut(&mut self, name: &str) -> Result<&mut Object> {
+        self.objects
+            .iter_mut()
+            .find(|o| o.name == name)
+            .ok_or_else(|| anyhow!("unknown object type: {}", name))
+    }
+
     /// Called by `APIBuilder` impls to add a newly-parsed callback interface definition to the `ComponentInterface`.
     fn add_callback_interface_definition(&mut self, defn: CallbackInterface) {
         // Note that there will be no duplicates thanks to the previous type-finding pass.
@@ -541,6 +911,27 @@ impl<'ci> ComponentInterface {
                 }
             }
         }
+        // Errors thrown by callback interfaces are sent back into Rust, which needs all of the
+        // fields to rebuild them. The foreign code only knows the message of a flat error.
+        for cbi in self.callback_interfaces.iter() {
+            for method in cbi.methods() {
+                let e = match method
+                    .throws()
+                    .and_then(|name| self.get_error_definition(name))
+                {
+                    Some(e) => e,
+                    None => continue,
+                };
+                if e.is_flat() {
+                    bail!(
+                        "Callback interface method {}.{} can't throw the flat error {}; declare it as an `[Error] interface` instead",
+                        cbi.name(),
+                        method.name(),
+                        e.name()
+                    )
+                }
+            }
+        }
         Ok(())
     }
 
@@ -571,6 +962,33 @@ impl FromStr for ComponentInterface {
     }
 }
 
+/// The checksum of a function, constructor or method in the interface.
+///
+/// See [`ComponentInterface::iter_checksums`].
+#[derive(Debug, Clone)]
+pub struct ApiChecksum {
+    item_name: String,
+    ffi_func: FFIFunction,
+    checksum: u16,
+}
+
+impl ApiChecksum {
+    /// A human-readable description of the item, for use in error messages.
+    pub fn item_name(&self) -> &str {
+        &self.item_name
+    }
+
+    /// The FFI function that returns the checksum of the item, as seen by the library.
+    pub fn ffi_func(&self) -> &FFIFunction {
+        &self.ffi_func
+    }
+
+    /// The checksum of the item, as seen by the bindings.
+    pub fn checksum(&self) -> u16 {
+        self.checksum
+    }
+}
+
 /// `ComponentInterface` structs can be hashed, but this is mostly a convenient way to
 /// produce a checksum of their contents. They're not really intended to live in a hashtable.
 impl Hash for ComponentInterface {
@@ -580,11 +998,13 @@ impl Hash for ComponentInterface {
         self.uniffi_version.hash(state);
         self.namespace.hash(state);
         self.enums.hash(state);
+        self.flags.hash(state);
         self.records.hash(state);
         self.functions.hash(state);
         self.objects.hash(state);
         self.callback_interfaces.hash(state);
         self.errors.hash(state);
+        self.constants.hash(state);
     }
 }
 
@@ -594,6 +1014,19 @@ impl IterTypes for ComponentInterface {
     }
 }
 
+/// Get the types that a value of the given type holds directly, including the type itself.
+///
+/// Optionals and tuples hold their contents inline, but sequences and maps don't.
+fn by_value_types(type_: &Type) -> Vec<&Type> {
+    let mut types = vec![type_];
+    match type_ {
+        Type::Optional(t) => types.extend(by_value_types(t)),
+        Type::Tuple(ts) => types.extend(ts.iter().flat_map(by_value_types)),
+        _ => (),
+    }
+    types
+}
+
 /// Stateful iterator for yielding all types contained in a given item.
 ///
 /// This struct is the implementation of [`ComponentInterface::iter_types_in_item`] and should be
@@ -736,6 +1169,10 @@ impl APIBuilder for weedle::Definition<'_> {
                 if attrs.contains_error_attr() {
                     let err = d.convert(ci)?;
                     ci.add_error_definition(err);
+                } else if attrs.get_flags_type().is_some() {
+                    // ...or a set of flags.
+                    let flags = d.convert(ci)?;
+                    ci.add_flags_definition(flags);
                 } else {
                     let e = d.convert(ci)?;
                     ci.add_enum_definition(e);
@@ -848,6 +1285,173 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_item_checksums() {
+        const UDL: &str = r#"
+            namespace test {
+                u32 add(u32 a, u32 b);
+            };
+            interface Counter {
+                constructor();
+                u32 get();
+            };
+        "#;
+        let ci = ComponentInterface::from_webidl(UDL).unwrap();
+        let checksums = ci.iter_checksums();
+        let names: Vec<_> = checksums.iter().map(|c| c.item_name()).collect();
+        assert_eq!(
+            names,
+            vec![
+                "function add",
+                "constructor Counter.new",
+                "method Counter.get"
+            ]
+        );
+        assert_eq!(
+            checksums[0].ffi_func().name(),
+            "uniffi_test_checksum_func_add"
+        );
+        assert!(matches!(
+            checksums[0].ffi_func().return_type(),
+            Some(FFIType::UInt16)
+        ));
+        assert!(ci
+            .iter_ffi_function_definitions()
+            .iter()
+            .any(|f| f.name() == "uniffi_test_checksum_method_Counter_get"));
+        // The FFI namespace doesn't depend on the checksum, so that the bindings can
+        // always find the functions they use to check it.
+        assert_eq!(ci.ffi_namespace(), "test");
+        assert_eq!(
+            ci.ffi_uniffi_contract_version().name(),
+            "ffi_test_uniffi_contract_version"
+        );
+
+        // The item checksums don't depend on the version of uniffi...
+        let mut ci2 = ComponentInterface::from_webidl(UDL).unwrap();
+        ci2.uniffi_version = String::from("fake-version");
+        assert_eq!(ci2.iter_checksums()[0].checksum(), checksums[0].checksum());
+        // ...but they do change with the signature of the item.
+        let ci3 = ComponentInterface::from_webidl(&UDL.replace("u32 b", "u64 b")).unwrap();
+        assert_ne!(ci3.iter_checksums()[0].checksum(), checksums[0].checksum());
+        assert_eq!(ci3.iter_checksums()[1].checksum(), checksums[1].checksum());
+    }
+
+    #[test]
+    fn test_add_metadata() {
+        use uniffi_meta::{
+            ConstructorMetadata, FieldMetadata, FnMetadata, FnParamMetadata, Metadata,
+            MethodMetadata, ObjectMetadata, RecordMetadata,
+        };
+        let mut ci = ComponentInterface::from_webidl(UDL2).unwrap();
+        let ffi_namespace = ci.ffi_namespace().to_string();
+        let checksum = ci.checksum();
+        let crate_name = String::from("uniffi_hello");
+        // Deliberately listed in an order where items refer to types defined after them.
+        ci.add_metadata(vec![
+            Metadata::Method(MethodMetadata {
+                crate_name: crate_name.clone(),
+                self_name: "Counter".into(),
+                name: "get".into(),
+                inputs: vec![],
+                return_type: Some(uniffi_meta::Type::Unresolved {
+                    name: "Point".into(),
+                }),
+                takes_self_by_arc: true,
+            }),
+            Metadata::Constructor(ConstructorMetadata {
+                crate_name: crate_name.clone(),
+                self_name: "Counter".into(),
+                name: "new".into(),
+                inputs: vec![],
+            }),
+            Metadata::Func(FnMetadata {
+                crate_name: crate_name.clone(),
+                name: "count".into(),
+                inputs: vec![FnParamMetadata {
+                    name: "counter".into(),
+                    ty: uniffi_meta::Type::ArcObject {
+                        object_name: "Counter".into(),
+                    },
+                }],
+                return_type: None,
+            }),
+            Metadata::Record(RecordMetadata {
+                crate_name: crate_name.clone(),
+                name: "Point".into(),
+                fields: vec![FieldMetadata {
+                    name: "x".into(),
+                    ty: uniffi_meta::Type::Option {
+                        inner_type: Box::new(uniffi_meta::Type::U32),
+                    },
+                }],
+            }),
+            Metadata::Object(ObjectMetadata {
+                crate_name,
+                name: "Counter".into(),
+            }),
+        ])
+        .unwrap();
+
+        assert_eq!(ci.iter_function_definitions().len(), 2);
+        let func = ci.get_function_definition("count").unwrap();
+        assert_eq!(func.ffi_func().name(), "uniffi_uniffi_hello_fn_count");
+        assert_eq!(func.arguments()[0].type_(), Type::Object("Counter".into()));
+
+        let rec = ci.get_record_definition("Point").unwrap();
+        assert_eq!(
+            rec.fields()[0].type_(),
+            Type::Optional(Box::new(Type::UInt32))
+        );
+
+        let obj = ci.get_object_definition("Counter").unwrap();
+        assert_eq!(
+            obj.primary_constructor().unwrap().ffi_func().name(),
+            "uniffi_uniffi_hello_fn_constructor_Counter_new"
+        );
+        assert_eq!(
+            obj.ffi_object_free().name(),
+            "uniffi_uniffi_hello_fn_free_Counter"
+        );
+        let meth = &obj.methods()[0];
+        assert_eq!(
+            meth.ffi_func().name(),
+            "uniffi_uniffi_hello_fn_method_Counter_get"
+        );
+        assert_eq!(meth.return_type(), Some(&Type::Record("Point".into())));
+        assert!(meth.takes_self_by_arc());
+
+        // Items defined in the UDL keep their existing FFI names.
+        assert_eq!(ci.ffi_namespace(), ffi_namespace);
+        assert_ne!(ci.checksum(), checksum);
+    }
+
+    #[test]
+    fn test_add_metadata_errors() {
+        use uniffi_meta::{Metadata, MethodMetadata, RecordMetadata};
+        let mut ci = ComponentInterface::from_webidl(UDL2).unwrap();
+        let err = ci
+            .add_metadata(vec![Metadata::Method(MethodMetadata {
+                crate_name: "uniffi_hello".into(),
+                self_name: "Missing".into(),
+                name: "get".into(),
+                inputs: vec![],
+                return_type: None,
+                takes_self_by_arc: false,
+            })])
+            .unwrap_err();
+        assert_eq!(err.to_string(), "unknown object type: Missing");
+
+        let err = ci
+            .add_metadata(vec![Metadata::Record(RecordMetadata {
+                crate_name: "uniffi_hello".into(),
+                name: "Test".into(),
+                fields: vec![],
+            })])
+            .unwrap_err();
+        assert_eq!(err.to_string(), "Conflicting type definition for \"Test\"");
+    }
+
     #[test]
     fn test_duplicate_type_names_are_an_error() {
         const UDL: &str = r#"
@@ -951,6 +1555,28 @@ mod test {
         assert_eq!(ci.contains_sequence_types(), true);
     }
 
+    #[test]
+    fn test_callback_interface_throws_types() {
+        const UDL: &str = r#"
+            namespace test{};
+            [Error]
+            interface Thrown { Oops(); };
+            [Error]
+            enum NotThrown { "Oops" };
+            callback interface Callback {
+                [Throws=Thrown]
+                void fail();
+            };
+            interface Rusty {
+                [Throws=NotThrown]
+                void fail();
+            };
+        "#;
+        let ci = ComponentInterface::from_webidl(UDL).unwrap();
+        assert!(ci.is_callback_interface_throws_type(&Type::Error("Thrown".into())));
+        assert!(!ci.is_callback_interface_throws_type(&Type::Error("NotThrown".into())));
+    }
+
     #[test]
     fn test_contains_map_types() {
         let mut ci = ComponentInterface {
@@ -963,7 +1589,10 @@ mod test {
         // check that `contains_map_types` returns true when there is a Map type in the interface
         assert!(ci
             .types
-            .add_type_definition("Map{}", Type::Map(Box::new(Type::Boolean)))
+            .add_type_definition(
+                "Map{}",
+                Type::Map(Box::new(Type::String), Box::new(Type::Boolean))
+            )
             .is_ok());
         assert_eq!(ci.contains_map_types(), true);
     }
@@ -997,4 +1626,41 @@ mod test {
         let ci = ComponentInterface::from_webidl(UDL).unwrap();
         assert!(ci.item_contains_unsigned_types(&Type::Object("TestObj".into())));
     }
+
+    #[test]
+    fn test_recursive_types() {
+        const UDL: &str = r#"
+            namespace test{};
+            dictionary Tree {
+                string label;
+                sequence<Tree> children;
+            };
+            dictionary Node {
+                u32 value;
+                Link? next;
+            };
+            dictionary Link {
+                Node node;
+                Tree tree;
+            };
+            [Enum]
+            interface Expr {
+                Num(i64 n);
+                Pair(tuple<Expr, Expr> both);
+            };
+        "#;
+        let ci = ComponentInterface::from_webidl(UDL).unwrap();
+        // Sequences already live on the heap, so they don't make a type recursive.
+        assert!(!ci.is_recursive_type("Tree"));
+        assert!(ci.is_recursive_type("Node"));
+        assert!(ci.is_recursive_type("Link"));
+        assert!(ci.is_recursive_type("Expr"));
+        assert!(ci.contains_recursive_records());
+
+        let link = ci.get_record_definition("Link").unwrap();
+        assert!(ci.is_recursive_field("Link", &link.fields()[0]));
+        assert!(!ci.is_recursive_field("Link", &link.fields()[1]));
+        assert!(ci.is_recursive_reference("Expr", &Type::Enum("Expr".into())));
+        assert!(!ci.is_recursive_reference("Node", &Type::Record("Tree".into())));
+    }
 }
@@ -76,18 +76,26 @@ pub struct Object {
     pub(super) name: String,
     pub(super) constructors: Vec<Constructor>,
     pub(super) methods: Vec<Method>,
+    pub(super) properties: Vec<Property>,
+    pub(super) traits: Vec<TraitImpl>,
     pub(super) ffi_func_free: FFIFunction,
     pub(super) uses_deprecated_threadsafe_attribute: bool,
+    pub(super) imp: ObjectImpl,
+    pub(super) docstring: Option<String>,
 }
 
 impl Object {
     fn new(name: String) -> Object {
         Object {
             name,
+            imp: ObjectImpl::Struct,
             constructors: Default::default(),
             methods: Default::default(),
+            properties: Default::default(),
+            traits: Default::default(),
             ffi_func_free: Default::default(),
             uses_deprecated_threadsafe_attribute: false,
+            docstring: None,
         }
     }
 
@@ -99,6 +107,16 @@ impl Object {
         Type::Object(self.name.clone())
     }
 
+    /// How the object is implemented in Rust.
+    pub fn imp(&self) -> &ObjectImpl {
+        &self.imp
+    }
+
+    /// The Rust type that implements the object, which is passed across the FFI in an `Arc`.
+    pub fn rust_name(&self) -> String {
+        self.imp.rust_name_for(&self.name)
+    }
+
     pub fn constructors(&self) -> Vec<&Constructor> {
         self.constructors.iter().collect()
     }
@@ -120,6 +138,51 @@ impl Object {
         self.methods.iter().collect()
     }
 
+    pub fn instance_methods(&self) -> Vec<&Method> {
+        self.methods
+            .iter()
+            .filter(|meth| !meth.is_static())
+            .collect()
+    }
+
+    pub fn static_methods(&self) -> Vec<&Method> {
+        self.methods
+            .iter()
+            .filter(|meth| meth.is_static())
+            .collect()
+    }
+
+    pub fn properties(&self) -> Vec<&Property> {
+        self.properties.iter().collect()
+    }
+
+    /// All the methods that have a function in the FFI, which includes the getters
+    /// and setters of properties as well as the object's declared methods.
+    pub fn ffi_methods(&self) -> Vec<&Method> {
+        self.methods
+            .iter()
+            .chain(self.properties.iter().flat_map(Property::accessors))
+            .collect()
+    }
+
+    /// The std traits that the object exposes to the bindings, from its `[Traits]` attribute.
+    pub fn trait_impls(&self) -> Vec<&TraitImpl> {
+        self.traits.iter().collect()
+    }
+
+    pub fn trait_impl(&self, trait_: &ObjectTrait) -> Option<&TraitImpl> {
+        self.traits.iter().find(|t| &t.trait_ == trait_)
+    }
+
+    pub fn docstring(&self) -> Option<&str> {
+        self.docstring.as_deref()
+    }
+
+    pub(super) fn has_member(&self, name: &str) -> bool {
+        self.constructors.iter().any(|cons| cons.name == name)
+            || self.ffi_methods().iter().any(|meth| meth.name == name)
+    }
+
     pub fn ffi_object_free(&self) -> &FFIFunction {
         &self.ffi_func_free
     }
@@ -141,6 +204,15 @@ impl Object {
         for meth in self.methods.iter_mut() {
             meth.derive_ffi_func(ci_prefix, &self.name)?
         }
+        for prop in self.properties.iter_mut() {
+            prop.getter.derive_ffi_func(ci_prefix, &self.name)?;
+            if let Some(setter) = prop.setter.as_mut() {
+                setter.derive_ffi_func(ci_prefix, &self.name)?;
+            }
+        }
+        for trait_impl in self.traits.iter_mut() {
+            trait_impl.derive_ffi_func(ci_prefix, &self.name);
+        }
         Ok(())
     }
 }
@@ -152,22 +224,25 @@ impl IterTypes for Object {
                 .iter()
                 .map(IterTypes::iter_types)
                 .chain(self.constructors.iter().map(IterTypes::iter_types))
-                .flatten(),
+                .chain(self.properties.iter().map(IterTypes::iter_types))
+                .flatten()
+                .chain(self.traits.iter().map(|t| &t.return_type)),
         )
     }
 }
 
 impl Hash for Object {
     fn hash<H: Hasher>(&self, state: &mut H) {
-        // We don't include the FFIFunc in the hash calculation, because:
-        //  - it is entirely determined by the other fields,
-        //    so excluding it is safe.
-        //  - its `name` property includes a checksum derived from  the very
-        //    hash value we're trying to calculate here, so excluding it
-        //    avoids a weird circular depenendency in the calculation.
+        // We don't include the FFIFunc in the hash calculation, because
+        // it is entirely determined by the other fields, so excluding it is safe.
         self.name.hash(state);
+        self.imp.hash(state);
         self.constructors.hash(state);
         self.methods.hash(state);
+        self.properties.hash(state);
+        for trait_impl in &self.traits {
+            trait_impl.trait_.hash(state);
+        }
     }
 }
 
@@ -182,11 +257,45 @@ impl APIConverter<Object> for weedle::InterfaceDefinition<'_> {
             None => Default::default(),
         };
         object.uses_deprecated_threadsafe_attribute = attributes.threadsafe();
-        // Convert each member into a constructor or method, guarding against duplicate names.
+        if attributes.is_trait() {
+            object.imp = ObjectImpl::Trait;
+        }
+        let traits = attributes.get_traits();
+        // A `dyn Trait` can't be compared or hashed, and Swift needs `Equatable` for `Hashable`.
+        if object.imp == ObjectImpl::Trait
+            && traits
+                .iter()
+                .any(|t| matches!(t, ObjectTrait::Eq | ObjectTrait::Hash))
+        {
+            bail!(
+                "Trait interface \"{}\" cannot implement Eq or Hash",
+                object.name
+            )
+        }
+        if traits.contains(&ObjectTrait::Hash) && !traits.contains(&ObjectTrait::Eq) {
+            bail!(
+                "Interface \"{}\" cannot implement Hash without Eq",
+                object.name
+            )
+        }
+        for trait_ in traits {
+            let trait_impl = TraitImpl::new(trait_);
+            // The bindings need to lift the result, like any other type they're given.
+            ci.types.add_known_type(trait_impl.return_type.clone())?;
+            object.traits.push(trait_impl);
+        }
+        // Convert each member into a constructor, method or property, guarding against
+        // duplicate names, including those of the methods that implement a property.
         let mut member_names = HashSet::new();
         for member in &self.members.body {
             match member {
                 weedle::interface::InterfaceMember::Constructor(t) => {
+                    if object.imp == ObjectImpl::Trait {
+                        bail!(
+                            "Trait interface \"{}\" cannot have constructors",
+                            object.name
+                        )
+                    }
                     let cons: Constructor = t.convert(ci)?;
                     if !member_names.insert(cons.name.clone()) {
                         bail!("Duplicate interface member name: \"{}\"", cons.name())
@@ -195,23 +304,168 @@ impl APIConverter<Object> for weedle::InterfaceDefinition<'_> {
                 }
                 weedle::interface::InterfaceMember::Operation(t) => {
                     let mut method: Method = t.convert(ci)?;
+                    if object.imp == ObjectImpl::Trait && method.is_async() {
+                        bail!(
+                            "Trait interface \"{}\" cannot have async methods",
+                            object.name
+                        )
+                    }
+                    // A static method would have to be called on a concrete type.
+                    if object.imp == ObjectImpl::Trait && method.is_static() {
+                        bail!(
+                            "Trait interface \"{}\" cannot have static methods",
+                            object.name
+                        )
+                    }
                     if !member_names.insert(method.name.clone()) {
                         bail!("Duplicate interface member name: \"{}\"", method.name())
                     }
                     method.object_name.push_str(object.name.as_str());
                     object.methods.push(method);
                 }
+                weedle::interface::InterfaceMember::Attribute(t) => {
+                    let mut prop: Property = t.convert(ci)?;
+                    for accessor in prop.accessors_mut() {
+                        if !member_names.insert(accessor.name.clone()) {
+                            bail!("Duplicate interface member name: \"{}\"", accessor.name())
+                        }
+                        accessor.object_name.push_str(object.name.as_str());
+                    }
+                    object.properties.push(prop);
+                }
                 _ => bail!("no support for interface member type {:?} yet", member),
             }
         }
-        // Everyone gets a primary constructor, even if not declared explicitly.
-        if object.primary_constructor().is_none() {
+        // Everyone gets a primary constructor, even if not declared explicitly,
+        // except for traits, which can only be obtained from a function or method.
+        if object.imp == ObjectImpl::Struct && object.primary_constructor().is_none() {
             object.constructors.push(Default::default());
         }
         Ok(object)
     }
 }
 
+impl APIConverter<Object> for uniffi_meta::ObjectMetadata {
+    fn convert(&self, _ci: &mut ComponentInterface) -> Result<Object> {
+        // Unlike those from the UDL, objects from proc-macro metadata don't get a default
+        // constructor; they only have the constructors exported from their `impl` blocks.
+        let mut object = Object::new(self.name.clone());
+        object.ffi_func_free = FFIFunction {
+            name: self.free_ffi_symbol_name(),
+            arguments: vec![FFIArgument {
+                name: "ptr".to_string(),
+                type_: FFIType::RustArcPtr,
+            }],
+            return_type: None,
+        };
+        Ok(object)
+    }
+}
+
+/// How an [`Object`] is implemented in Rust.
+///
+/// The foreign-language bindings see the same kind of object either way; this only
+/// affects the Rust scaffolding.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum ObjectImpl {
+    /// A struct, which is passed across the FFI in an `Arc<T>`.
+    Struct,
+    /// A trait, declared with the `[Trait]` attribute, which is passed across the FFI
+    /// in an `Arc<dyn T>`. Its methods are dispatched dynamically.
+    Trait,
+}
+
+impl ObjectImpl {
+    /// The Rust type that implements an object with the given name.
+    pub fn rust_name_for(&self, name: &str) -> String {
+        match self {
+            ObjectImpl::Struct => name.to_string(),
+            ObjectImpl::Trait => format!("dyn {}", name),
+        }
+    }
+}
+
+/// A std trait that an [`Object`] can expose through the `[Traits]` attribute.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum ObjectTrait {
+    /// `Debug`, for a developer-facing string.
+    Debug,
+    /// `Display`, for a user-facing string.
+    Display,
+    /// `PartialEq`, for comparing two objects of the same type.
+    Eq,
+    /// `Hash`, for using the object as a key.
+    Hash,
+}
+
+impl TryFrom<&str> for ObjectTrait {
+    type Error = anyhow::Error;
+    fn try_from(name: &str) -> Result<Self> {
+        Ok(match name {
+            "Debug" => ObjectTrait::Debug,
+            "Display" => ObjectTrait::Display,
+            "Eq" => ObjectTrait::Eq,
+            "Hash" => ObjectTrait::Hash,
+            _ => bail!("Unsupported trait: {:?}", name),
+        })
+    }
+}
+
+/// The FFI function that calls an object's implementation of an [`ObjectTrait`].
+///
+/// It takes a pointer to the object, and for `Eq` a pointer to the other object, and
+/// returns a string for `Debug` and `Display`, a boolean for `Eq` and a `u64` for `Hash`.
+#[derive(Debug, Clone)]
+pub struct TraitImpl {
+    pub(super) trait_: ObjectTrait,
+    pub(super) return_type: Type,
+    pub(super) ffi_func: FFIFunction,
+}
+
+impl TraitImpl {
+    fn new(trait_: ObjectTrait) -> Self {
+        let return_type = match trait_ {
+            ObjectTrait::Debug | ObjectTrait::Display => Type::String,
+            ObjectTrait::Eq => Type::Boolean,
+            ObjectTrait::Hash => Type::UInt64,
+        };
+        Self {
+            trait_,
+            return_type,
+            ffi_func: Default::default(),
+        }
+    }
+
+    pub fn trait_(&self) -> ObjectTrait {
+        self.trait_
+    }
+
+    pub fn return_type(&self) -> Type {
+        self.return_type.clone()
+    }
+
+    pub fn ffi_func(&self) -> &FFIFunction {
+        &self.ffi_func
+    }
+
+    fn derive_ffi_func(&mut self, ci_prefix: &str, obj_prefix: &str) {
+        let trait_name = format!("{:?}", self.trait_).to_lowercase();
+        self.ffi_func.name = format!("{}_{}_uniffi_trait_{}", ci_prefix, obj_prefix, trait_name);
+        let mut arguments = vec![FFIArgument {
+            name: "ptr".to_string(),
+            type_: FFIType::RustArcPtr,
+        }];
+        if self.trait_ == ObjectTrait::Eq {
+            arguments.push(FFIArgument {
+                name: "other".to_string(),
+                type_: FFIType::RustArcPtr,
+            });
+        }
+        self.ffi_func.arguments = arguments;
+        self.ffi_func.return_type = Some((&self.return_type).into());
+    }
+}
+
 // Represents a constructor for an object type.
 //
 // In the FFI, this will be a function that returns a pointer to an instance
@@ -221,7 +475,10 @@ pub struct Constructor {
     pub(super) name: String,
     pub(super) arguments: Vec<Argument>,
     pub(super) ffi_func: FFIFunction,
+    pub(super) checksum_func: FFIFunction,
+    pub(super) checksum: u16,
     pub(super) attributes: ConstructorAttributes,
+    pub(super) docstring: Option<String>,
 }
 
 impl Constructor {
@@ -251,12 +508,41 @@ impl Constructor {
             .map(|name| Type::Error(name.to_owned()))
     }
 
+    pub fn deprecated(&self) -> Option<&str> {
+        self.attributes.get_deprecated()
+    }
+
+    /// The FFI function that returns the checksum of this constructor's API, as seen by the library.
+    pub fn checksum_func(&self) -> &FFIFunction {
+        &self.checksum_func
+    }
+
+    /// The checksum of this constructor's API, as seen by the bindings.
+    pub fn checksum(&self) -> u16 {
+        self.checksum
+    }
+
+    pub fn docstring(&self) -> Option<&str> {
+        self.docstring.as_deref()
+    }
+
     fn derive_ffi_func(&mut self, ci_prefix: &str, obj_prefix: &str) {
         self.ffi_func.name.push_str(ci_prefix);
         self.ffi_func.name.push('_');
         self.ffi_func.name.push_str(obj_prefix);
         self.ffi_func.name.push('_');
         self.ffi_func.name.push_str(&self.name);
+        self.checksum_func.name = format!(
+            "uniffi_{}_checksum_constructor_{}_{}",
+            ci_prefix, obj_prefix, self.name
+        );
+        self.checksum = uniffi_meta::item_checksum(self);
+        self.derive_ffi_signature();
+    }
+
+    /// Derive the arguments and return type of the FFI function, whose name has already been set.
+    pub(super) fn derive_ffi_signature(&mut self) {
+        self.checksum_func.return_type = Some(FFIType::UInt16);
         self.ffi_func.arguments = self.arguments.iter().map(Into::into).collect();
         self.ffi_func.return_type = Some(FFIType::RustArcPtr);
     }
@@ -274,12 +560,9 @@ impl IterTypes for Constructor {
 
 impl Hash for Constructor {
     fn hash<H: Hasher>(&self, state: &mut H) {
-        // We don't include the FFIFunc in the hash calculation, because:
-        //  - it is entirely determined by the other fields,
-        //    so excluding it is safe.
-        //  - its `name` property includes a checksum derived from  the very
-        //    hash value we're trying to calculate here, so excluding it
-        //    avoids a weird circular depenendency in the calculation.
+        // We don't include the FFIFuncs or checksum in the hash calculation, because
+        // they are entirely determined by the other fields, so excluding them is safe.
+        // The checksum is itself derived from this hash, so we must exclude that anyway.
         self.name.hash(state);
         self.arguments.hash(state);
         self.attributes.hash(state);
@@ -292,7 +575,10 @@ impl Default for Constructor {
             name: String::from("new"),
             arguments: Vec::new(),
             ffi_func: Default::default(),
+            checksum_func: Default::default(),
+            checksum: 0,
             attributes: Default::default(),
+            docstring: None,
         }
     }
 }
@@ -307,15 +593,39 @@ impl APIConverter<Constructor> for weedle::interface::ConstructorInterfaceMember
             name: String::from(attributes.get_name().unwrap_or("new")),
             arguments: self.args.body.list.convert(ci)?,
             ffi_func: Default::default(),
+            checksum_func: Default::default(),
+            checksum: 0,
             attributes,
+            docstring: None,
         })
     }
 }
 
-// Represents an instance method for an object type.
+impl APIConverter<Constructor> for uniffi_meta::ConstructorMetadata {
+    fn convert(&self, ci: &mut ComponentInterface) -> Result<Constructor> {
+        Ok(Constructor {
+            name: self.name.clone(),
+            arguments: self.inputs.convert(ci)?,
+            ffi_func: FFIFunction {
+                name: self.ffi_symbol_name(),
+                ..Default::default()
+            },
+            checksum_func: FFIFunction {
+                name: self.checksum_symbol_name(),
+                ..Default::default()
+            },
+            checksum: uniffi_meta::item_checksum(self),
+            attributes: Default::default(),
+            docstring: None,
+        })
+    }
+}
+
+// Represents a method for an object type.
 //
 // The FFI will represent this as a function whose first/self argument is a
-// `FFIType::RustArcPtr` to the instance.
+// `FFIType::RustArcPtr` to the instance, unless it's a `[Static]` method, which
+// has no instance and takes only its declared arguments.
 #[derive(Debug, Clone)]
 pub struct Method {
     pub(super) name: String,
@@ -323,7 +633,10 @@ pub struct Method {
     pub(super) return_type: Option<Type>,
     pub(super) arguments: Vec<Argument>,
     pub(super) ffi_func: FFIFunction,
+    pub(super) checksum_func: FFIFunction,
+    pub(super) checksum: u16,
     pub(super) attributes: MethodAttributes,
+    pub(super) docstring: Option<String>,
 }
 
 impl Method {
@@ -338,6 +651,9 @@ impl Method {
     // Methods have a special implicit first argument for the object instance,
     // hence `arguments` and `full_arguments` are different.
     pub fn full_arguments(&self) -> Vec<Argument> {
+        if self.is_static() {
+            return self.arguments.clone();
+        }
         vec![Argument {
             name: "ptr".to_string(),
             // TODO: ideally we'd get this via `ci.resolve_type_expression` so that it
@@ -370,18 +686,60 @@ impl Method {
             .map(|name| Type::Error(name.to_owned()))
     }
 
+    pub fn deprecated(&self) -> Option<&str> {
+        self.attributes.get_deprecated()
+    }
+
     pub fn takes_self_by_arc(&self) -> bool {
         self.attributes.get_self_by_arc()
     }
 
+    pub fn is_async(&self) -> bool {
+        self.attributes.is_async()
+    }
+
+    pub fn is_static(&self) -> bool {
+        self.attributes.is_static()
+    }
+
+    pub fn docstring(&self) -> Option<&str> {
+        self.docstring.as_deref()
+    }
+
+    /// The FFI function that returns the checksum of this method's API, as seen by the library.
+    pub fn checksum_func(&self) -> &FFIFunction {
+        &self.checksum_func
+    }
+
+    /// The checksum of this method's API, as seen by the bindings.
+    pub fn checksum(&self) -> u16 {
+        self.checksum
+    }
+
     pub fn derive_ffi_func(&mut self, ci_prefix: &str, obj_prefix: &str) -> Result<()> {
         self.ffi_func.name.push_str(ci_prefix);
         self.ffi_func.name.push('_');
         self.ffi_func.name.push_str(obj_prefix);
         self.ffi_func.name.push('_');
         self.ffi_func.name.push_str(&self.name);
+        self.checksum_func.name = format!(
+            "uniffi_{}_checksum_method_{}_{}",
+            ci_prefix, obj_prefix, self.name
+        );
+        self.checksum = uniffi_meta::item_checksum(self);
+        self.derive_ffi_signature()
+    }
+
+    /// Derive the arguments and return type of the FFI function, whose name has already been set.
+    pub(super) fn derive_ffi_signature(&mut self) -> Result<()> {
+        self.checksum_func.return_type = Some(FFIType::UInt16);
         self.ffi_func.arguments = self.full_arguments().iter().map(Into::into).collect();
-        self.ffi_func.return_type = self.return_type.as_ref().map(Into::into);
+        // Like async functions, async methods return a handle to a `RustFuture`.
+        self.ffi_func.return_type = if self.is_async() {
+            Some(FFIType::RustArcPtr)
+        } else {
+            self.return_type.as_ref().map(Into::into)
+        };
         Ok(())
     }
 }
@@ -400,12 +758,9 @@ impl IterTypes for Method {
 
 impl Hash for Method {
     fn hash<H: Hasher>(&self, state: &mut H) {
-        // We don't include the FFIFunc in the hash calculation, because:
-        //  - it is entirely determined by the other fields,
-        //    so excluding it is safe.
-        //  - its `name` property includes a checksum derived from  the very
-        //    hash value we're trying to calculate here, so excluding it
-        //    avoids a weird circular depenendency in the calculation.
+        // We don't include the FFIFuncs or checksum in the hash calculation, because
+        // they are entirely determined by the other fields, so excluding them is safe.
+        // The checksum is itself derived from this hash, so we must exclude that anyway.
         self.name.hash(state);
         self.object_name.hash(state);
         self.arguments.hash(state);
@@ -439,7 +794,147 @@ impl APIConverter<Method> for weedle::interface::OperationInterfaceMember<'_> {
             arguments: self.args.body.list.convert(ci)?,
             return_type,
             ffi_func: Default::default(),
+            checksum_func: Default::default(),
+            checksum: 0,
             attributes: MethodAttributes::try_from(self.attributes.as_ref())?,
+            docstring: None,
+        })
+    }
+}
+
+impl APIConverter<Method> for uniffi_meta::MethodMetadata {
+    fn convert(&self, ci: &mut ComponentInterface) -> Result<Method> {
+        let return_type = self
+            .return_type
+            .as_ref()
+            .map(|t| ci.resolve_type_expression(t))
+            .transpose()?;
+        Ok(Method {
+            name: self.name.clone(),
+            object_name: self.self_name.clone(),
+            arguments: self.inputs.convert(ci)?,
+            return_type,
+            ffi_func: FFIFunction {
+                name: self.ffi_symbol_name(),
+                ..Default::default()
+            },
+            checksum_func: FFIFunction {
+                name: self.checksum_symbol_name(),
+                ..Default::default()
+            },
+            checksum: uniffi_meta::item_checksum(self),
+            attributes: self.into(),
+            docstring: None,
+        })
+    }
+}
+
+/// Represents a property of an object, declared as an `attribute` in the UDL.
+///
+/// In Rust, a property called `name` is read by a `name()` method on the object, and
+/// written by a `set_name()` method unless it's a `readonly attribute`. These cross the
+/// FFI like any other method, but the bindings expose them as a property.
+#[derive(Debug, Clone)]
+pub struct Property {
+    pub(super) name: String,
+    pub(super) getter: Method,
+    pub(super) setter: Option<Method>,
+    pub(super) docstring: Option<String>,
+}
+
+impl Property {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn type_(&self) -> Type {
+        self.getter
+            .return_type
+            .clone()
+            .expect("property getters always return a value")
+    }
+
+    pub fn is_readonly(&self) -> bool {
+        self.setter.is_none()
+    }
+
+    pub fn getter(&self) -> &Method {
+        &self.getter
+    }
+
+    pub fn setter(&self) -> Option<&Method> {
+        self.setter.as_ref()
+    }
+
+    pub fn docstring(&self) -> Option<&str> {
+        self.docstring.as_deref()
+    }
+
+    fn accessors(&self) -> impl Iterator<Item = &Method> {
+        std::iter::once(&self.getter).chain(self.setter.iter())
+    }
+
+    fn accessors_mut(&mut self) -> impl Iterator<Item = &mut Method> {
+        std::iter::once(&mut self.getter).chain(self.setter.iter_mut())
+    }
+}
+
+impl IterTypes for Property {
+    fn iter_types(&self) -> TypeIterator<'_> {
+        // The setter takes the same type that the getter returns.
+        self.getter.iter_types()
+    }
+}
+
+impl Hash for Property {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.name.hash(state);
+        self.getter.hash(state);
+        self.setter.hash(state);
+    }
+}
+
+impl APIConverter<Property> for weedle::interface::AttributeInterfaceMember<'_> {
+    fn convert(&self, ci: &mut ComponentInterface) -> Result<Property> {
+        if self.attributes.is_some() {
+            bail!("attributes are not supported on interface attributes");
+        }
+        if self.modifier.is_some() {
+            bail!("interface attribute modifiers are not supported");
+        }
+        let name = self.identifier.0.to_string();
+        let type_ = ci.resolve_type_expression(&self.type_.type_)?;
+        let accessor = |name: String, arguments: Vec<Argument>, return_type: Option<Type>| {
+            Method {
+                name,
+                // We don't know the name of the containing `Object` at this point, fill it in later.
+                object_name: Default::default(),
+                arguments,
+                return_type,
+                ffi_func: Default::default(),
+                checksum_func: Default::default(),
+                checksum: 0,
+                attributes: Default::default(),
+                docstring: None,
+            }
+        };
+        let setter = if self.readonly.is_some() {
+            None
+        } else {
+            let value = Argument {
+                name: "value".to_string(),
+                type_: type_.clone(),
+                by_ref: false,
+                optional: false,
+                default: None,
+            };
+            Some(accessor(format!("set_{}", name), vec![value], None))
+        };
+        Ok(Property {
+            getter: accessor(name.clone(), vec![], Some(type_)),
+            setter,
+            name,
+            docstring: None,
         })
     }
 }
@@ -579,4 +1074,271 @@ mod test {
         let err = ComponentInterface::from_webidl(UDL2).unwrap_err();
         assert_eq!(err.to_string(), "Duplicate interface member name: \"new\"");
     }
+
+    #[test]
+    fn test_async_methods() {
+        const UDL: &str = r#"
+            namespace test{};
+            interface Testing {
+                constructor();
+                u32 sync_method();
+                [Async]
+                u32 async_method(string arg);
+            };
+        "#;
+        let ci = ComponentInterface::from_webidl(UDL).unwrap();
+        let obj = ci.get_object_definition("Testing").unwrap();
+
+        let meth = obj.methods()[0];
+        assert_eq!(meth.name(), "sync_method");
+        assert!(!meth.is_async());
+        assert!(matches!(meth.ffi_func.return_type(), Some(FFIType::UInt32)));
+
+        let meth = obj.methods()[1];
+        assert_eq!(meth.name(), "async_method");
+        assert!(meth.is_async());
+        assert!(matches!(meth.return_type(), Some(Type::UInt32)));
+        assert_eq!(meth.ffi_func.arguments.len(), 2);
+        assert!(matches!(
+            meth.ffi_func.return_type(),
+            Some(FFIType::RustArcPtr)
+        ));
+    }
+
+    #[test]
+    fn test_deprecated_constructors_and_methods() {
+        const UDL: &str = r#"
+            namespace test{};
+            interface Testing {
+                [Deprecated="Use Testing.create()"]
+                constructor();
+                [Name=create]
+                constructor(u32 size);
+                [Deprecated="Use new_method()", Throws=Error]
+                void old_method();
+                void new_method();
+            };
+            [Error]
+            enum Error { "Oops" };
+        "#;
+        let ci = ComponentInterface::from_webidl(UDL).unwrap();
+        let obj = ci.get_object_definition("Testing").unwrap();
+        let cons = obj.primary_constructor().unwrap();
+        assert_eq!(cons.deprecated(), Some("Use Testing.create()"));
+        assert_eq!(obj.alternate_constructors()[0].deprecated(), None);
+        assert_eq!(obj.methods()[0].deprecated(), Some("Use new_method()"));
+        assert_eq!(obj.methods()[0].throws(), Some("Error"));
+        assert_eq!(obj.methods()[1].deprecated(), None);
+    }
+
+    #[test]
+    fn test_trait_interfaces() {
+        const UDL: &str = r#"
+            namespace test{};
+            [Trait]
+            interface Testing {
+                string name();
+            };
+        "#;
+        let ci = ComponentInterface::from_webidl(UDL).unwrap();
+        let obj = ci.get_object_definition("Testing").unwrap();
+        assert_eq!(obj.imp(), &ObjectImpl::Trait);
+        assert_eq!(obj.rust_name(), "dyn Testing");
+        // Traits can't be constructed, so they don't get a default constructor.
+        assert!(obj.constructors().is_empty());
+        assert_eq!(obj.methods()[0].name(), "name");
+
+        const UDL2: &str = r#"
+            namespace test{};
+            [Trait]
+            interface Testing {
+                constructor();
+            };
+        "#;
+        let err = ComponentInterface::from_webidl(UDL2).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Trait interface \"Testing\" cannot have constructors"
+        );
+
+        const UDL3: &str = r#"
+            namespace test{};
+            [Trait]
+            interface Testing {
+                [Async]
+                string name();
+            };
+        "#;
+        let err = ComponentInterface::from_webidl(UDL3).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Trait interface \"Testing\" cannot have async methods"
+        );
+    }
+
+    #[test]
+    fn test_properties() {
+        const UDL: &str = r#"
+            namespace test{};
+            interface Testing {
+                readonly attribute string name;
+                attribute u32 volume;
+                void reset();
+            };
+        "#;
+        let ci = ComponentInterface::from_webidl(UDL).unwrap();
+        let obj = ci.get_object_definition("Testing").unwrap();
+        assert_eq!(obj.properties().len(), 2);
+
+        let name = &obj.properties()[0];
+        assert_eq!(name.name(), "name");
+        assert_eq!(name.type_(), Type::String);
+        assert!(name.is_readonly());
+        assert_eq!(name.getter().name(), "name");
+        assert!(name.setter().is_none());
+
+        let volume = &obj.properties()[1];
+        assert!(!volume.is_readonly());
+        let setter = volume.setter().unwrap();
+        assert_eq!(setter.name(), "set_volume");
+        assert_eq!(setter.arguments()[0].type_(), Type::UInt32);
+        assert_eq!(setter.return_type(), None);
+
+        // The accessors cross the FFI like any other method.
+        assert_eq!(obj.methods().len(), 1);
+        let names: Vec<_> = obj.ffi_methods().iter().map(|m| m.name()).collect();
+        assert_eq!(names, vec!["reset", "name", "volume", "set_volume"]);
+        assert!(ci
+            .iter_ffi_function_definitions()
+            .iter()
+            .any(|f| f.name().ends_with("_Testing_set_volume")));
+    }
+
+    #[test]
+    fn test_property_accessors_cannot_clash_with_methods() {
+        const UDL: &str = r#"
+            namespace test{};
+            interface Testing {
+                attribute u32 volume;
+                void set_volume(u32 volume);
+            };
+        "#;
+        let err = ComponentInterface::from_webidl(UDL).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Duplicate interface member name: \"set_volume\""
+        );
+    }
+
+    #[test]
+    fn test_static_methods() {
+        const UDL: &str = r#"
+            namespace test{};
+            interface Testing {
+                [Static]
+                boolean validate(string token);
+                void reset();
+            };
+            [Trait]
+            interface Shape {
+                [Static]
+                Shape unit();
+            };
+        "#;
+        let err = ComponentInterface::from_webidl(UDL).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Trait interface \"Shape\" cannot have static methods"
+        );
+
+        let ci = ComponentInterface::from_webidl(&UDL[..UDL.find("[Trait]").unwrap()]).unwrap();
+        let obj = ci.get_object_definition("Testing").unwrap();
+        assert_eq!(obj.methods().len(), 2);
+        assert_eq!(obj.instance_methods()[0].name(), "reset");
+        let meth = obj.static_methods()[0];
+        assert_eq!(meth.name(), "validate");
+        assert!(meth.is_static());
+        // Static methods don't take a pointer to the object.
+        assert_eq!(meth.full_arguments().len(), 1);
+        assert_eq!(meth.ffi_func().arguments().len(), 1);
+        assert_eq!(meth.ffi_func().arguments()[0].name(), "token");
+        assert_eq!(obj.instance_methods()[0].ffi_func().arguments().len(), 1);
+    }
+
+    #[test]
+    fn test_traits() {
+        const UDL: &str = r#"
+            namespace test{};
+            [Traits=(Display, Eq, Hash)]
+            interface Testing {};
+        "#;
+        let ci = ComponentInterface::from_webidl(UDL).unwrap();
+        let obj = ci.get_object_definition("Testing").unwrap();
+        assert_eq!(obj.trait_impls().len(), 3);
+        assert!(obj.trait_impl(&ObjectTrait::Debug).is_none());
+
+        let display = obj.trait_impl(&ObjectTrait::Display).unwrap();
+        assert_eq!(display.return_type(), Type::String);
+        assert_eq!(display.ffi_func().arguments().len(), 1);
+        assert!(matches!(
+            display.ffi_func().return_type(),
+            Some(FFIType::RustBuffer)
+        ));
+
+        let eq = obj.trait_impl(&ObjectTrait::Eq).unwrap();
+        assert!(eq.ffi_func().name().ends_with("_Testing_uniffi_trait_eq"));
+        assert_eq!(eq.ffi_func().arguments().len(), 2);
+        assert!(matches!(eq.ffi_func().return_type(), Some(FFIType::Int8)));
+
+        let hash = obj.trait_impl(&ObjectTrait::Hash).unwrap();
+        assert!(matches!(
+            hash.ffi_func().return_type(),
+            Some(FFIType::UInt64)
+        ));
+
+        assert!(ci
+            .iter_ffi_function_definitions()
+            .iter()
+            .any(|f| f.name() == eq.ffi_func().name()));
+        // The types of the trait results need helpers in the bindings.
+        assert!(ci.iter_types().iter().any(|t| t == &Type::UInt64));
+    }
+
+    #[test]
+    fn test_traits_errors() {
+        const UDL: &str = r#"
+            namespace test{};
+            [Traits=(Hash)]
+            interface Testing {};
+        "#;
+        let err = ComponentInterface::from_webidl(UDL).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Interface \"Testing\" cannot implement Hash without Eq"
+        );
+
+        const UDL2: &str = r#"
+            namespace test{};
+            [Trait, Traits=(Eq)]
+            interface Testing {};
+        "#;
+        let err = ComponentInterface::from_webidl(UDL2).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Trait interface \"Testing\" cannot implement Eq or Hash"
+        );
+    }
+
+    #[test]
+    fn test_traits_change_the_checksum() {
+        let ci1 = ComponentInterface::from_webidl(
+            "namespace test{}; [Traits=(Debug)] interface Testing {};",
+        )
+        .unwrap();
+        let ci2 = ComponentInterface::from_webidl(
+            "namespace test{}; [Traits=(Display)] interface Testing {};",
+        )
+        .unwrap();
+        assert_ne!(ci1.checksum(), ci2.checksum());
+    }
 }
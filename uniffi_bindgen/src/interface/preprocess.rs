@@ -0,0 +1,585 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! # Preparing UDL for weedle.
+//!
+//! UniFFI accepts some syntax that isn't part of WebIDL, such as constants in the namespace,
+//! generic types like tuples and sets, and values for enum variants, so weedle can't parse the
+//! UDL as written. Instead, we tokenize the UDL once and use the tokens to rewrite those parts
+//! before handing it to weedle, parsing them ourselves.
+//!
+//! Anything we rewrite is padded with whitespace, keeping its line breaks, so that the rest of
+//! the UDL stays where it was and weedle's parse errors still point at the right place.
+//!
+//! The same tokens are used to find the doc comments in the UDL, see [`super::docstring`].
+
+use std::ops::Range;
+
+use super::types::GenericType;
+
+/// The UDL, ready for weedle to parse, along with the parts that we took out of it.
+#[derive(Debug)]
+pub(super) struct Preprocessed<'a> {
+    /// The UDL for weedle to parse, with the same line breaks as the original.
+    pub(super) idl: String,
+    /// The text of each `const` declaration in the namespace.
+    pub(super) constants: Vec<&'a str>,
+    /// The generic types that weedle can't parse, by the placeholder names that replaced them.
+    pub(super) generic_types: Vec<(String, GenericType)>,
+}
+
+/// Take the parts of some UDL that weedle can't parse out of it.
+pub(super) fn preprocess(idl: &str) -> Preprocessed<'_> {
+    let tokens = tokenize(idl);
+    let mut edits = Edits::default();
+    let constants = extract_constants(idl, &tokens, &mut edits);
+    // There's no need to look for generic types in the constants we took out.
+    let tokens = tokens
+        .into_iter()
+        .filter(|(_, span)| !edits.covers(span.start))
+        .collect::<Vec<_>>();
+    let mut generic_types = Vec::new();
+    edits.extend(desugar_generic_types(idl, &tokens, &mut generic_types));
+    desugar_discriminants(&tokens, &mut edits);
+    Preprocessed {
+        idl: edits.apply(idl),
+        constants,
+        generic_types,
+    }
+}
+
+/// Take the `const` declarations out of the namespace, returning the text of each one.
+///
+/// Any doc comment on a declaration is taken out too, since it would otherwise be left
+/// documenting nothing.
+fn extract_constants<'a>(
+    idl: &'a str,
+    tokens: &[(Token<'a>, Range<usize>)],
+    edits: &mut Edits,
+) -> Vec<&'a str> {
+    let mut constants = Vec::new();
+    // How deeply nested in braces we are, and whether that's in the namespace definition.
+    let mut depth = 0_usize;
+    let mut in_namespace = false;
+    // Whether the next token starts a new member of the namespace, and where its doc comment starts.
+    let mut at_member_start = false;
+    let mut doc_start = None;
+    let mut i = 0;
+    while i < tokens.len() {
+        let (token, span) = &tokens[i];
+        match token {
+            Token::Doc(_) => {
+                if at_member_start && doc_start.is_none() {
+                    doc_start = Some(span.start);
+                }
+                i += 1;
+                continue;
+            }
+            Token::Ident("const") if at_member_start => {
+                // Skip to the semicolon, which ends the member as usual.
+                if let Some(len) = tokens[i..]
+                    .iter()
+                    .position(|(t, _)| *t == Token::Punct(';'))
+                {
+                    let end = tokens[i + len].1.end;
+                    constants.push(&idl[span.start..end]);
+                    edits.replace(doc_start.unwrap_or(span.start)..end, "");
+                    i += len;
+                    continue;
+                }
+            }
+            Token::Ident("namespace") if depth == 0 => in_namespace = true,
+            Token::Punct('{') => depth += 1,
+            Token::Punct('}') => {
+                depth = depth.saturating_sub(1);
+                if depth == 0 {
+                    in_namespace = false;
+                }
+            }
+            _ => {}
+        }
+        at_member_start =
+            in_namespace && depth == 1 && matches!(token, Token::Punct('{') | Token::Punct(';'));
+        doc_start = None;
+        i += 1;
+    }
+    constants
+}
+
+/// Replace the generic types that weedle can't parse with placeholder names, adding each one to
+/// `generic_types` under its name.
+///
+/// The type arguments are kept as text, after replacing any generic types nested inside them.
+fn desugar_generic_types(
+    idl: &str,
+    tokens: &[(Token<'_>, Range<usize>)],
+    generic_types: &mut Vec<(String, GenericType)>,
+) -> Edits {
+    let mut edits = Edits::default();
+    let mut i = 0;
+    while i < tokens.len() {
+        if let Some((len, args)) = generic_type_args(&tokens[i..]) {
+            let mut desugar_arg = |arg| desugar_type_arg(idl, arg, generic_types);
+            let generic = match (tokens[i].0, args.as_slice()) {
+                (Token::Ident("record"), [key, value]) if !is_string_type(key) => Some((
+                    "map",
+                    GenericType::Map(desugar_arg(key), desugar_arg(value)),
+                )),
+                (Token::Ident("tuple"), _) => Some((
+                    "tuple",
+                    GenericType::Tuple(args.iter().map(|arg| desugar_arg(arg)).collect()),
+                )),
+                (Token::Ident("set"), [element]) => {
+                    Some(("set", GenericType::Set(desugar_arg(element))))
+                }
+                (Token::Ident("ordered_record"), [key, value]) => Some((
+                    "ordered_map",
+                    GenericType::OrderedMap(desugar_arg(key), desugar_arg(value)),
+                )),
+                _ => None,
+            };
+            if let Some((kind, generic)) = generic {
+                let name = format!("{}-{}", kind, generic_types.len());
+                edits.replace(tokens[i].1.start..tokens[i + len - 1].1.end, &name);
+                generic_types.push((name, generic));
+                i += len;
+                continue;
+            }
+        }
+        i += 1;
+    }
+    edits
+}
+
+/// If the tokens start with a generic type, like `record<K, V>`, find how many tokens it
+/// takes up and the tokens of each of its type arguments.
+#[allow(clippy::type_complexity)]
+fn generic_type_args<'t, 'a>(
+    tokens: &'t [(Token<'a>, Range<usize>)],
+) -> Option<(usize, Vec<&'t [(Token<'a>, Range<usize>)]>)> {
+    if !matches!(tokens, [(Token::Ident(_), _), (Token::Punct('<'), _), ..]) {
+        return None;
+    }
+    let mut args = Vec::new();
+    let mut arg_start = 2;
+    // How deeply nested in brackets we are, within the type arguments.
+    let mut depth = 0_usize;
+    for (i, (token, _)) in tokens.iter().enumerate().skip(2) {
+        match token {
+            Token::Punct('<') | Token::Punct('(') => depth += 1,
+            Token::Punct(',') | Token::Punct('>') if depth == 0 => {
+                if arg_start == i {
+                    return None;
+                }
+                args.push(&tokens[arg_start..i]);
+                arg_start = i + 1;
+                if *token == Token::Punct('>') {
+                    return Some((i + 1, args));
+                }
+            }
+            Token::Punct('>') | Token::Punct(')') if depth > 0 => depth -= 1,
+            // Leave anything that doesn't look like a type for weedle to complain about.
+            Token::Punct(')') | Token::Punct(';') | Token::Punct('{') | Token::Punct('}') => {
+                return None
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Whether the tokens of a type argument are one of the WebIDL string types, which weedle
+/// accepts as the key of a record.
+fn is_string_type(tokens: &[(Token<'_>, Range<usize>)]) -> bool {
+    matches!(
+        tokens,
+        [(Token::Ident("DOMString"), _)]
+            | [(Token::Ident("ByteString"), _)]
+            | [(Token::Ident("USVString"), _)]
+    )
+}
+
+/// Get the text of a (non-empty) type argument, with any generic types in it desugared.
+fn desugar_type_arg(
+    idl: &str,
+    tokens: &[(Token<'_>, Range<usize>)],
+    generic_types: &mut Vec<(String, GenericType)>,
+) -> String {
+    let range = tokens[0].1.start..tokens[tokens.len() - 1].1.end;
+    desugar_generic_types(idl, tokens, generic_types).apply_within(idl, range, false)
+}
+
+/// Move the explicit values of enum variants into syntax that weedle can parse.
+///
+/// WebIDL enums are just lists of strings, so we move each value inside the string before it,
+/// turning `"Low" = 10` into `"Low=10"`, and split it back out again with [`variant_value`]
+/// when converting the enum. The values of flags are often written in hex, so any number-like
+/// word is moved, and it's up to the conversion to reject the ones it doesn't understand.
+fn desugar_discriminants(tokens: &[(Token<'_>, Range<usize>)], edits: &mut Edits) {
+    let mut in_enum = false;
+    for (i, (token, span)) in tokens.iter().enumerate() {
+        match (token, &tokens[i + 1..]) {
+            (Token::Ident("enum"), [(Token::Ident(_), _), (Token::Punct('{'), _), ..]) => {
+                in_enum = true
+            }
+            (Token::Punct('}'), _) => in_enum = false,
+            (Token::Str(variant), [(Token::Punct('='), _), rest @ ..]) if in_enum => {
+                let (sign, rest) = match rest {
+                    [(Token::Punct('-'), _), rest @ ..] => ("-", rest),
+                    _ => ("", rest),
+                };
+                if let [(Token::Ident(value), value_span), ..] = rest {
+                    if value.starts_with(|c: char| c.is_ascii_digit()) {
+                        let replacement = format!("\"{}={}{}\"", variant, sign, value);
+                        edits.replace(span.start..value_span.end, &replacement);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Get the name of an enum variant, and the text of its value if it has one.
+///
+/// See [`desugar_discriminants`] for how the value gets into the name.
+pub(super) fn variant_value<'a>(
+    variant: &weedle::literal::StringLit<'a>,
+) -> (&'a str, Option<&'a str>) {
+    match variant.0.split_once('=') {
+        Some((name, value)) => (name, Some(value)),
+        None => (variant.0, None),
+    }
+}
+
+/// Replacements for parts of the UDL, which mustn't overlap.
+#[derive(Debug, Default)]
+struct Edits(Vec<(Range<usize>, String)>);
+
+impl Edits {
+    fn replace(&mut self, range: Range<usize>, replacement: &str) {
+        self.0.push((range, replacement.to_string()));
+    }
+
+    fn extend(&mut self, other: Edits) {
+        self.0.extend(other.0);
+    }
+
+    /// Whether a position in the UDL is replaced by one of the edits.
+    fn covers(&self, pos: usize) -> bool {
+        self.0.iter().any(|(range, _)| range.contains(&pos))
+    }
+
+    /// Make the replacements in the UDL.
+    ///
+    /// Each replacement is padded with whitespace, keeping the line breaks of the text it
+    /// replaces, so that the text after it doesn't move.
+    fn apply(self, idl: &str) -> String {
+        self.apply_within(idl, 0..idl.len(), true)
+    }
+
+    /// Make the replacements in part of the UDL, returning just that part. The replacements
+    /// are only padded if `pad` is set.
+    fn apply_within(mut self, idl: &str, within: Range<usize>, pad: bool) -> String {
+        self.0.sort_by_key(|(range, _)| range.start);
+        let mut result = String::with_capacity(within.len());
+        let mut pos = within.start;
+        for (range, replacement) in self.0 {
+            result.push_str(&idl[pos..range.start]);
+            result.push_str(&replacement);
+            pos = range.end;
+            if !pad {
+                continue;
+            }
+            let replaced = &idl[range.clone()];
+            let first_line_len = replaced.find('\n').unwrap_or(replaced.len());
+            let mut padding_start = usize::min(replacement.len(), first_line_len);
+            while !replaced.is_char_boundary(padding_start) {
+                padding_start -= 1;
+            }
+            result.extend(replaced[padding_start..].chars().map(|c| {
+                if c == '\n' {
+                    '\n'
+                } else {
+                    ' '
+                }
+            }));
+        }
+        result.push_str(&idl[pos..within.end]);
+        result
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum Token<'a> {
+    /// The text of a `///` comment, without the slashes.
+    Doc(&'a str),
+    Ident(&'a str),
+    /// The contents of a string literal.
+    Str(&'a str),
+    /// Any other character, except whitespace.
+    Punct(char),
+}
+
+/// Split some UDL into tokens, along with the range of the UDL that each one came from.
+///
+/// Comments other than doc comments are skipped over, like whitespace.
+pub(super) fn tokenize(idl: &str) -> Vec<(Token<'_>, Range<usize>)> {
+    let mut tokens = Vec::new();
+    let mut rest = idl;
+    while let Some(c) = rest.chars().next() {
+        let start = idl.len() - rest.len();
+        let token = if let Some(comment) = rest.strip_prefix("//") {
+            let end = comment.find('\n').unwrap_or(comment.len());
+            let token = match comment.strip_prefix('/') {
+                // A comment starting with four slashes isn't a doc comment.
+                Some(doc) if !doc.starts_with('/') => Some(Token::Doc(&doc[..end - 1])),
+                _ => None,
+            };
+            rest = &comment[end..];
+            token
+        } else if let Some(comment) = rest.strip_prefix("/*") {
+            rest = comment.find("*/").map_or("", |end| &comment[end + 2..]);
+            None
+        } else if let Some(string) = rest.strip_prefix('"') {
+            let end = string.find('"').unwrap_or(string.len());
+            rest = string.get(end + 1..).unwrap_or("");
+            Some(Token::Str(&string[..end]))
+        } else if c.is_alphanumeric() || c == '_' {
+            let end = rest
+                .find(|c: char| !(c.is_alphanumeric() || c == '_' || c == '-' || c == '.'))
+                .unwrap_or(rest.len());
+            let token = Token::Ident(&rest[..end]);
+            rest = &rest[end..];
+            Some(token)
+        } else {
+            rest = &rest[c.len_utf8()..];
+            if c.is_whitespace() {
+                None
+            } else {
+                Some(Token::Punct(c))
+            }
+        };
+        if let Some(token) = token {
+            tokens.push((token, start..idl.len() - rest.len()));
+        }
+    }
+    tokens
+}
+
+/// A cursor over the tokens of some UDL.
+pub(super) struct Tokens<'a> {
+    tokens: Vec<(Token<'a>, Range<usize>)>,
+    pos: usize,
+}
+
+impl<'a> Tokens<'a> {
+    pub(super) fn new(idl: &'a str) -> Self {
+        Self {
+            tokens: tokenize(idl),
+            pos: 0,
+        }
+    }
+
+    pub(super) fn is_empty(&self) -> bool {
+        self.pos >= self.tokens.len()
+    }
+
+    pub(super) fn peek(&self) -> Option<Token<'a>> {
+        self.peek_nth(0)
+    }
+
+    /// Look at the token `n` places after the current one.
+    pub(super) fn peek_nth(&self, n: usize) -> Option<Token<'a>> {
+        self.tokens.get(self.pos + n).map(|(token, _)| *token)
+    }
+
+    pub(super) fn next(&mut self) -> Option<Token<'a>> {
+        let token = self.peek();
+        self.pos += 1;
+        token
+    }
+
+    /// Skip to the end of a block whose opening brace has already been consumed.
+    pub(super) fn skip_block(&mut self) {
+        let mut nesting = 1;
+        while let Some(token) = self.next() {
+            match token {
+                Token::Punct('{') => nesting += 1,
+                Token::Punct('}') => {
+                    nesting -= 1;
+                    if nesting == 0 {
+                        return;
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_tokenize() {
+        const UDL: &str = "/// Doc.\nvoid f(string s = \"a///b\"); //// Four.\n/* /// */ é";
+        let tokens = tokenize(UDL);
+        assert_eq!(
+            tokens.iter().map(|(token, _)| *token).collect::<Vec<_>>(),
+            vec![
+                Token::Doc(" Doc."),
+                Token::Ident("void"),
+                Token::Ident("f"),
+                Token::Punct('('),
+                Token::Ident("string"),
+                Token::Ident("s"),
+                Token::Punct('='),
+                Token::Str("a///b"),
+                Token::Punct(')'),
+                Token::Punct(';'),
+                Token::Ident("é"),
+            ]
+        );
+        assert_eq!(&UDL[tokens[0].1.clone()], "/// Doc.");
+        assert_eq!(&UDL[tokens[7].1.clone()], "\"a///b\"");
+        assert_eq!(&UDL[tokens[10].1.clone()], "é");
+    }
+
+    #[test]
+    fn test_extract_constants() {
+        const UDL: &str = r#"
+            // const u8 NOT_A_CONSTANT = 1;
+            namespace test {
+                /// The maximum number of items.
+                const u32 MAX_ITEMS = 500;
+                /* const u8 ALSO_NOT = 2; */
+                void hello(string greeting = "const u8 STILL_NOT = 3;");
+                const string API_URL = "https://example.com/{api};";
+            };
+            dictionary const_record {
+                u32 value;
+            };
+        "#;
+        let preprocessed = preprocess(UDL);
+        assert_eq!(
+            preprocessed.constants,
+            vec![
+                "const u32 MAX_ITEMS = 500;",
+                r#"const string API_URL = "https://example.com/{api};";"#
+            ]
+        );
+        let remaining = preprocessed.idl;
+        assert_eq!(remaining.len(), UDL.len());
+        assert_eq!(remaining.lines().count(), UDL.lines().count());
+        assert!(!remaining.contains("MAX_ITEMS"));
+        assert!(!remaining.contains("The maximum number of items."));
+        assert!(!remaining.contains("API_URL"));
+        assert!(remaining.contains("NOT_A_CONSTANT"));
+        assert!(remaining.contains("ALSO_NOT"));
+        assert!(remaining.contains("STILL_NOT"));
+        assert_eq!(remaining.find("void hello"), UDL.find("void hello"));
+        assert_eq!(remaining.find("u32 value;"), UDL.find("u32 value;"));
+    }
+
+    #[test]
+    fn test_desugar_generic_types() {
+        const UDL: &str = r#"
+            namespace test {
+                const string NOT_A_MAP = "record<u8, u8>";
+                record<u8, record<string, u8>> f(record<DOMString, record<i32, u8>?> m);
+                tuple<u8, tuple<string, (u8 or u16)>?> g(sequence<tuple<u8>> t);
+                set<tuple<u8, u8>> h(ordered_record<string, set<u8>>? m);
+            };
+        "#;
+        let preprocessed = preprocess(UDL);
+        assert_eq!(
+            preprocessed.generic_types,
+            vec![
+                (
+                    "map-0".into(),
+                    GenericType::Map("string".into(), "u8".into())
+                ),
+                (
+                    "map-1".into(),
+                    GenericType::Map("u8".into(), "map-0".into())
+                ),
+                ("map-2".into(), GenericType::Map("i32".into(), "u8".into())),
+                (
+                    "tuple-3".into(),
+                    GenericType::Tuple(vec!["string".into(), "(u8 or u16)".into()])
+                ),
+                (
+                    "tuple-4".into(),
+                    GenericType::Tuple(vec!["u8".into(), "tuple-3?".into()])
+                ),
+                ("tuple-5".into(), GenericType::Tuple(vec!["u8".into()])),
+                (
+                    "tuple-6".into(),
+                    GenericType::Tuple(vec!["u8".into(), "u8".into()])
+                ),
+                ("set-7".into(), GenericType::Set("tuple-6".into())),
+                ("set-8".into(), GenericType::Set("u8".into())),
+                (
+                    "ordered_map-9".into(),
+                    GenericType::OrderedMap("string".into(), "set-8".into())
+                ),
+            ]
+        );
+        let remaining = preprocessed.idl;
+        assert_eq!(remaining.len(), UDL.len());
+        assert!(remaining.contains(&format!(
+            "map-1{} f(record<DOMString, map-2{}?> m);",
+            " ".repeat(25),
+            " ".repeat(10)
+        )));
+        assert!(remaining.contains(&format!(
+            "tuple-4{} g(sequence<tuple-5{}> t);",
+            " ".repeat(31),
+            " ".repeat(2)
+        )));
+        assert!(remaining.contains(&format!(
+            "set-7{} h(ordered_map-9{}? m);",
+            " ".repeat(13),
+            " ".repeat(18)
+        )));
+    }
+
+    #[test]
+    fn test_desugar_discriminants() {
+        const UDL: &str = r#"
+            enum E { "a" = 1, "b",  "c"=-20 };
+            [Flags=u8]
+            enum F { "a" = 0x10 , "b" = 1_000};
+            // Default values and attributes have the string after the `=`.
+            // "a" = 1
+            [Name="x"]
+            dictionary D { string s = "a"; };
+        "#;
+        assert_eq!(
+            preprocess(UDL).idl,
+            r#"
+            enum E { "a=1"  , "b",  "c=-20" };
+            [Flags=u8]
+            enum F { "a=0x10"   , "b=1_000"  };
+            // Default values and attributes have the string after the `=`.
+            // "a" = 1
+            [Name="x"]
+            dictionary D { string s = "a"; };
+        "#
+        );
+    }
+
+    #[test]
+    fn test_edits_keep_line_breaks() {
+        const UDL: &str = "a bbbb\nbb c\nd";
+        let mut edits = Edits::default();
+        edits.replace(2..9, "x");
+        assert_eq!(edits.apply(UDL), "a x   \n   c\nd");
+        let mut edits = Edits::default();
+        edits.replace(2..9, "xxxxxxxxxx");
+        assert_eq!(edits.apply(UDL), "a xxxxxxxxxx\n   c\nd");
+    }
+}
@@ -44,8 +44,12 @@
 //! # Ok::<(), anyhow::Error>(())
 //! ```
 
+use std::convert::TryFrom;
+use std::hash::{Hash, Hasher};
+
 use anyhow::{bail, Result};
 
+use super::attributes::{FieldAttributes, RecordAttributes};
 use super::literal::{convert_default_value, Literal};
 use super::types::{IterTypes, Type, TypeIterator};
 use super::{APIConverter, ComponentInterface};
@@ -55,10 +59,12 @@ use super::{APIConverter, ComponentInterface};
 /// In the FFI these are represented as a byte buffer, which one side explicitly
 /// serializes the data into and the other serializes it out of. So I guess they're
 /// kind of like "pass by clone" values.
-#[derive(Debug, Clone, Hash)]
+#[derive(Debug, Clone)]
 pub struct Record {
     pub(super) name: String,
     pub(super) fields: Vec<Field>,
+    pub(super) deprecated: Option<String>,
+    pub(super) docstring: Option<String>,
 }
 
 impl Record {
@@ -75,6 +81,14 @@ impl Record {
     pub fn fields(&self) -> Vec<&Field> {
         self.fields.iter().collect()
     }
+
+    pub fn deprecated(&self) -> Option<&str> {
+        self.deprecated.as_deref()
+    }
+
+    pub fn docstring(&self) -> Option<&str> {
+        self.docstring.as_deref()
+    }
 }
 
 impl IterTypes for Record {
@@ -83,28 +97,49 @@ impl IterTypes for Record {
     }
 }
 
+impl Hash for Record {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.name.hash(state);
+        self.fields.hash(state);
+        self.deprecated.hash(state);
+    }
+}
+
 impl APIConverter<Record> for weedle::DictionaryDefinition<'_> {
     fn convert(&self, ci: &mut ComponentInterface) -> Result<Record> {
-        if self.attributes.is_some() {
-            bail!("dictionary attributes are not supported yet");
-        }
         if self.inheritance.is_some() {
             bail!("dictionary inheritence is not supported");
         }
+        let attributes = RecordAttributes::try_from(self.attributes.as_ref())?;
         Ok(Record {
             name: self.identifier.0.to_string(),
             fields: self.members.body.convert(ci)?,
+            deprecated: attributes.get_deprecated().map(String::from),
+            docstring: None,
+        })
+    }
+}
+
+impl APIConverter<Record> for uniffi_meta::RecordMetadata {
+    fn convert(&self, ci: &mut ComponentInterface) -> Result<Record> {
+        Ok(Record {
+            name: self.name.clone(),
+            fields: self.fields.convert(ci)?,
+            deprecated: None,
+            docstring: None,
         })
     }
 }
 
 // Represents an individual field on a Record.
-#[derive(Debug, Clone, Hash)]
+#[derive(Debug, Clone)]
 pub struct Field {
     pub(super) name: String,
     pub(super) type_: Type,
     pub(super) required: bool,
     pub(super) default: Option<Literal>,
+    pub(super) deprecated: Option<String>,
+    pub(super) docstring: Option<String>,
 }
 
 impl Field {
@@ -117,6 +152,12 @@ impl Field {
     pub fn default_value(&self) -> Option<Literal> {
         self.default.clone()
     }
+    pub fn deprecated(&self) -> Option<&str> {
+        self.deprecated.as_deref()
+    }
+    pub fn docstring(&self) -> Option<&str> {
+        self.docstring.as_deref()
+    }
 }
 
 impl IterTypes for Field {
@@ -125,11 +166,19 @@ impl IterTypes for Field {
     }
 }
 
+impl Hash for Field {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.name.hash(state);
+        self.type_.hash(state);
+        self.required.hash(state);
+        self.default.hash(state);
+        self.deprecated.hash(state);
+    }
+}
+
 impl APIConverter<Field> for weedle::dictionary::DictionaryMember<'_> {
     fn convert(&self, ci: &mut ComponentInterface) -> Result<Field> {
-        if self.attributes.is_some() {
-            bail!("dictionary member attributes are not supported yet");
-        }
+        let attributes = FieldAttributes::try_from(self.attributes.as_ref())?;
         let type_ = ci.resolve_type_expression(&self.type_)?;
         if let Type::Object(_) = type_ {
             bail!("Objects cannot currently appear in record fields");
@@ -143,6 +192,25 @@ impl APIConverter<Field> for weedle::dictionary::DictionaryMember<'_> {
             type_,
             required: self.required.is_some(),
             default,
+            deprecated: attributes.get_deprecated().map(String::from),
+            docstring: None,
+        })
+    }
+}
+
+impl APIConverter<Field> for uniffi_meta::FieldMetadata {
+    fn convert(&self, ci: &mut ComponentInterface) -> Result<Field> {
+        let type_ = ci.resolve_type_expression(&self.ty)?;
+        if let Type::Object(_) = type_ {
+            bail!("Objects cannot currently appear in record fields");
+        }
+        Ok(Field {
+            name: self.name.clone(),
+            type_,
+            required: false,
+            default: None,
+            deprecated: None,
+            docstring: None,
         })
     }
 }
@@ -204,6 +272,37 @@ mod test {
         assert!(record.fields()[2].default_value().is_none());
     }
 
+    #[test]
+    fn test_deprecated_records_and_fields() {
+        const UDL: &str = r#"
+            namespace test{};
+            [Deprecated="Use Point instead"]
+            dictionary Coordinates {
+                [Deprecated="Always zero"]
+                u32 z;
+                u32 x;
+            };
+        "#;
+        let ci = ComponentInterface::from_webidl(UDL).unwrap();
+        let record = ci.get_record_definition("Coordinates").unwrap();
+        assert_eq!(record.deprecated(), Some("Use Point instead"));
+        assert_eq!(record.fields()[0].deprecated(), Some("Always zero"));
+        assert_eq!(record.fields()[1].deprecated(), None);
+
+        const UDL_BAD: &str = r#"
+            namespace test{};
+            dictionary Coordinates {
+                [ByRef]
+                u32 z;
+            };
+        "#;
+        let err = ComponentInterface::from_webidl(UDL_BAD).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "ByRef not supported for dictionary members"
+        );
+    }
+
     #[test]
     fn test_that_all_field_types_become_known() {
         const UDL: &str = r#"
@@ -22,7 +22,7 @@ use std::convert::TryFrom;
 use anyhow::{bail, Result};
 
 use super::super::attributes::{EnumAttributes, InterfaceAttributes, TypedefAttributes};
-use super::{Type, TypeUniverse};
+use super::{resolve_builtin_type, Type, TypeUniverse};
 
 /// Trait to help with an early "type discovery" phase when processing the UDL.
 ///
@@ -79,9 +79,24 @@ impl TypeFinder for weedle::DictionaryDefinition<'_> {
 impl TypeFinder for weedle::EnumDefinition<'_> {
     fn add_type_definitions_to(&self, types: &mut TypeUniverse) -> Result<()> {
         let name = self.identifier.0.to_string();
+        let attrs = EnumAttributes::try_from(self.attributes.as_ref())?;
         // Our error types are defined using an `enum` with a special attribute.
-        if EnumAttributes::try_from(self.attributes.as_ref())?.contains_error_attr() {
+        if attrs.contains_error_attr() {
             types.add_type_definition(self.identifier.0, Type::Error(name))
+        } else if let Some(prim) = attrs.get_flags_type() {
+            // As are sets of flags, whose attribute names the integer type that holds them.
+            // The bindings pass them using the helpers for that type, so it needs to be known.
+            let prim = match resolve_builtin_type(prim) {
+                Some(prim) => types.add_known_type(prim)?,
+                None => bail!("unknown type reference: {}", prim),
+            };
+            types.add_type_definition(
+                self.identifier.0,
+                Type::Flags {
+                    name,
+                    prim: prim.into(),
+                },
+            )
         } else {
             types.add_type_definition(self.identifier.0, Type::Enum(name))
         }
@@ -192,6 +207,19 @@ mod test {
             },
         );
 
+        test_a_finding(
+            r#"
+            [Flags=u8]
+            enum TestFlags { "A=1", "B=2" };
+        "#,
+            |types| {
+                assert!(
+                    matches!(types.get_type_definition("TestFlags").unwrap(), Type::Flags { name, prim }
+                                                                          if name == "TestFlags" && prim == Box::new(Type::UInt8))
+                );
+            },
+        );
+
         test_a_finding(
             r#"
             interface TestObject {
@@ -32,6 +32,22 @@ pub(super) use finder::TypeFinder;
 mod resolver;
 pub(super) use resolver::{resolve_builtin_type, TypeResolver};
 
+/// A generic type that weedle can't parse, such as a map with non-string keys.
+///
+/// Before the UDL is parsed, each of these is replaced with a placeholder name, and the text of
+/// its type arguments is kept here so that they can be resolved along with the rest of the UDL.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum GenericType {
+    /// A `record<K, V>` whose key type isn't one of the WebIDL string types.
+    Map(String, String),
+    /// A `tuple<A, B>`, with any number of elements.
+    Tuple(Vec<String>),
+    /// A `set<T>`.
+    Set(String),
+    /// An `ordered_record<K, V>`.
+    OrderedMap(String, String),
+}
+
 /// Represents all the different high-level types that can be used in a component interface.
 /// At this level we identify user-defined types by name, without knowing any details
 /// of their internal structure apart from what type of thing they are (record, enum, etc).
@@ -46,10 +62,13 @@ pub enum Type {
     Int32,
     UInt64,
     Int64,
+    UInt128,
+    Int128,
     Float32,
     Float64,
     Boolean,
     String,
+    Bytes,
     Timestamp,
     Duration,
     // Types defined in the component API, each of which has a string name.
@@ -61,11 +80,16 @@ pub enum Type {
     // Structurally recursive types.
     Optional(Box<Type>),
     Sequence(Box<Type>),
-    Map(/* String, */ Box<Type>),
+    Map(Box<Type>, Box<Type>),
+    Set(Box<Type>),
+    OrderedMap(Box<Type>, Box<Type>),
+    Tuple(Vec<Type>),
     // An FfiConverter we `use` from an external crate
     External { name: String, crate_name: String },
     // A local type we will generate an FfiConverter via wrapping a primitive.
     Wrapped { name: String, prim: Box<Type> },
+    // A set of flags, which is passed as the unsigned integer that holds its bits.
+    Flags { name: String, prim: Box<Type> },
 }
 
 impl Type {
@@ -86,9 +110,12 @@ impl Type {
             Type::UInt32 => "u32".into(),
             Type::Int64 => "i64".into(),
             Type::UInt64 => "u64".into(),
+            Type::Int128 => "i128".into(),
+            Type::UInt128 => "u128".into(),
             Type::Float32 => "f32".into(),
             Type::Float64 => "f64".into(),
             Type::String => "string".into(),
+            Type::Bytes => "bytes".into(),
             Type::Boolean => "bool".into(),
             // API defined types.
             // Note that these all get unique names, and the parser ensures that the names do not
@@ -100,6 +127,7 @@ impl Type {
             Type::Error(nm) => format!("Type{}", nm),
             Type::Enum(nm) => format!("Type{}", nm),
             Type::Record(nm) => format!("Type{}", nm),
+            Type::Flags { name, .. } => format!("Type{}", name),
             Type::CallbackInterface(nm) => format!("CallbackInterface{}", nm),
             Type::Timestamp => "Timestamp".into(),
             Type::Duration => "Duration".into(),
@@ -110,7 +138,18 @@ impl Type {
             // acccidentally generating name collisions.
             Type::Optional(t) => format!("Optional{}", t.canonical_name()),
             Type::Sequence(t) => format!("Sequence{}", t.canonical_name()),
-            Type::Map(t) => format!("Map{}", t.canonical_name()),
+            Type::Map(k, v) => format!("Map{}{}", k.canonical_name(), v.canonical_name()),
+            Type::Set(t) => format!("Set{}", t.canonical_name()),
+            Type::OrderedMap(k, v) => {
+                format!("OrderedMap{}{}", k.canonical_name(), v.canonical_name())
+            }
+            // Include the arity, so that nested tuples like `((A, B), C, D)` and `((A, B, C), D)`
+            // get different names.
+            Type::Tuple(ts) => format!(
+                "Tuple{}{}",
+                ts.len(),
+                ts.iter().map(Type::canonical_name).collect::<String>()
+            ),
             // A type that exists externally.
             Type::External { name, .. } | Type::Wrapped { name, .. } => format!("Type{}", name),
         }
@@ -134,6 +173,8 @@ impl From<&Type> for FFIType {
             Type::Int32 => FFIType::Int32,
             Type::UInt64 => FFIType::UInt64,
             Type::Int64 => FFIType::Int64,
+            // There's no portable C type for 128-bit integers, so they're serialized.
+            Type::UInt128 | Type::Int128 => FFIType::RustBuffer,
             Type::Float32 => FFIType::Float32,
             Type::Float64 => FFIType::Float64,
             // Booleans lower into an Int8, to work around a bug in JNA.
@@ -141,6 +182,8 @@ impl From<&Type> for FFIType {
             // Strings are always owned rust values.
             // We might add a separate type for borrowed strings in future.
             Type::String => FFIType::RustBuffer,
+            // Byte strings are passed in the same way as strings, without the utf-8 requirement.
+            Type::Bytes => FFIType::RustBuffer,
             // Objects are pointers to an Arc<>
             Type::Object(_) => FFIType::RustArcPtr,
             // Callback interfaces are passed as opaque integer handles.
@@ -151,11 +194,14 @@ impl From<&Type> for FFIType {
             | Type::Record(_)
             | Type::Optional(_)
             | Type::Sequence(_)
-            | Type::Map(_)
+            | Type::Map(..)
+            | Type::Set(_)
+            | Type::OrderedMap(..)
+            | Type::Tuple(_)
             | Type::Timestamp
             | Type::Duration
             | Type::External { .. } => FFIType::RustBuffer,
-            Type::Wrapped { prim, .. } => FFIType::from(prim.as_ref()),
+            Type::Wrapped { prim, .. } | Type::Flags { prim, .. } => FFIType::from(prim.as_ref()),
         }
     }
 }
@@ -176,6 +222,8 @@ pub(crate) struct TypeUniverse {
     type_definitions: HashMap<String, Type>,
     // All the types in the universe, by canonical type name, in a well-defined order.
     all_known_types: BTreeSet<Type>,
+    // Generic types that were replaced with placeholder names before parsing the UDL.
+    generic_types: HashMap<String, GenericType>,
 }
 
 impl TypeUniverse {
@@ -212,6 +260,16 @@ impl TypeUniverse {
         self.type_definitions.get(name).cloned()
     }
 
+    /// Add a generic type that was replaced with the given placeholder name in the UDL.
+    pub(super) fn add_generic_type(&mut self, name: &str, generic: GenericType) {
+        self.generic_types.insert(name.to_string(), generic);
+    }
+
+    /// Get the generic type that a placeholder name stands for, if any.
+    pub(super) fn get_generic_type(&self, name: &str) -> Option<GenericType> {
+        self.generic_types.get(name).cloned()
+    }
+
     /// Get the [Type] corresponding to a given WebIDL type node.
     ///
     /// If the node is a structural type (e.g. a sequence) then this will also add
@@ -286,7 +344,14 @@ impl<T: IterTypes> IterTypes for Option<T> {
 impl IterTypes for Type {
     fn iter_types(&self) -> TypeIterator<'_> {
         let nested_types = match self {
-            Type::Optional(t) | Type::Sequence(t) | Type::Map(t) => Some(t.iter_types()),
+            Type::Optional(t) | Type::Sequence(t) | Type::Set(t) => Some(t.iter_types()),
+            Type::Map(k, v) | Type::OrderedMap(k, v) => {
+                Some(Box::new(k.iter_types().chain(v.iter_types())) as TypeIterator<'_>)
+            }
+            Type::Tuple(ts) => {
+                Some(Box::new(ts.iter().flat_map(IterTypes::iter_types)) as TypeIterator<'_>)
+            }
+            Type::Wrapped { prim, .. } | Type::Flags { prim, .. } => Some(prim.iter_types()),
             _ => None,
         };
         Box::new(std::iter::once(self).chain(nested_types.into_iter().flatten()))
@@ -308,6 +373,7 @@ mod test_type {
         // Non-exhaustive, but gives a bit of a flavour of what we want.
         assert_eq!(Type::UInt8.canonical_name(), "u8");
         assert_eq!(Type::String.canonical_name(), "string");
+        assert_eq!(Type::Bytes.canonical_name(), "bytes");
         assert_eq!(
             Type::Optional(Box::new(Type::Sequence(Box::new(Type::Object(
                 "Example".into()
@@ -315,6 +381,14 @@ mod test_type {
             .canonical_name(),
             "OptionalSequenceTypeExample"
         );
+        assert_eq!(
+            Type::Tuple(vec![
+                Type::Tuple(vec![Type::String, Type::UInt32]),
+                Type::Boolean
+            ])
+            .canonical_name(),
+            "Tuple2Tuple2stringu32bool"
+        );
     }
 }
 
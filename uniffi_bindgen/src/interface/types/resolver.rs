@@ -14,7 +14,7 @@
 
 use anyhow::{bail, Result};
 
-use super::{Type, TypeUniverse};
+use super::{GenericType, Type, TypeUniverse};
 
 /// Trait to help resolving an UDL type node to a [`Type`].
 ///
@@ -121,25 +121,128 @@ impl TypeResolver for weedle::types::SequenceType<'_> {
 
 impl TypeResolver for weedle::types::RecordType<'_> {
     fn resolve_type_expression(&self, types: &mut TypeUniverse) -> Result<Type> {
-        let t = (&self.generics.body.2).resolve_type_expression(types)?;
-        // Maps always have string keys, make sure the `String` type is known.
-        types.add_known_type(Type::String)?;
-        types.add_known_type(Type::Map(Box::new(t)))
+        // Weedle only parses records with string keys, any others are a [`GenericType::Map`].
+        let key_type = types.add_known_type(Type::String)?;
+        let value_type = (&self.generics.body.2).resolve_type_expression(types)?;
+        resolve_map_type(key_type, value_type, types)
     }
 }
 
+/// Map keys and set elements need to be hashable and comparable for equality, on both
+/// sides of the FFI. The `role` names what the type is being used as, for error messages.
+fn check_hashable(type_: &Type, role: &str) -> Result<()> {
+    match type_ {
+        Type::Float32 | Type::Float64 => bail!("floating point types can't be used as {}s", role),
+        // Kotlin compares `ByteArray`s by reference, so they don't work as keys.
+        Type::Bytes | Type::Sequence(_) | Type::Map(..) | Type::Set(_) | Type::OrderedMap(..) => {
+            bail!("{} can't be used as a {}", type_.canonical_name(), role)
+        }
+        Type::Object(_) | Type::CallbackInterface(_) => {
+            bail!("interfaces can't be used as {}s", role)
+        }
+        // Swift tuples can't be `Hashable`.
+        Type::Tuple(_) => bail!("tuples can't be used as {}s", role),
+        Type::Optional(t) => check_hashable(t, role),
+        _ => Ok(()),
+    }
+}
+
+fn resolve_map_type(key_type: Type, value_type: Type, types: &mut TypeUniverse) -> Result<Type> {
+    check_hashable(&key_type, "map key")?;
+    types.add_known_type(Type::Map(Box::new(key_type), Box::new(value_type)))
+}
+
+fn resolve_set_type(element_type: Type, types: &mut TypeUniverse) -> Result<Type> {
+    check_hashable(&element_type, "set element")?;
+    types.add_known_type(Type::Set(Box::new(element_type)))
+}
+
+/// Ordered map keys also need to sort the same way in every language, so that the bindings
+/// can keep them in order. That rules out anything but integers and strings.
+fn resolve_ordered_map_type(
+    key_type: Type,
+    value_type: Type,
+    types: &mut TypeUniverse,
+) -> Result<Type> {
+    match key_type {
+        Type::UInt8
+        | Type::Int8
+        | Type::UInt16
+        | Type::Int16
+        | Type::UInt32
+        | Type::Int32
+        | Type::UInt64
+        | Type::Int64
+        | Type::String => {
+            types.add_known_type(Type::OrderedMap(Box::new(key_type), Box::new(value_type)))
+        }
+        _ => bail!(
+            "{} can't be used as an ordered map key; consider using an integer or string",
+            key_type.canonical_name()
+        ),
+    }
+}
+
+/// Tuples are limited to what Kotlin can represent as a `Pair` or `Triple`.
+fn resolve_tuple_type(element_types: Vec<Type>, types: &mut TypeUniverse) -> Result<Type> {
+    if !(2..=3).contains(&element_types.len()) {
+        bail!("tuples must have two or three elements; consider using a dictionary instead");
+    }
+    types.add_known_type(Type::Tuple(element_types))
+}
+
 impl TypeResolver for weedle::common::Identifier<'_> {
     fn resolve_type_expression(&self, types: &mut TypeUniverse) -> Result<Type> {
         match resolve_builtin_type(self.0) {
             Some(type_) => types.add_known_type(type_),
             None => match types.get_type_definition(self.0) {
                 Some(type_) => types.add_known_type(type_),
-                None => bail!("unknown type reference: {}", self.0),
+                None => match types.get_generic_type(self.0) {
+                    Some(generic) => generic.resolve_type_expression(types),
+                    None => bail!("unknown type reference: {}", self.0),
+                },
             },
         }
     }
 }
 
+impl TypeResolver for GenericType {
+    fn resolve_type_expression(&self, types: &mut TypeUniverse) -> Result<Type> {
+        match self {
+            GenericType::Map(key, value) => {
+                let key_type = resolve_type_text(key, types)?;
+                let value_type = resolve_type_text(value, types)?;
+                resolve_map_type(key_type, value_type, types)
+            }
+            GenericType::Tuple(elements) => {
+                let element_types = elements
+                    .iter()
+                    .map(|element| resolve_type_text(element, types))
+                    .collect::<Result<Vec<_>>>()?;
+                resolve_tuple_type(element_types, types)
+            }
+            GenericType::Set(element) => {
+                let element_type = resolve_type_text(element, types)?;
+                resolve_set_type(element_type, types)
+            }
+            GenericType::OrderedMap(key, value) => {
+                let key_type = resolve_type_text(key, types)?;
+                let value_type = resolve_type_text(value, types)?;
+                resolve_ordered_map_type(key_type, value_type, types)
+            }
+        }
+    }
+}
+
+/// Parse the text of a type argument to a [`GenericType`], and resolve it.
+fn resolve_type_text(text: &str, types: &mut TypeUniverse) -> Result<Type> {
+    use weedle::Parse;
+    match weedle::types::Type::parse(text) {
+        Ok((rest, type_)) if rest.trim().is_empty() => type_.resolve_type_expression(types),
+        _ => bail!("can't parse type argument: {}", text.trim()),
+    }
+}
+
 impl TypeResolver for weedle::term::Boolean {
     fn resolve_type_expression(&self, types: &mut TypeUniverse) -> Result<Type> {
         types.add_known_type(Type::Boolean)
@@ -164,6 +267,76 @@ impl TypeResolver for weedle::types::DoubleType {
     }
 }
 
+/// Types from proc-macro metadata are resolved in the same way as those from the UDL,
+/// except that we get the builtin types directly rather than looking them up by name.
+impl TypeResolver for &uniffi_meta::Type {
+    fn resolve_type_expression(&self, types: &mut TypeUniverse) -> Result<Type> {
+        use uniffi_meta::Type as Ty;
+        let type_ = match self {
+            Ty::U8 => Type::UInt8,
+            Ty::U16 => Type::UInt16,
+            Ty::U32 => Type::UInt32,
+            Ty::U64 => Type::UInt64,
+            Ty::I8 => Type::Int8,
+            Ty::I16 => Type::Int16,
+            Ty::I32 => Type::Int32,
+            Ty::I64 => Type::Int64,
+            Ty::U128 => Type::UInt128,
+            Ty::I128 => Type::Int128,
+            Ty::F32 => Type::Float32,
+            Ty::F64 => Type::Float64,
+            Ty::Bool => Type::Boolean,
+            Ty::String => Type::String,
+            Ty::SystemTime => Type::Timestamp,
+            Ty::Duration => Type::Duration,
+            Ty::Option { inner_type } => Type::Optional(Box::new(
+                inner_type.as_ref().resolve_type_expression(types)?,
+            )),
+            Ty::Vec { inner_type } => Type::Sequence(Box::new(
+                inner_type.as_ref().resolve_type_expression(types)?,
+            )),
+            Ty::HashMap {
+                key_type,
+                value_type,
+            } => {
+                let key_type = key_type.as_ref().resolve_type_expression(types)?;
+                let value_type = value_type.as_ref().resolve_type_expression(types)?;
+                return resolve_map_type(key_type, value_type, types);
+            }
+            Ty::HashSet { inner_type } | Ty::BTreeSet { inner_type } => {
+                let element_type = inner_type.as_ref().resolve_type_expression(types)?;
+                return resolve_set_type(element_type, types);
+            }
+            Ty::BTreeMap {
+                key_type,
+                value_type,
+            } => {
+                let key_type = key_type.as_ref().resolve_type_expression(types)?;
+                let value_type = value_type.as_ref().resolve_type_expression(types)?;
+                return resolve_ordered_map_type(key_type, value_type, types);
+            }
+            Ty::Tuple { element_types } => {
+                let element_types = element_types
+                    .iter()
+                    .map(|t| t.resolve_type_expression(types))
+                    .collect::<Result<Vec<_>>>()?;
+                return resolve_tuple_type(element_types, types);
+            }
+            Ty::ArcObject { object_name } => match types.get_type_definition(object_name) {
+                Some(type_ @ Type::Object(_)) => type_,
+                _ => bail!("unknown object type reference: {}", object_name),
+            },
+            Ty::Unresolved { name } => match types.get_type_definition(name) {
+                // Objects are always passed around in an `Arc`.
+                Some(Type::Object(_)) => bail!("object type {} must be passed as an `Arc`", name),
+                Some(type_) => type_,
+                None => bail!("unknown type reference: {}", name),
+            },
+        };
+        types.add_known_type(type_)
+    }
+}
+
 /// Resolve built-in API types by name.
 ///
 /// Given an identifier from the UDL, this will return `Some(Type)` if it names one of the
@@ -171,6 +344,7 @@ impl TypeResolver for weedle::types::DoubleType {
 pub(in super::super) fn resolve_builtin_type(name: &str) -> Option<Type> {
     match name {
         "string" => Some(Type::String),
+        "bytes" => Some(Type::Bytes),
         "u8" => Some(Type::UInt8),
         "i8" => Some(Type::Int8),
         "u16" => Some(Type::UInt16),
@@ -179,6 +353,8 @@ pub(in super::super) fn resolve_builtin_type(name: &str) -> Option<Type> {
         "i32" => Some(Type::Int32),
         "u64" => Some(Type::UInt64),
         "i64" => Some(Type::Int64),
+        "u128" => Some(Type::UInt128),
+        "i128" => Some(Type::Int128),
         "f32" => Some(Type::Float32),
         "f64" => Some(Type::Float64),
         "timestamp" => Some(Type::Timestamp),
@@ -213,6 +389,42 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_metadata_type_resolution() -> Result<()> {
+        let mut types = TypeUniverse::default();
+        types.add_type_definition("TestRecord", Type::Record("TestRecord".into()))?;
+        types.add_type_definition("TestObject", Type::Object("TestObject".into()))?;
+
+        let t = types.resolve_type_expression(&uniffi_meta::Type::Vec {
+            inner_type: Box::new(uniffi_meta::Type::Unresolved {
+                name: "TestRecord".into(),
+            }),
+        })?;
+        assert_eq!(t.canonical_name(), "SequenceTypeTestRecord");
+
+        let t = types.resolve_type_expression(&uniffi_meta::Type::ArcObject {
+            object_name: "TestObject".into(),
+        })?;
+        assert!(matches!(t, Type::Object(nm) if nm == "TestObject"));
+
+        let e = types
+            .resolve_type_expression(&uniffi_meta::Type::Unresolved {
+                name: "TestObject".into(),
+            })
+            .unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "object type TestObject must be passed as an `Arc`"
+        );
+        let e = types
+            .resolve_type_expression(&uniffi_meta::Type::Unresolved {
+                name: "Missing".into(),
+            })
+            .unwrap_err();
+        assert_eq!(e.to_string(), "unknown type reference: Missing");
+        Ok(())
+    }
+
     #[test]
     fn test_resolving_optional_type_adds_inner_type() {
         let mut types = TypeUniverse::default();
@@ -246,16 +458,16 @@ mod test {
     }
 
     #[test]
-    fn test_resolving_map_type_adds_string_and_inner_type() {
+    fn test_resolving_map_type_adds_key_and_value_types() {
         let mut types = TypeUniverse::default();
         assert_eq!(types.iter_known_types().count(), 0);
         let (_, expr) = weedle::types::Type::parse("record<DOMString, float>").unwrap();
         let t = types.resolve_type_expression(expr).unwrap();
-        assert_eq!(t.canonical_name(), "Mapf32");
+        assert_eq!(t.canonical_name(), "Mapstringf32");
         assert_eq!(types.iter_known_types().count(), 3);
         assert!(types
             .iter_known_types()
-            .any(|t| t.canonical_name() == "Mapf32"));
+            .any(|t| t.canonical_name() == "Mapstringf32"));
         assert!(types
             .iter_known_types()
             .any(|t| t.canonical_name() == "string"));
@@ -264,6 +476,57 @@ mod test {
             .any(|t| t.canonical_name() == "f32"));
     }
 
+    #[test]
+    fn test_resolving_map_type_with_non_string_keys() -> Result<()> {
+        let mut types = TypeUniverse::default();
+        types.add_type_definition("TestEnum", Type::Enum("TestEnum".into()))?;
+        let t = types.resolve_type_expression(GenericType::Map("u64".into(), "string".into()))?;
+        assert_eq!(t.canonical_name(), "Mapu64string");
+        assert!(types
+            .iter_known_types()
+            .any(|t| t.canonical_name() == "u64"));
+        // The placeholder name in the UDL resolves to the generic type it stands for.
+        types.add_generic_type(
+            "map-0",
+            GenericType::Map("TestEnum".into(), "sequence<u8>".into()),
+        );
+        let (_, expr) = weedle::types::Type::parse("map-0?").unwrap();
+        let t = types.resolve_type_expression(expr)?;
+        assert_eq!(
+            t,
+            Type::Optional(Box::new(Type::Map(
+                Box::new(Type::Enum("TestEnum".into())),
+                Box::new(Type::Sequence(Box::new(Type::UInt8)))
+            )))
+        );
+        let err = types
+            .resolve_type_expression(GenericType::Map("u8".into(), "sequence<".into()))
+            .unwrap_err();
+        assert_eq!(err.to_string(), "can't parse type argument: sequence<");
+        Ok(())
+    }
+
+    #[test]
+    fn test_error_on_unhashable_map_keys() -> Result<()> {
+        let mut types = TypeUniverse::default();
+        types.add_type_definition("TestObject", Type::Object("TestObject".into()))?;
+        let mut resolve_map = |key: &str| {
+            types.resolve_type_expression(GenericType::Map(key.into(), "string".into()))
+        };
+        let err = resolve_map("double").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "floating point types can't be used as map keys"
+        );
+        let err = resolve_map("sequence<u8>").unwrap_err();
+        assert_eq!(err.to_string(), "Sequenceu8 can't be used as a map key");
+        let err = resolve_map("bytes").unwrap_err();
+        assert_eq!(err.to_string(), "bytes can't be used as a map key");
+        let err = resolve_map("TestObject").unwrap_err();
+        assert_eq!(err.to_string(), "interfaces can't be used as map keys");
+        Ok(())
+    }
+
     #[test]
     fn test_error_on_unknown_type() -> Result<()> {
         let mut types = TypeUniverse::default();
@@ -285,6 +548,120 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_resolving_tuple_types() -> Result<()> {
+        let mut types = TypeUniverse::default();
+        types.add_type_definition("TestRecord", Type::Record("TestRecord".into()))?;
+        types.add_generic_type(
+            "tuple-0",
+            GenericType::Tuple(vec!["u8".into(), "string".into()]),
+        );
+        let t = types.resolve_type_expression(GenericType::Tuple(vec![
+            "TestRecord".into(),
+            "tuple-0?".into(),
+        ]))?;
+        assert_eq!(
+            t.canonical_name(),
+            "Tuple2TypeTestRecordOptionalTuple2u8string"
+        );
+        assert_eq!(types.iter_known_types().count(), 6);
+
+        let t = types.resolve_type_expression(&uniffi_meta::Type::Tuple {
+            element_types: vec![uniffi_meta::Type::Bool, uniffi_meta::Type::U8],
+        })?;
+        assert_eq!(t.canonical_name(), "Tuple2boolu8");
+        Ok(())
+    }
+
+    #[test]
+    fn test_error_on_bad_tuple_types() -> Result<()> {
+        let mut types = TypeUniverse::default();
+        let err = types
+            .resolve_type_expression(GenericType::Tuple(vec!["u8".into(); 4]))
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "tuples must have two or three elements; consider using a dictionary instead"
+        );
+        types.add_generic_type(
+            "tuple-0",
+            GenericType::Tuple(vec!["u8".into(), "u8".into()]),
+        );
+        let err = types
+            .resolve_type_expression(GenericType::Map("tuple-0".into(), "string".into()))
+            .unwrap_err();
+        assert_eq!(err.to_string(), "tuples can't be used as map keys");
+        let err = types
+            .resolve_type_expression(&uniffi_meta::Type::HashMap {
+                key_type: Box::new(uniffi_meta::Type::Tuple {
+                    element_types: vec![uniffi_meta::Type::U8, uniffi_meta::Type::U8],
+                }),
+                value_type: Box::new(uniffi_meta::Type::String),
+            })
+            .unwrap_err();
+        assert_eq!(err.to_string(), "tuples can't be used as map keys");
+        Ok(())
+    }
+
+    #[test]
+    fn test_resolving_set_and_ordered_map_types() -> Result<()> {
+        let mut types = TypeUniverse::default();
+        types.add_type_definition("TestEnum", Type::Enum("TestEnum".into()))?;
+        types.add_generic_type("set-0", GenericType::Set("TestEnum".into()));
+        let (_, expr) = weedle::types::Type::parse("set-0?").unwrap();
+        let t = types.resolve_type_expression(expr)?;
+        assert_eq!(t.canonical_name(), "OptionalSetTypeTestEnum");
+        types.add_generic_type("set-1", GenericType::Set("string".into()));
+        let t =
+            types.resolve_type_expression(GenericType::OrderedMap("u32".into(), "set-1".into()))?;
+        assert_eq!(
+            t,
+            Type::OrderedMap(
+                Box::new(Type::UInt32),
+                Box::new(Type::Set(Box::new(Type::String)))
+            )
+        );
+
+        let t = types.resolve_type_expression(&uniffi_meta::Type::BTreeSet {
+            inner_type: Box::new(uniffi_meta::Type::U8),
+        })?;
+        assert_eq!(t.canonical_name(), "Setu8");
+        let t = types.resolve_type_expression(&uniffi_meta::Type::BTreeMap {
+            key_type: Box::new(uniffi_meta::Type::String),
+            value_type: Box::new(uniffi_meta::Type::Bool),
+        })?;
+        assert_eq!(t.canonical_name(), "OrderedMapstringbool");
+        Ok(())
+    }
+
+    #[test]
+    fn test_error_on_bad_set_and_ordered_map_types() -> Result<()> {
+        let mut types = TypeUniverse::default();
+        types.add_type_definition("TestRecord", Type::Record("TestRecord".into()))?;
+        let err = types
+            .resolve_type_expression(GenericType::Set("double".into()))
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "floating point types can't be used as set elements"
+        );
+        let err = types
+            .resolve_type_expression(GenericType::Set("bytes?".into()))
+            .unwrap_err();
+        assert_eq!(err.to_string(), "bytes can't be used as a set element");
+        let err = types
+            .resolve_type_expression(GenericType::OrderedMap(
+                "TestRecord".into(),
+                "string".into(),
+            ))
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "TypeTestRecord can't be used as an ordered map key; consider using an integer or string"
+        );
+        Ok(())
+    }
+
     #[test]
     fn test_type_set_is_well_ordered() -> Result<()> {
         // The set (universe) of types should have a well-defined order. When
@@ -108,6 +108,7 @@ use std::{
 
 pub mod bindings;
 pub mod interface;
+pub mod macro_metadata;
 pub mod scaffolding;
 
 use bindings::TargetLanguage;
@@ -125,12 +126,20 @@ pub fn generate_component_scaffolding<P: AsRef<Path>>(
     let config_file_override = config_file_override.as_ref().map(|p| p.as_ref());
     let out_dir_override = out_dir_override.as_ref().map(|p| p.as_ref());
     let udl_file = udl_file.as_ref();
-    let component = parse_udl(udl_file)?;
-    let _config = get_config(
-        &component,
-        guess_crate_root(udl_file)?,
-        config_file_override,
-    );
+    let crate_root = guess_crate_root(udl_file)?;
+    let udl =
+        slurp_file(udl_file).with_context(|| format!("Failed to read UDL from {:?}", &udl_file))?;
+    let component = parse_udl_contents(&udl)?;
+    let _config = get_config(&component, crate_root, config_file_override);
+    // Embed the UDL and config in the library, so that the bindings can be generated from it.
+    let udl_metadata = uniffi_meta::UdlMetadata {
+        crate_name: get_crate_name(crate_root)?,
+        namespace: component.namespace().to_string(),
+        udl,
+        config: find_config_file(crate_root, config_file_override)
+            .map(|path| slurp_file(&path))
+            .transpose()?,
+    };
     let mut filename = Path::new(&udl_file)
         .file_stem()
         .ok_or_else(|| anyhow!("not a file"))?
@@ -140,7 +149,7 @@ pub fn generate_component_scaffolding<P: AsRef<Path>>(
     out_dir.push(filename);
     let mut f =
         File::create(&out_dir).map_err(|e| anyhow!("Failed to create output file: {:?}", e))?;
-    write!(f, "{}", RustScaffolding::new(&component))
+    write!(f, "{}", RustScaffolding::new(&component, &udl_metadata))
         .map_err(|e| anyhow!("Failed to write output file: {:?}", e))?;
     if format_code {
         Command::new("rustfmt").arg(&out_dir).status()?;
@@ -150,23 +159,32 @@ pub fn generate_component_scaffolding<P: AsRef<Path>>(
 
 // Generate the bindings in the target languages that call the scaffolding
 // Rust code.
+//
+// If `library_file` is given, any items exported from the crate with procedural
+// macros are read from the compiled library and included in the bindings.
 pub fn generate_bindings<P: AsRef<Path>>(
     udl_file: P,
     config_file_override: Option<P>,
     target_languages: Vec<&str>,
     out_dir_override: Option<P>,
+    library_file: Option<P>,
     try_format_code: bool,
 ) -> Result<()> {
     let out_dir_override = out_dir_override.as_ref().map(|p| p.as_ref());
     let config_file_override = config_file_override.as_ref().map(|p| p.as_ref());
+    let library_file = library_file.as_ref().map(|p| p.as_ref());
     let udl_file = udl_file.as_ref();
+    let crate_root = guess_crate_root(udl_file)?;
 
-    let component = parse_udl(udl_file)?;
-    let config = get_config(
-        &component,
-        guess_crate_root(udl_file)?,
-        config_file_override,
-    )?;
+    let mut component = parse_udl(udl_file)?;
+    if let Some(library_file) = library_file {
+        macro_metadata::add_to_ci_from_library(
+            &mut component,
+            library_file,
+            &get_crate_name(crate_root)?,
+        )?;
+    }
+    let config = get_config(&component, crate_root, config_file_override)?;
     let out_dir = get_out_dir(&udl_file, out_dir_override)?;
     for language in target_languages {
         bindings::write_bindings(
@@ -180,6 +198,51 @@ pub fn generate_bindings<P: AsRef<Path>>(
     Ok(())
 }
 
+// Generate the bindings in the target languages for every component in a compiled library.
+//
+// This doesn't need the source of the components: the scaffolding for each one embeds its
+// UDL and `uniffi.toml` in the library, along with the metadata for any items exported with
+// procedural macros, so we generate exactly the same bindings as we would from the UDL.
+pub fn generate_bindings_from_library<P: AsRef<Path>>(
+    library_file: P,
+    config_file_override: Option<P>,
+    target_languages: Vec<&str>,
+    out_dir: P,
+    try_format_code: bool,
+) -> Result<()> {
+    let library_file = library_file.as_ref();
+    let config_file_override = config_file_override.as_ref().map(|p| p.as_ref());
+    let out_dir = out_dir.as_ref();
+    std::fs::create_dir_all(out_dir)?;
+
+    let components = macro_metadata::components_from_library(library_file)?;
+    if components.is_empty() {
+        bail!("No uniffi components found in {:?}", library_file);
+    }
+    for (udl_metadata, component) in components {
+        let config_contents = match config_file_override {
+            Some(path) => Some(
+                slurp_file(path)
+                    .with_context(|| format!("Failed to read config file from {:?}", path))?,
+            ),
+            None => udl_metadata.config.clone(),
+        };
+        let config = parse_config(&component, config_contents.as_deref()).with_context(|| {
+            format!("Failed to generate config for {}", udl_metadata.crate_name)
+        })?;
+        for language in &target_languages {
+            bindings::write_bindings(
+                &config.bindings,
+                &component,
+                out_dir,
+                (*language).try_into()?,
+                try_format_code,
+            )?;
+        }
+    }
+    Ok(())
+}
+
 // Run tests against the foreign language bindings (generated and compiled at the same time).
 // Note that the cdylib we're testing against must be built already.
 pub fn run_tests<P: AsRef<Path>>(
@@ -214,7 +277,18 @@ pub fn run_tests<P: AsRef<Path>>(
     for (lang, test_scripts) in language_tests {
         for udl_file in udl_files {
             let crate_root = guess_crate_root(Path::new(udl_file))?;
-            let component = parse_udl(Path::new(udl_file))?;
+            let mut component = parse_udl(Path::new(udl_file))?;
+            // Pick up any items exported with procedural macros from the cdylib itself.
+            let crate_name = get_crate_name(crate_root)?;
+            let library_file = cdylib_dir.join(format!(
+                "{}{}{}",
+                env::consts::DLL_PREFIX,
+                crate_name,
+                env::consts::DLL_SUFFIX
+            ));
+            if library_file.exists() {
+                macro_metadata::add_to_ci_from_library(&mut component, &library_file, &crate_name)?;
+            }
             let config = get_config(&component, crate_root, config_file_override)?;
             bindings::write_bindings(&config.bindings, &component, &cdylib_dir, lang, true)?;
             bindings::compile_bindings(&config.bindings, &component, &cdylib_dir, lang)?;
@@ -243,24 +317,51 @@ fn guess_crate_root(udl_file: &Path) -> Result<&Path> {
     Ok(path_guess)
 }
 
+/// Get the name of the crate's library target, as seen by the compiler.
+///
+/// This is the name that procedural macros record in their metadata, and the
+/// basename of the compiled cdylib.
+fn get_crate_name(crate_root: &Path) -> Result<String> {
+    let manifest_path = crate_root.join("Cargo.toml");
+    let manifest: toml::Value = toml::de::from_str(&slurp_file(&manifest_path)?)
+        .with_context(|| format!("Failed to parse {:?}", &manifest_path))?;
+    let name = manifest
+        .get("lib")
+        .and_then(|lib| lib.get("name"))
+        .or_else(|| manifest.get("package").and_then(|pkg| pkg.get("name")))
+        .and_then(|name| name.as_str())
+        .ok_or_else(|| anyhow!("Failed to find the crate name in {:?}", &manifest_path))?;
+    Ok(name.replace('-', "_"))
+}
+
 fn get_config(
     component: &ComponentInterface,
     crate_root: &Path,
     config_file_override: Option<&Path>,
 ) -> Result<Config> {
-    let default_config: Config = component.into();
+    match find_config_file(crate_root, config_file_override) {
+        Some(path) => {
+            let contents = slurp_file(&path)
+                .with_context(|| format!("Failed to read config file from {:?}", &path))?;
+            parse_config(component, Some(&contents))
+                .with_context(|| format!("Failed to generate config from file {:?}", &path))
+        }
+        None => parse_config(component, None),
+    }
+}
 
-    let config_file: Option<PathBuf> = match config_file_override {
+fn find_config_file(crate_root: &Path, config_file_override: Option<&Path>) -> Option<PathBuf> {
+    match config_file_override {
         Some(cfg) => Some(PathBuf::from(cfg)),
         None => crate_root.join("uniffi.toml").canonicalize().ok(),
-    };
+    }
+}
 
-    match config_file {
-        Some(path) => {
-            let contents = slurp_file(&path)
-                .with_context(|| format!("Failed to read config file from {:?}", &path))?;
-            let loaded_config: Config = toml::de::from_str(&contents)
-                .with_context(|| format!("Failed to generate config from file {:?}", &path))?;
+fn parse_config(component: &ComponentInterface, contents: Option<&str>) -> Result<Config> {
+    let default_config: Config = component.into();
+    match contents {
+        Some(contents) => {
+            let loaded_config: Config = toml::de::from_str(contents)?;
             Ok(loaded_config.merge_with(&default_config))
         }
         None => Ok(default_config),
@@ -285,6 +386,10 @@ fn get_out_dir(udl_file: &Path, out_dir_override: Option<&Path>) -> Result<PathB
 fn parse_udl(udl_file: &Path) -> Result<ComponentInterface> {
     let udl =
         slurp_file(udl_file).map_err(|_| anyhow!("Failed to read UDL from {:?}", &udl_file))?;
+    parse_udl_contents(&udl)
+}
+
+fn parse_udl_contents(udl: &str) -> Result<ComponentInterface> {
     udl.parse::<interface::ComponentInterface>()
         .map_err(|e| anyhow!("Failed to parse UDL: {}", e))
 }
@@ -363,7 +468,21 @@ pub fn run_main() -> Result<()> {
                         .long("--no-format")
                         .help("Do not try to format the generated bindings"),
                 )
-                .arg(clap::Arg::with_name("udl_file").required(true))
+                .arg(
+                    clap::Arg::with_name("lib_file")
+                        .long("--lib-file")
+                        .takes_value(true)
+                        .help("Path to the compiled library, for reading items exported with procedural macros."),
+                )
+                .arg(
+                    clap::Arg::with_name("library")
+                        .long("--library")
+                        .takes_value(true)
+                        .conflicts_with_all(&["udl_file", "lib_file"])
+                        .requires("out_dir")
+                        .help("Generate bindings for every component in this compiled library, instead of from a UDL file."),
+                )
+                .arg(clap::Arg::with_name("udl_file").required_unless("library"))
                 .arg(
                     clap::Arg::with_name("config")
                     .long("--config-path")
@@ -415,13 +534,23 @@ pub fn run_main() -> Result<()> {
         )
         .get_matches();
     match matches.subcommand() {
-        ("generate", Some(m)) => crate::generate_bindings(
-            m.value_of_os("udl_file").unwrap(), // Required
-            m.value_of_os("config"),
-            m.values_of("language").unwrap().collect(), // Required
-            m.value_of_os("out_dir"),
-            !m.is_present("no_format"),
-        )?,
+        ("generate", Some(m)) => match m.value_of_os("library") {
+            Some(library) => crate::generate_bindings_from_library(
+                library,
+                m.value_of_os("config"),
+                m.values_of("language").unwrap().collect(), // Required
+                m.value_of_os("out_dir").unwrap(),          // Required with `--library`
+                !m.is_present("no_format"),
+            )?,
+            None => crate::generate_bindings(
+                m.value_of_os("udl_file").unwrap(), // Required without `--library`
+                m.value_of_os("config"),
+                m.values_of("language").unwrap().collect(), // Required
+                m.value_of_os("out_dir"),
+                m.value_of_os("lib_file"),
+                !m.is_present("no_format"),
+            )?,
+        },
         ("scaffolding", Some(m)) => crate::generate_component_scaffolding(
             m.value_of_os("udl_file").unwrap(), // Required
             m.value_of_os("config"),
@@ -0,0 +1,192 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! # Reading proc-macro metadata from a compiled library.
+//!
+//! Items defined with attributes such as `#[uniffi::export]` don't appear in the UDL.
+//! Instead, the macros store metadata about each one in a `#[no_mangle]` static in the
+//! compiled library, named with [`uniffi_meta::METADATA_SYMBOL_PREFIX`] and holding the
+//! item serialized as JSON. This module finds those statics in a cdylib or staticlib
+//! and deserializes them, so the items can be added to a `ComponentInterface`.
+//!
+//! The scaffolding generated from a UDL file embeds the UDL in the same way, which
+//! lets us rebuild the complete `ComponentInterface` for every component in a library.
+
+use anyhow::{bail, Context, Result};
+use goblin::{
+    archive::Archive,
+    elf::Elf,
+    mach::{Mach, MachO},
+    pe::PE,
+    Object,
+};
+use std::{fs, path::Path};
+use uniffi_meta::{Metadata, UdlMetadata, METADATA_SYMBOL_PREFIX};
+
+use crate::interface::ComponentInterface;
+
+/// Add the items for the given crate from the metadata in a compiled library to a `ComponentInterface`.
+pub fn add_to_ci_from_library(
+    ci: &mut ComponentInterface,
+    library_path: &Path,
+    crate_name: &str,
+) -> Result<()> {
+    let metadata = extract_from_library(library_path)?;
+    add_to_ci(ci, metadata, crate_name)
+        .with_context(|| format!("Failed to add the metadata from {:?}", library_path))
+}
+
+/// Build the `ComponentInterface` for every component in a compiled library.
+///
+/// Each component is returned along with its embedded UDL metadata, which holds the
+/// name of its crate and the contents of its `uniffi.toml`.
+pub fn components_from_library(
+    library_path: &Path,
+) -> Result<Vec<(UdlMetadata, ComponentInterface)>> {
+    let metadata = extract_from_library(library_path)?;
+    metadata
+        .iter()
+        .filter_map(|meta| match meta {
+            Metadata::Udl(udl_metadata) => Some(udl_metadata),
+            _ => None,
+        })
+        .map(|udl_metadata| {
+            let mut ci: ComponentInterface = udl_metadata.udl.parse().with_context(|| {
+                format!("Failed to parse the UDL for {}", udl_metadata.crate_name)
+            })?;
+            add_to_ci(&mut ci, metadata.clone(), &udl_metadata.crate_name).with_context(|| {
+                format!("Failed to add the metadata for {}", udl_metadata.crate_name)
+            })?;
+            Ok((udl_metadata.clone(), ci))
+        })
+        .collect()
+}
+
+fn add_to_ci(ci: &mut ComponentInterface, metadata: Vec<Metadata>, crate_name: &str) -> Result<()> {
+    ci.add_metadata(
+        metadata
+            .into_iter()
+            .filter(|meta| !matches!(meta, Metadata::Udl(_)) && meta.crate_name() == crate_name)
+            .collect(),
+    )
+}
+
+/// Extract all the metadata from a compiled library.
+pub fn extract_from_library(path: &Path) -> Result<Vec<Metadata>> {
+    let data = fs::read(path).with_context(|| format!("Failed to read library {:?}", path))?;
+    extract_from_bytes(&data).with_context(|| format!("Failed to parse library {:?}", path))
+}
+
+fn extract_from_bytes(data: &[u8]) -> Result<Vec<Metadata>> {
+    let mut metadata = match Object::parse(data)? {
+        Object::Elf(elf) => extract_from_elf(&elf, data)?,
+        Object::PE(pe) => extract_from_pe(&pe, data)?,
+        Object::Mach(Mach::Binary(macho)) => extract_from_macho(&macho, data)?,
+        Object::Archive(archive) => extract_from_archive(&archive, data)?,
+        Object::Mach(Mach::Fat(_)) => {
+            bail!("Multi-architecture libraries are not supported, please use a single-architecture library")
+        }
+        Object::Unknown(magic) => bail!("Unknown library format (magic: {:#x})", magic),
+    };
+    // The same item may be found more than once, e.g. in both the static and dynamic symbol tables.
+    // Sort them so that the resulting interface doesn't depend on the order of the symbols.
+    metadata.sort_by_key(Metadata::symbol_name);
+    metadata.dedup();
+    Ok(metadata)
+}
+
+fn extract_from_elf(elf: &Elf<'_>, data: &[u8]) -> Result<Vec<Metadata>> {
+    let mut metadata = Vec::new();
+    let symbols = elf
+        .dynsyms
+        .iter()
+        .filter_map(|sym| Some((elf.dynstrtab.get_at(sym.st_name)?, sym)))
+        .chain(
+            elf.syms
+                .iter()
+                .filter_map(|sym| Some((elf.strtab.get_at(sym.st_name)?, sym))),
+        );
+    for (name, sym) in symbols {
+        if !is_metadata_symbol(name) {
+            continue;
+        }
+        let section = match elf.section_headers.get(sym.st_shndx) {
+            Some(section) => section,
+            None => bail!("Metadata symbol {} has an invalid section", name),
+        };
+        let offset = sym.st_value - section.sh_addr + section.sh_offset;
+        metadata.push(parse_metadata(name, data, offset as usize)?);
+    }
+    Ok(metadata)
+}
+
+fn extract_from_macho(macho: &MachO<'_>, data: &[u8]) -> Result<Vec<Metadata>> {
+    let mut sections = Vec::new();
+    for section_iter in macho.segments.sections() {
+        for section in section_iter {
+            sections.push(section?.0);
+        }
+    }
+    let mut metadata = Vec::new();
+    for symbol in macho.symbols() {
+        let (name, nlist) = symbol?;
+        // Mach-O symbol names are prefixed with an underscore.
+        let name = name.strip_prefix('_').unwrap_or(name);
+        if !is_metadata_symbol(name) {
+            continue;
+        }
+        // Section numbers are 1-based, with zero meaning "no section".
+        let section = match nlist.n_sect.checked_sub(1).and_then(|i| sections.get(i)) {
+            Some(section) => section,
+            None => bail!("Metadata symbol {} has an invalid section", name),
+        };
+        let offset = nlist.n_value - section.addr + section.offset as u64;
+        metadata.push(parse_metadata(name, data, offset as usize)?);
+    }
+    Ok(metadata)
+}
+
+fn extract_from_pe(pe: &PE<'_>, data: &[u8]) -> Result<Vec<Metadata>> {
+    let mut metadata = Vec::new();
+    for export in pe.exports.iter() {
+        if let Some(name) = export.name {
+            if is_metadata_symbol(name) {
+                metadata.push(parse_metadata(name, data, export.offset)?);
+            }
+        }
+    }
+    Ok(metadata)
+}
+
+fn extract_from_archive(archive: &Archive<'_>, data: &[u8]) -> Result<Vec<Metadata>> {
+    let mut metadata = Vec::new();
+    for member in archive.members() {
+        // Archives may contain things other than object files, which we can safely ignore.
+        let member_data = archive.extract(member, data)?;
+        match Object::parse(member_data) {
+            Ok(Object::Unknown(_)) | Err(_) => continue,
+            Ok(_) => metadata.extend(extract_from_bytes(member_data)?),
+        }
+    }
+    Ok(metadata)
+}
+
+fn is_metadata_symbol(name: &str) -> bool {
+    name.starts_with(METADATA_SYMBOL_PREFIX)
+}
+
+fn parse_metadata(name: &str, data: &[u8], offset: usize) -> Result<Metadata> {
+    let bytes = match data.get(offset..) {
+        Some(bytes) => bytes,
+        None => bail!("Metadata symbol {} is outside the library", name),
+    };
+    // The symbol holds exactly one JSON value, so we don't need to know its size.
+    let mut stream = serde_json::Deserializer::from_slice(bytes).into_iter::<Metadata>();
+    match stream.next() {
+        Some(metadata) => {
+            metadata.with_context(|| format!("Failed to deserialize metadata symbol {}", name))
+        }
+        None => bail!("Metadata symbol {} is empty", name),
+    }
+}
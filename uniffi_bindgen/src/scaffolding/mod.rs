@@ -13,12 +13,35 @@ use heck::SnakeCase;
 pub struct RustScaffolding<'a> {
     ci: &'a ComponentInterface,
     uniffi_version: &'static str,
+    udl_metadata: EmbeddedMetadata,
 }
 impl<'a> RustScaffolding<'a> {
-    pub fn new(ci: &'a ComponentInterface) -> Self {
+    pub fn new(ci: &'a ComponentInterface, udl_metadata: &uniffi_meta::UdlMetadata) -> Self {
         Self {
             ci,
             uniffi_version: crate::BINDGEN_VERSION,
+            udl_metadata: EmbeddedMetadata::new(uniffi_meta::Metadata::Udl(udl_metadata.clone())),
+        }
+    }
+}
+
+/// Metadata to be embedded in the compiled library, rendered as a byte string literal.
+struct EmbeddedMetadata {
+    symbol_name: String,
+    len: usize,
+    bytes: String,
+}
+impl EmbeddedMetadata {
+    fn new(metadata: uniffi_meta::Metadata) -> Self {
+        let data = serde_json::to_vec(&metadata).expect("Failed to serialize uniffi metadata");
+        Self {
+            symbol_name: metadata.symbol_name(),
+            len: data.len(),
+            bytes: data
+                .into_iter()
+                .flat_map(std::ascii::escape_default)
+                .map(char::from)
+                .collect(),
         }
     }
 }
@@ -36,18 +59,43 @@ mod filters {
             Type::UInt32 => "u32".into(),
             Type::Int64 => "i64".into(),
             Type::UInt64 => "u64".into(),
+            Type::Int128 => "i128".into(),
+            Type::UInt128 => "u128".into(),
             Type::Float32 => "f32".into(),
             Type::Float64 => "f64".into(),
             Type::Boolean => "bool".into(),
             Type::String => "String".into(),
+            Type::Bytes => "std::vec::Vec<u8>".into(),
             Type::Timestamp => "std::time::SystemTime".into(),
             Type::Duration => "std::time::Duration".into(),
             Type::Enum(name) | Type::Record(name) | Type::Error(name) => name.clone(),
-            Type::Object(name) => format!("std::sync::Arc<{}>", name),
+            Type::Flags { name, .. } => name.clone(),
+            // The implementation of an object may be a struct or a trait, which its FfiConverter knows.
+            Type::Object(_) => format!(
+                "<{} as uniffi::FfiConverter>::RustType",
+                ffi_converter_name(type_)?
+            ),
             Type::CallbackInterface(name) => format!("Box<dyn {}>", name),
             Type::Optional(t) => format!("std::option::Option<{}>", type_rs(t)?),
             Type::Sequence(t) => format!("std::vec::Vec<{}>", type_rs(t)?),
-            Type::Map(t) => format!("std::collections::HashMap<String, {}>", type_rs(t)?),
+            Type::Map(k, v) => format!(
+                "std::collections::HashMap<{}, {}>",
+                type_rs(k)?,
+                type_rs(v)?
+            ),
+            Type::Set(t) => format!("std::collections::HashSet<{}>", type_rs(t)?),
+            Type::OrderedMap(k, v) => format!(
+                "std::collections::BTreeMap<{}, {}>",
+                type_rs(k)?,
+                type_rs(v)?
+            ),
+            Type::Tuple(ts) => format!(
+                "({})",
+                ts.iter()
+                    .map(type_rs)
+                    .collect::<Result<Vec<_>, _>>()?
+                    .join(", ")
+            ),
             Type::External { .. } => panic!("External types coming to a uniffi near you soon!"),
             Type::Wrapped { .. } => panic!("Wrapped types coming to a uniffi near you soon!"),
         })
@@ -69,6 +117,7 @@ mod filters {
             FFIType::RustBuffer => "uniffi::RustBuffer".into(),
             FFIType::ForeignBytes => "uniffi::ForeignBytes".into(),
             FFIType::ForeignCallback => "uniffi::ForeignCallback".into(),
+            FFIType::ForeignWaker => "uniffi::ForeignWaker".into(),
         })
     }
 
@@ -82,30 +131,49 @@ mod filters {
             // Timestamp/Duraration are handled by standard types
             Type::Timestamp => "std::time::SystemTime".into(),
             Type::Duration => "std::time::Duration".into(),
-            // Object is handled by Arc<T>
-            Type::Object(name) => format!("std::sync::Arc<{}>", name),
-            // Other user-defined types are handled by a unit-struct that we generate.  The
+            // `Vec<u8>` is already a sequence, so bytes need their own converter.
+            Type::Bytes => "uniffi::FfiConverterBytes".into(),
+            // User-defined types are handled by a unit-struct that we generate.  The
             // FfiConverter implementation for this can be found in one of the scaffolding template code.
             //
             // We generate a unit-struct to sidestep Rust's orphan rules (ADR-0006).
             //
             // CallbackInterface is handled by special case code on both the scaffolding and
             // bindings side.  It's not a unit-struct, but the same name generation code works.
-            Type::Enum(_) | Type::Record(_) | Type::Error(_) | Type::CallbackInterface(_) => {
+            Type::Enum(_)
+            | Type::Record(_)
+            | Type::Error(_)
+            | Type::Object(_)
+            | Type::CallbackInterface(_) => {
                 format!("FfiConverter{}", type_.canonical_name())
             }
             // Wrapper types are implemented by generics that wrap the FfiConverter implementation of the
             // inner type.
             Type::Optional(inner) => format!("std::option::Option<{}>", ffi_converter_name(inner)?),
             Type::Sequence(inner) => format!("std::vec::Vec<{}>", ffi_converter_name(inner)?),
-            Type::Map(inner) => format!(
-                "std::collections::HashMap<String, {}>",
-                ffi_converter_name(inner)?
+            Type::Map(k, v) => format!(
+                "std::collections::HashMap<{}, {}>",
+                ffi_converter_name(k)?,
+                ffi_converter_name(v)?
+            ),
+            Type::Set(t) => format!("std::collections::HashSet<{}>", ffi_converter_name(t)?),
+            Type::OrderedMap(k, v) => format!(
+                "std::collections::BTreeMap<{}, {}>",
+                ffi_converter_name(k)?,
+                ffi_converter_name(v)?
+            ),
+            Type::Tuple(ts) => format!(
+                "({})",
+                ts.iter()
+                    .map(ffi_converter_name)
+                    .collect::<Result<Vec<_>, _>>()?
+                    .join(", ")
             ),
             // External and Wrapped bytes have FfiConverters with a predictable name based on the type name.
             Type::Wrapped { name, .. } | Type::External { name, .. } => {
                 format!("FfiConverterType{}", name)
             }
+            Type::Flags { .. } => format!("FfiConverter{}", type_.canonical_name()),
             // Primitive types / strings are implemented by their rust type
             Type::Int8 => "i8".into(),
             Type::UInt8 => "u8".into(),
@@ -115,6 +183,8 @@ mod filters {
             Type::UInt32 => "u32".into(),
             Type::Int64 => "i64".into(),
             Type::UInt64 => "u64".into(),
+            Type::Int128 => "i128".into(),
+            Type::UInt128 => "u128".into(),
             Type::Float32 => "f32".into(),
             Type::Float64 => "f64".into(),
             Type::String => "String".into(),
@@ -132,6 +202,63 @@ mod filters {
         ))
     }
 
+    /// Get the FfiConverter for a field of the named record, enum or error.
+    ///
+    /// This is the same as `ffi_converter` except that references back to the containing
+    /// type are boxed, as they must be in the Rust definition of a recursive type.
+    pub fn field_ffi_converter(
+        type_: &Type,
+        ci: &ComponentInterface,
+        parent: &str,
+    ) -> Result<String, askama::Error> {
+        Ok(format!(
+            "<{} as uniffi::FfiConverter>",
+            field_ffi_converter_name(type_, ci, parent)?
+        ))
+    }
+
+    fn field_ffi_converter_name(
+        type_: &Type,
+        ci: &ComponentInterface,
+        parent: &str,
+    ) -> Result<String, askama::Error> {
+        Ok(match type_ {
+            Type::Optional(inner) => format!(
+                "std::option::Option<{}>",
+                field_ffi_converter_name(inner, ci, parent)?
+            ),
+            Type::Tuple(ts) => format!(
+                "({})",
+                ts.iter()
+                    .map(|t| field_ffi_converter_name(t, ci, parent))
+                    .collect::<Result<Vec<_>, _>>()?
+                    .join(", ")
+            ),
+            _ if ci.is_recursive_reference(parent, type_) => {
+                format!("std::boxed::Box<{}>", ffi_converter_name(type_)?)
+            }
+            _ => ffi_converter_name(type_)?,
+        })
+    }
+
+    // Render a literal value from the UDL as a Rust expression, e.g. to check a constant.
+    pub fn literal_rs(literal: &Literal) -> Result<String, askama::Error> {
+        Ok(match literal {
+            Literal::Boolean(v) => format!("{}", v),
+            // `Debug` gives us a Rust string literal with any special characters escaped.
+            Literal::String(s) => format!("{:?}", s),
+            Literal::Int(i, _, _) => format!("{}", i),
+            Literal::UInt(i, _, _) => format!("{}", i),
+            // WebIDL allows floats like `.5`, but Rust needs a digit before the decimal point.
+            Literal::Float(string, _) => match string.strip_prefix('-') {
+                Some(v) if v.starts_with('.') => format!("-0{}", v),
+                None if string.starts_with('.') => format!("0{}", string),
+                _ => string.clone(),
+            },
+            _ => panic!("Unsupported literal in the scaffolding: {:?}", literal),
+        })
+    }
+
     // Turns a `crate-name` into the `crate_name` the .rs code needs to specify.
     pub fn crate_name_rs(nm: &dyn fmt::Display) -> Result<String, askama::Error> {
         Ok(nm.to_string().to_snake_case())
@@ -7,7 +7,8 @@
 //  * a proxy `struct` which implements the `trait` that the Callback Interface corresponds to. This 
 //    is the object that client code interacts with.
 //    - for each method, arguments will be packed into a `RustBuffer` and sent over the `ForeignCallback` to be 
-//      unpacked and called. The return value is packed into another `RustBuffer` and sent back to Rust.
+//      unpacked and called. The return value (or the declared error) is packed into another `RustBuffer`
+//      and sent back to Rust, along with a status code saying which one it is.
 //    - a `Drop` `impl`, which tells the foreign language to forget about the real callback object.
 #}
 {% let trait_name = cbi.name() -%}
@@ -33,8 +34,8 @@ struct {{ trait_impl }} {
 
 impl Drop for {{ trait_impl }} {
     fn drop(&mut self) {
-        let callback = {{ foreign_callback_internals }}.get_callback().unwrap();
-        unsafe { callback(self.handle, uniffi::IDX_CALLBACK_FREE, Default::default()) };
+        // There's nothing useful we can do with a failure here, so we ignore the result.
+        let _ = {{ foreign_callback_internals }}.invoke_callback(self.handle, uniffi::IDX_CALLBACK_FREE, Default::default());
     }
 }
 
@@ -46,10 +47,14 @@ impl {{ trait_name }} for {{ trait_impl }} {
     {#- Method declaration #}
     fn {{ meth.name() -}}
     ({% call rs::arg_list_decl_with_prefix("&self", meth) %})
+    {%- match meth.throws_type() %}
+    {%- when Some with (e) %} -> Result<{% match meth.return_type() %}{% when Some with (return_type) %}{{ return_type|type_rs }}{% else %}(){% endmatch %}, {{ e|type_rs }}>
+    {%- else %}
     {%- match meth.return_type() %}
     {%- when Some with (return_type) %} -> {{ return_type|type_rs }}
-    {% else -%}
-    {%- endmatch -%} { 
+    {%- else %}
+    {%- endmatch %}
+    {%- endmatch %} {
     {#- Method body #}
         uniffi::deps::log::debug!("{{ cbi.name() }}.{{ meth.name() }}");
 
@@ -65,17 +70,34 @@ impl {{ trait_name }} for {{ trait_impl }} {
         let args_rbuf = uniffi::RustBuffer::from_vec(args_buf);
 
     {#- Calling into foreign code. #}
-        let callback = {{ foreign_callback_internals }}.get_callback().unwrap();
-        let ret_rbuf = unsafe { callback(self.handle, {{ loop.index }}, args_rbuf) };
+        let ret_rbuf = match {{ foreign_callback_internals }}.invoke_callback(self.handle, {{ loop.index }}, args_rbuf) {
+            uniffi::CallbackResult::Success(ret_rbuf) => ret_rbuf,
+            {%- match meth.throws_type() %}
+            {%- when Some with (e) %}
+            uniffi::CallbackResult::Error(err_rbuf) => {
+                return Err({{ e|ffi_converter }}::try_lift(err_rbuf).expect("Error reading callback interface error"));
+            }
+            uniffi::CallbackResult::UnexpectedError(err) => return Err(err.into()),
+            {%- else %}
+            uniffi::CallbackResult::Error(err_rbuf) => {
+                err_rbuf.destroy();
+                panic!("Callback interface method {{ cbi.name() }}.{{ meth.name() }} returned an error, but doesn't declare one");
+            }
+            uniffi::CallbackResult::UnexpectedError(err) => {
+                panic!("Callback interface method {{ cbi.name() }}.{{ meth.name() }} failed: {}", err.reason);
+            }
+            {%- endmatch %}
+        };
 
     {#- Unpacking the RustBuffer to return to Rust #}
         {% match meth.return_type() -%}
         {% when Some with (return_type) -%}
         let vec = ret_rbuf.destroy_into_vec();
         let mut ret_buf = vec.as_slice();
-        {{ return_type|ffi_converter }}::try_read(&mut ret_buf).unwrap()
+        {% if meth.throws().is_some() %}Ok({{ return_type|ffi_converter }}::try_read(&mut ret_buf).unwrap()){% else %}{{ return_type|ffi_converter }}::try_read(&mut ret_buf).unwrap(){% endif %}
         {%- else -%}
         uniffi::RustBuffer::destroy(ret_rbuf);
+        {% if meth.throws().is_some() %}Ok(()){% endif %}
         {%- endmatch %}
     }
     {%- endfor %}
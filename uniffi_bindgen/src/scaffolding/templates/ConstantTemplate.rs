@@ -0,0 +1,17 @@
+{#
+// For each constant declared in the UDL, we assume the caller has provided a rust `const` of the
+// same name. We check its type and value at compile time, so that the constants in the foreign
+// language bindings can't silently disagree with it. Like the version check, each assertion has
+// an error message on the same line, since that's the only context the compiler shows if it fails.
+#}
+{%- match constant.type_() %}
+{%- when Type::String %}
+const _: &str = {{ constant.name() }};
+uniffi::deps::static_assertions::const_assert!(uniffi::const_str_eq({{ constant.name() }}, {{ constant.value()|literal_rs }})); // Please check that `{{ constant.name() }}` has the value declared in the UDL.
+{%- when Type::Boolean %}
+const _: bool = {{ constant.name() }};
+uniffi::deps::static_assertions::const_assert!({% if constant.value()|literal_rs == "false" %}!{% endif %}{{ constant.name() }}); // Please check that `{{ constant.name() }}` has the value declared in the UDL.
+{%- else %}
+const _: {{ constant.type_()|type_rs }} = {{ constant.name() }};
+uniffi::deps::static_assertions::const_assert!({{ constant.name() }} == {{ constant.value()|literal_rs }}); // Please check that `{{ constant.name() }}` has the value declared in the UDL.
+{%- endmatch %}
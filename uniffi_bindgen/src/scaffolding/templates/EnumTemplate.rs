@@ -18,9 +18,9 @@ impl uniffi::RustBufferFfiConverter for {{ e.type_()|ffi_converter_name }} {
         match obj {
             {%- for variant in e.variants() %}
             {{ e.name() }}::{{ variant.name() }} { {% for field in variant.fields() %}{{ field.name() }}, {%- endfor %} } => {
-                buf.put_i32({{ loop.index }});
+                buf.put_i32({{ variant.discr() }});
                 {% for field in variant.fields() -%}
-                {{ field.type_()|ffi_converter }}::write({{ field.name() }}, buf);
+                {{ field.type_()|field_ffi_converter(ci, e.name()) }}::write({{ field.name() }}, buf);
                 {%- endfor %}
             },
             {%- endfor %}
@@ -32,9 +32,9 @@ impl uniffi::RustBufferFfiConverter for {{ e.type_()|ffi_converter_name }} {
         uniffi::check_remaining(buf, 4)?;
         Ok(match buf.get_i32() {
             {%- for variant in e.variants() %}
-            {{ loop.index }} => {{ e.name() }}::{{ variant.name() }}{% if variant.has_fields() %} {
+            {{ variant.discr() }} => {{ e.name() }}::{{ variant.name() }}{% if variant.has_fields() %} {
                 {% for field in variant.fields() %}
-                {{ field.name() }}: {{ field.type_()|ffi_converter }}::try_read(buf)?,
+                {{ field.name() }}: {{ field.type_()|field_ffi_converter(ci, e.name()) }}::try_read(buf)?,
                 {%- endfor %}
             }{% endif %},
             {%- endfor %}
@@ -33,7 +33,8 @@ impl uniffi::RustBufferFfiConverter for {{ e.type_()|ffi_converter_name }} {
     }
 
     fn try_read(_buf: &mut &[u8]) -> uniffi::deps::anyhow::Result<{{ e.name() }}> {
-        // It's not currently possible to send errors from the foreign language *into* Rust.
+        // It's not currently possible to send flat errors from the foreign language *into* Rust,
+        // since only their message is known there. Callback interfaces can only throw rich errors.
         panic!("try_read not supported for flat errors");
     }
 
@@ -53,7 +54,7 @@ impl uniffi::RustBufferFfiConverter for {{ e.type_()|ffi_converter_name }} {
             {{ e.name() }}::{{ variant.name() }}{% if variant.has_fields() %} { {% for field in variant.fields() %}{{ field.name() }}, {%- endfor %} }{% else %}{..}{% endif %} => {
                 buf.put_i32({{ loop.index }});
                 {% for field in variant.fields() -%}
-                {{ field.type_()|ffi_converter }}::write({{ field.name() }}, buf);
+                {{ field.type_()|field_ffi_converter(ci, e.name()) }}::write({{ field.name() }}, buf);
                 {%- endfor %}
             },
             {%- endfor %}
@@ -61,15 +62,15 @@ impl uniffi::RustBufferFfiConverter for {{ e.type_()|ffi_converter_name }} {
     }
 
     fn try_read(buf: &mut &[u8]) -> uniffi::deps::anyhow::Result<{{ e.name() }}> {
-        // It's not currently supported to send errors from the foreign language *into* Rust,
-        // but this is what the supporting code might look like...
+        // This is used when errors are thrown by a callback interface implemented in the
+        // foreign language, and sent back *into* Rust.
         use uniffi::deps::bytes::Buf;
         uniffi::check_remaining(buf, 4)?;
         Ok(match buf.get_i32() {
             {%- for variant in e.variants() %}
             {{ loop.index }} => {{ e.name() }}::{{ variant.name() }}{% if variant.has_fields() %} {
                 {% for field in variant.fields() %}
-                {{ field.name() }}: {{ field.type_()|ffi_converter }}::try_read(buf)?,
+                {{ field.name() }}: {{ field.type_()|field_ffi_converter(ci, e.name()) }}::try_read(buf)?,
                 {%- endfor %}
             }{% endif %},
             {%- endfor %}
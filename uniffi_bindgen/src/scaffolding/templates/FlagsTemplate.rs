@@ -0,0 +1,41 @@
+{#
+// For each set of flags declared in the UDL, we assume the caller has provided a `bitflags`-style
+// type with a constant for each flag. It's passed as the integer that holds its bits, and we check
+// the value of each flag at compile time, with the error message on the same line as the assertion.
+//
+// Like enums, the FfiConverter is a public unit-struct, so other crates can refer to it.
+#}
+{%- for flag in f.flags() %}
+uniffi::deps::static_assertions::const_assert!({{ f.name() }}::{{ flag.name() }}.bits() == {{ flag.value() }}); // Please check that `{{ f.name() }}::{{ flag.name() }}` has the value declared in the UDL.
+{%- endfor %}
+
+pub struct {{ f.type_()|ffi_converter_name }};
+
+unsafe impl uniffi::FfiConverter for {{ f.type_()|ffi_converter_name }} {
+    type RustType = {{ f.name() }};
+    type FfiType = {{ f.ffi_type()|type_ffi }};
+
+    fn lower(obj: {{ f.name() }}) -> Self::FfiType {
+        <{{ f.prim()|type_rs }} as uniffi::FfiConverter>::lower(obj.bits())
+    }
+
+    fn try_lift(v: Self::FfiType) -> uniffi::Result<{{ f.name() }}> {
+        let bits = <{{ f.prim()|type_rs }} as uniffi::FfiConverter>::try_lift(v)?;
+        match {{ f.name() }}::from_bits(bits) {
+            Some(obj) => Ok(obj),
+            None => uniffi::deps::anyhow::bail!("Invalid {{ f.name() }} flags: {:#x}", bits),
+        }
+    }
+
+    fn write(obj: {{ f.name() }}, buf: &mut Vec<u8>) {
+        <{{ f.prim()|type_rs }} as uniffi::FfiConverter>::write(obj.bits(), buf);
+    }
+
+    fn try_read(buf: &mut &[u8]) -> uniffi::Result<{{ f.name() }}> {
+        let bits = <{{ f.prim()|type_rs }} as uniffi::FfiConverter>::try_read(buf)?;
+        match {{ f.name() }}::from_bits(bits) {
+            Some(obj) => Ok(obj),
+            None => uniffi::deps::anyhow::bail!("Invalid {{ f.name() }} flags: {:#x}", bits),
+        }
+    }
+}
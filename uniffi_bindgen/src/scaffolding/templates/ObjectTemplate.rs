@@ -6,6 +6,10 @@
 // (Note that "safely" is in "scare quotes" - that's because we use functions on an `Arc` that
 // that are inherently unsafe, but the code we generate is safe in practice.)
 //
+// If the Object is declared with the `[Trait]` attribute, we instead assume the caller has provided
+// a `trait T: Send + Sync` with each method on the object, and hand out references to an `Arc<dyn T>`.
+// That is a "fat" pointer, so it is boxed to give the foreign language code a pointer it can hold.
+//
 // If the caller's implementation of the struct does not match with the methods or types specified
 // in the UDL, then the rust compiler will complain with a (hopefully at least somewhat helpful!)
 // error message when processing this generated code.
@@ -24,14 +28,82 @@
 fn uniffi_note_threadsafe_deprecation_{{ obj.name() }}() {}
 {% endif %}
 
+// Unlike other user-defined types, this isn't `pub`, as the object's own type needn't be.
+#[doc(hidden)]
+struct {{ obj.type_()|ffi_converter_name }};
+
+{% match obj.imp() -%}
+{% when ObjectImpl::Struct -%}
+// The `Arc<T>` is handled by the `FfiConverter` implementation in the uniffi crate.
+unsafe impl uniffi::FfiConverter for {{ obj.type_()|ffi_converter_name }} {
+    type RustType = std::sync::Arc<{{ obj.rust_name() }}>;
+    type FfiType = *const std::os::raw::c_void;
+
+    fn lower(obj: Self::RustType) -> Self::FfiType {
+        <Self::RustType as uniffi::FfiConverter>::lower(obj)
+    }
+
+    fn try_lift(v: Self::FfiType) -> uniffi::deps::anyhow::Result<Self::RustType> {
+        <Self::RustType as uniffi::FfiConverter>::try_lift(v)
+    }
+
+    fn write(obj: Self::RustType, buf: &mut Vec<u8>) {
+        <Self::RustType as uniffi::FfiConverter>::write(obj, buf)
+    }
+
+    fn try_read(buf: &mut &[u8]) -> uniffi::deps::anyhow::Result<Self::RustType> {
+        <Self::RustType as uniffi::FfiConverter>::try_read(buf)
+    }
+}
+{% when ObjectImpl::Trait -%}
+uniffi::deps::static_assertions::assert_impl_all!({{ obj.rust_name() }}: Sync, Send);
+
+// The foreign language code owns a `Box<Arc<dyn T>>`, in the same way that it owns an `Arc<T>`
+// for a struct; see the `FfiConverter` implementation for `Arc<T>` in the uniffi crate.
+unsafe impl uniffi::FfiConverter for {{ obj.type_()|ffi_converter_name }} {
+    type RustType = std::sync::Arc<{{ obj.rust_name() }}>;
+    type FfiType = *const std::os::raw::c_void;
+
+    fn lower(obj: Self::RustType) -> Self::FfiType {
+        Box::into_raw(Box::new(obj)) as Self::FfiType
+    }
+
+    fn try_lift(v: Self::FfiType) -> uniffi::deps::anyhow::Result<Self::RustType> {
+        // We musn't drop the `Box` that is owned by the foreign-language code.
+        let foreign_arc = unsafe { &*(v as *const Self::RustType) };
+        // Take a clone for our own use.
+        Ok(std::sync::Arc::clone(foreign_arc))
+    }
+
+    fn write(obj: Self::RustType, buf: &mut Vec<u8>) {
+        use uniffi::deps::bytes::BufMut;
+        uniffi::deps::static_assertions::const_assert!(std::mem::size_of::<*const std::ffi::c_void>() <= 8);
+        buf.put_u64(Self::lower(obj) as u64);
+    }
+
+    fn try_read(buf: &mut &[u8]) -> uniffi::deps::anyhow::Result<Self::RustType> {
+        use uniffi::deps::bytes::Buf;
+        uniffi::deps::static_assertions::const_assert!(std::mem::size_of::<*const std::ffi::c_void>() <= 8);
+        uniffi::check_remaining(buf, 8)?;
+        Self::try_lift(buf.get_u64() as Self::FfiType)
+    }
+}
+{%- endmatch %}
+
 {% let ffi_free = obj.ffi_object_free() -%}
 #[doc(hidden)]
 #[no_mangle]
 pub extern "C" fn {{ ffi_free.name() }}(ptr: *const std::os::raw::c_void, call_status: &mut uniffi::RustCallStatus) {
     uniffi::call_with_output(call_status, || {
         assert!(!ptr.is_null());
+        {%- match obj.imp() %}
+        {%- when ObjectImpl::Struct %}
         {#- turn it into an Arc and explicitly drop it. #}
-        drop(unsafe { std::sync::Arc::from_raw(ptr as *const {{ obj.name() }}) })
+        drop(unsafe { std::sync::Arc::from_raw(ptr as *const {{ obj.rust_name() }}) })
+        {%- when ObjectImpl::Trait %}
+        {#- turn it into a Box<Arc> and explicitly drop it. #}
+        drop(unsafe { Box::from_raw(ptr as *mut std::sync::Arc<{{ obj.rust_name() }}>) })
+        {%- endmatch %}
     })
 }
 
@@ -49,9 +121,10 @@ pub extern "C" fn {{ ffi_free.name() }}(ptr: *const std::os::raw::c_void, call_s
         // this attempt to call it will fail with a (somewhat) helpful compiler error.
         {% call rs::to_rs_constructor_call(obj, cons) %}
     }
+{% call rs::checksum_func(cons) %}
 {%- endfor %}
 
-{%- for meth in obj.methods() %}
+{%- for meth in obj.ffi_methods() %}
     #[doc(hidden)]
     #[no_mangle]
     pub extern "C" fn {{ meth.ffi_func().name() }}(
@@ -60,6 +133,41 @@ pub extern "C" fn {{ ffi_free.name() }}(ptr: *const std::os::raw::c_void, call_s
         uniffi::deps::log::debug!("{{ meth.ffi_func().name() }}");
         // If the method does not have the same signature as declared in the UDL, then
         // this attempt to call it will fail with a (somewhat) helpful compiler error.
+        {%- if meth.is_async() %}
+        {% call rs::to_rs_async_call(obj.rust_name(), meth) %}
+        {%- else %}
         {% call rs::to_rs_method_call(obj, meth) %}
+        {%- endif %}
+    }
+{% call rs::checksum_func(meth) %}
+{% endfor %}
+
+{%- for trait_impl in obj.trait_impls() %}
+    #[doc(hidden)]
+    #[no_mangle]
+    pub extern "C" fn {{ trait_impl.ffi_func().name() }}(
+        {%- call rs::arg_list_ffi_decl(trait_impl.ffi_func()) %}
+    ) {% call rs::return_signature(trait_impl) %} {
+        uniffi::deps::log::debug!("{{ trait_impl.ffi_func().name() }}");
+        // If the object doesn't implement the trait listed in the UDL, then this will fail
+        // with a compiler error about the missing impl.
+        uniffi::call_with_output(call_status, || {
+            let obj = {{ obj.type_()|ffi_converter }}::try_lift(ptr).unwrap();
+            {%- match trait_impl.trait_() %}
+            {%- when ObjectTrait::Debug %}
+            let _retval = format!("{:?}", obj);
+            {%- when ObjectTrait::Display %}
+            let _retval = format!("{}", obj);
+            {%- when ObjectTrait::Eq %}
+            let other = {{ obj.type_()|ffi_converter }}::try_lift(other).unwrap();
+            let _retval = *obj == *other;
+            {%- when ObjectTrait::Hash %}
+            use std::hash::{Hash, Hasher};
+            let mut hasher = std::collections::hash_map::DefaultHasher::new();
+            obj.hash(&mut hasher);
+            let _retval = hasher.finish();
+            {%- endmatch %}
+            {{ trait_impl.return_type()|ffi_converter }}::lower(_retval)
+        })
     }
 {% endfor %}
@@ -17,14 +17,14 @@ impl uniffi::RustBufferFfiConverter for {{ rec.type_()|ffi_converter_name }} {
         // If the provided struct doesn't match the fields declared in the UDL, then
         // the generated code here will fail to compile with somewhat helpful error.
         {%- for field in rec.fields() %}
-        {{ field.type_()|ffi_converter }}::write(obj.{{ field.name() }}, buf);
+        {{ field.type_()|field_ffi_converter(ci, rec.name()) }}::write(obj.{{ field.name() }}, buf);
         {%- endfor %}
     }
 
     fn try_read(buf: &mut &[u8]) -> uniffi::deps::anyhow::Result<{{ rec.name() }}> {
         Ok({{ rec.name() }} {
             {%- for field in rec.fields() %}
-                {{ field.name() }}: {{ field.type_()|ffi_converter }}::try_read(buf)?,
+                {{ field.name() }}: {{ field.type_()|field_ffi_converter(ci, rec.name()) }}::try_read(buf)?,
             {%- endfor %}
         })
     }
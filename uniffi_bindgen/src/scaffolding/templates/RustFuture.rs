@@ -0,0 +1,45 @@
+// Async functions and methods return a `RustFuture`, which the foreign language code drives to
+// completion using these helpers. See the `uniffi::ffi::rustfuture` module for details.
+
+/// Poll a `RustFuture`, returning 1 if it is ready to be completed and 0 otherwise.
+/// If the future is not ready then the `waker` will be called with `waker_data` when
+/// it should be polled again.
+///
+/// # Safety
+/// The `future` argument must be a pointer previously returned by an async function,
+/// which has not yet been freed.
+#[doc(hidden)]
+#[no_mangle]
+pub unsafe extern "C" fn {{ ci.ffi_rust_future_poll().name() }}(future: *const std::os::raw::c_void, waker: uniffi::ForeignWaker, waker_data: u64, call_status: &mut uniffi::RustCallStatus) -> i8 {
+    uniffi::call_with_output(call_status, || {
+        uniffi::RustFuture::borrow_raw(future).poll(waker, waker_data) as i8
+    })
+}
+
+/// Take the result of a `RustFuture` that is ready, serialized into a `RustBuffer`.
+/// If the async function returned an error, it is reported via the `call_status`.
+///
+/// # Safety
+/// The `future` argument must be a pointer previously returned by an async function,
+/// which has not yet been freed.
+#[doc(hidden)]
+#[no_mangle]
+pub unsafe extern "C" fn {{ ci.ffi_rust_future_complete().name() }}(future: *const std::os::raw::c_void, call_status: &mut uniffi::RustCallStatus) -> uniffi::RustBuffer {
+    uniffi::call_with_result(call_status, || {
+        uniffi::RustFuture::borrow_raw(future).complete()
+    })
+}
+
+/// Free a `RustFuture`, cancelling it if it has not yet completed.
+///
+/// # Safety
+/// The `future` argument must be a pointer previously returned by an async function,
+/// and must not be used after this call.
+#[doc(hidden)]
+#[no_mangle]
+pub unsafe extern "C" fn {{ ci.ffi_rust_future_free().name() }}(future: *const std::os::raw::c_void, call_status: &mut uniffi::RustCallStatus) {
+    uniffi::call_with_output(call_status, || {
+        assert!(!future.is_null());
+        uniffi::RustFuture::drop_raw(future)
+    })
+}
@@ -8,8 +8,9 @@
 
 {%- macro _arg_list_rs_call(func) %}
     {%- for arg in func.full_arguments() %}
-        {%- if arg.by_ref() %}&{% endif %}
-        {{- arg.type_()|ffi_converter }}::try_lift({{ arg.name() }}).unwrap()
+        {#- Objects passed by reference are borrowed from their `Arc`, which may hold a trait object. #}
+        {%- if arg.by_ref() %}&{% match arg.type_() %}{% when Type::Object with (_) %}*{% else %}{% endmatch %}{% endif %}
+        {{- arg.type_()|ffi_converter }}::try_lift({{ arg.name() }}).unwrap_or_else(|err| panic!("Failed to convert arg '{}': {}", "{{ arg.name() }}", err))
         {%- if !loop.last %}, {% endif %}
     {%- endfor %}
 {%- endmacro -%}
@@ -61,21 +62,34 @@
 {% endmatch %}
 {% endmacro %}
 
+{#
+// The path to an object's methods. For a trait, we name the `dyn` type explicitly, as that's
+// the type of the `self` that we pass.
+#}
+{%- macro _method_path(obj) -%}
+{%- match obj.imp() -%}
+{%- when ObjectImpl::Struct -%}
+{{ obj.name() }}
+{%- when ObjectImpl::Trait -%}
+<{{ obj.rust_name() }} as {{ obj.name() }}>
+{%- endmatch -%}
+{%- endmacro -%}
+
 {% macro to_rs_method_call(obj, meth) -%}
 {% match meth.throws_type() -%}
 {% when Some with (e) -%}
 uniffi::call_with_result(call_status, || {
-    let _retval =  {{ obj.name() }}::{% call to_rs_call(meth) %}.map_err(Into::into).map_err({{ e|ffi_converter }}::lower)?;
+    let _retval =  {% call _method_path(obj) %}::{% call to_rs_call(meth) %}.map_err(Into::into).map_err({{ e|ffi_converter }}::lower)?;
     Ok({% call ret(meth) %})
 })
 {% else %}
 uniffi::call_with_output(call_status, || {
     {% match meth.return_type() -%}
     {% when Some with (return_type) -%}
-    let retval = {{ obj.name() }}::{% call to_rs_call(meth) %};
+    let retval = {% call _method_path(obj) %}::{% call to_rs_call(meth) %};
     {{ return_type|ffi_converter }}::lower(retval)
     {% else -%}
-    {{ obj.name() }}::{% call to_rs_call(meth) %}
+    {% call _method_path(obj) %}::{% call to_rs_call(meth) %}
     {% endmatch -%}
 })
 {% endmatch -%}
@@ -100,3 +114,63 @@ uniffi::call_with_output(call_status, || {
 })
 {% endmatch %}
 {% endmacro %}
+
+{#
+// Async functions and methods are called with their arguments lifted up-front, so that the
+// resulting future owns everything it needs. The future is then wrapped in a `RustFuture`
+// whose output is lowered into a `RustBuffer`, and returned to the foreign language code
+// to be polled.
+#}
+{% macro to_rs_async_call(obj_name, func) %}
+uniffi::call_with_output(call_status, || {
+    {%- for arg in func.full_arguments() %}
+    let {{ arg.name() }} = {{ arg.type_()|ffi_converter }}::try_lift({{ arg.name() }}).unwrap_or_else(|err| panic!("Failed to convert arg '{}': {}", "{{ arg.name() }}", err));
+    {%- endfor %}
+    uniffi::RustFuture::new(async move {
+        {%- match func.throws_type() %}
+        {%- when Some with (e) %}
+        match {% if !obj_name.is_empty() %}{{ obj_name }}::{% endif %}{% call _async_rs_call(func) %}.await {
+            Ok({% if func.return_type().is_some() %}_retval{% else %}(){% endif %}) => Ok({% call _async_ret(func) %}),
+            Err(err) => Err({{ e|ffi_converter }}::lower(err)),
+        }
+        {%- else %}
+        {% if func.return_type().is_some() %}let _retval = {% endif %}{% if !obj_name.is_empty() %}{{ obj_name }}::{% endif %}{% call _async_rs_call(func) %}.await;
+        Ok({% call _async_ret(func) %})
+        {%- endmatch %}
+    }).into_raw()
+})
+{% endmacro %}
+
+{%- macro _async_rs_call(func) -%}
+{{ func.name() }}(
+    {%- for arg in func.full_arguments() %}
+        {%- if arg.by_ref() %}&{% endif %}{{ arg.name() }}
+        {%- if !loop.last %}, {% endif %}
+    {%- endfor -%}
+)
+{%- endmacro -%}
+
+{%- macro _async_ret(func) -%}
+{%- match func.return_type() -%}
+{%- when Some with (return_type) -%}
+{
+    let mut buf = Vec::new();
+    {{ return_type|ffi_converter }}::write(_retval, &mut buf);
+    uniffi::RustBuffer::from_vec(buf)
+}
+{%- else -%}
+uniffi::RustBuffer::new()
+{%- endmatch -%}
+{%- endmacro -%}
+
+{#
+// The function returning the checksum of a function, constructor or method, which the bindings
+// compare with their own checksum for it to check that they match the library.
+#}
+{%- macro checksum_func(item) %}
+#[doc(hidden)]
+#[no_mangle]
+pub extern "C" fn {{ item.checksum_func().name() }}(call_status: &mut uniffi::RustCallStatus) -> u16 {
+    uniffi::call_with_output(call_status, || {{ item.checksum() }})
+}
+{%- endmacro %}
@@ -8,8 +8,19 @@
 // seems to show that single line as context for the user.
 uniffi::assert_compatible_version!("{{ uniffi_version }}"); // Please check that you depend on version {{ uniffi_version }} of the `uniffi` crate.
 
+// The version of the FFI contract that this scaffolding implements, which the bindings check when they load the library.
+#[doc(hidden)]
+#[no_mangle]
+pub extern "C" fn {{ ci.ffi_uniffi_contract_version().name() }}(call_status: &mut uniffi::RustCallStatus) -> u32 {
+    uniffi::call_with_output(call_status, || {{ ci.uniffi_contract_version() }})
+}
+
 {% include "RustBuffer.rs" %}
 
+{%- if ci.has_async_functions() %}
+{% include "RustFuture.rs" %}
+{%- endif %}
+
 // Error definitions, corresponding to `error` in the UDL.
 {% for e in ci.iter_error_definitions() %}
 {% include "ErrorTemplate.rs" %}
@@ -20,6 +31,11 @@ uniffi::assert_compatible_version!("{{ uniffi_version }}"); // Please check that
 {% include "EnumTemplate.rs" %}
 {% endfor %}
 
+// Flags definitions, corresponding to `[Flags] enum` in UDL.
+{% for f in ci.iter_flags_definitions() %}
+{% include "FlagsTemplate.rs" %}
+{% endfor %}
+
 // Record definitions, implemented as method-less structs, corresponding to `dictionary` objects.
 {% for rec in ci.iter_record_definitions() %}
 {% include "RecordTemplate.rs" %}
@@ -30,6 +46,11 @@ uniffi::assert_compatible_version!("{{ uniffi_version }}"); // Please check that
 {% include "TopLevelFunctionTemplate.rs" %}
 {% endfor -%}
 
+// Constants, corresponding to UDL `const` declarations in the `namespace`.
+{%- for constant in ci.iter_constant_definitions() %}
+{% include "ConstantTemplate.rs" %}
+{% endfor %}
+
 // Object definitions, corresponding to UDL `interface` definitions.
 {% for obj in ci.iter_object_definitions() %}
 {% include "ObjectTemplate.rs" %}
@@ -43,4 +64,9 @@ uniffi::assert_compatible_version!("{{ uniffi_version }}"); // Please check that
 // External and Wrapped types
 {% include "ExternalTypesTemplate.rs" %}
 
+// The UDL that this scaffolding was generated from, so that bindings can be generated from the compiled library.
+#[doc(hidden)]
+#[no_mangle]
+pub static {{ udl_metadata.symbol_name }}: [u8; {{ udl_metadata.len }}] = *b"{{ udl_metadata.bytes }}";
+
 {%- import "macros.rs" as rs -%}
@@ -3,7 +3,7 @@
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
 use anyhow::Result;
-use std::env;
+use std::{env, path::Path};
 
 #[cfg(not(feature = "builtin-bindgen"))]
 use anyhow::{bail, Context};
@@ -27,6 +27,13 @@ use std::process::Command;
 /// itself and need to test out their changes to the bindings generator.
 pub fn generate_scaffolding(udl_file: &str) -> Result<()> {
     println!("cargo:rerun-if-changed={}", udl_file);
+    // The crate's `uniffi.toml` is embedded in the scaffolding, so we need to rebuild when it changes.
+    if let Some(crate_root) = Path::new(udl_file).parent().and_then(Path::parent) {
+        let config_file = crate_root.join("uniffi.toml");
+        if config_file.exists() {
+            println!("cargo:rerun-if-changed={}", config_file.display());
+        }
+    }
     // The UNIFFI_TESTS_DISABLE_EXTENSIONS variable disables some bindings, but it is evaluated
     // at *build* time, so we need to rebuild when it changes.
     println!("cargo:rerun-if-env-changed=UNIFFI_TESTS_DISABLE_EXTENSIONS");
@@ -0,0 +1,353 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! Implementation of the `#[uniffi::export]` attribute.
+//!
+//! This can be applied to a top-level function, or to an `impl` block for a type
+//! that has been exported with `#[uniffi::object]`. For each exported function we
+//! generate the same kind of `pub extern "C"` wrapper as the UDL scaffolding does,
+//! lifting each argument and lowering the return value via its `FfiConverter`.
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{
+    spanned::Spanned, visit_mut::VisitMut, FnArg, ImplItem, ItemFn, ItemImpl, Pat, ReturnType,
+    Signature,
+};
+use uniffi_meta::{
+    ConstructorMetadata, FnMetadata, FnParamMetadata, Metadata, MethodMetadata, Type,
+};
+
+use crate::util::{
+    convert_return_type, convert_type, crate_name, create_checksum_fn, create_metadata_static,
+    return_type_rs,
+};
+
+pub fn expand_export(item: syn::Item) -> syn::Result<TokenStream> {
+    match item {
+        syn::Item::Fn(item) => expand_fn(item),
+        syn::Item::Impl(item) => expand_impl(item),
+        _ => Err(syn::Error::new(
+            item.span(),
+            "#[uniffi::export] can only be used on functions and impl blocks",
+        )),
+    }
+}
+
+fn expand_fn(item: ItemFn) -> syn::Result<TokenStream> {
+    check_signature(&item.sig)?;
+    let args = Arguments::from_inputs(item.sig.inputs.iter())?;
+    let func = FnMetadata {
+        crate_name: crate_name(),
+        name: item.sig.ident.to_string(),
+        inputs: args.metadata()?,
+        return_type: convert_return_type(&item.sig.output)?,
+    };
+    let ffi_ident = format_ident!("{}", func.ffi_symbol_name());
+    let ident = &item.sig.ident;
+    let arg_list_ffi_decl = args.ffi_decl();
+    let lifted_args = args.lifted();
+    let return_ffi_type = return_ffi_type(&item.sig.output);
+    let call = lower_return_value(&item.sig.output, quote! { #ident(#(#lifted_args),*) });
+    let name = func.name.clone();
+    let checksum_fn = create_checksum_fn(
+        &func.checksum_symbol_name(),
+        uniffi_meta::item_checksum(&func),
+    );
+    let metadata_static = create_metadata_static(&Metadata::Func(func));
+    Ok(quote! {
+        #item
+
+        #[doc(hidden)]
+        #[no_mangle]
+        pub extern "C" fn #ffi_ident(
+            #(#arg_list_ffi_decl,)*
+            call_status: &mut uniffi::RustCallStatus
+        ) #return_ffi_type {
+            uniffi::deps::log::debug!(#name);
+            uniffi::call_with_output(call_status, || {
+                #call
+            })
+        }
+
+        #checksum_fn
+
+        #metadata_static
+    })
+}
+
+fn expand_impl(item: ItemImpl) -> syn::Result<TokenStream> {
+    if item.trait_.is_some() {
+        return Err(syn::Error::new(
+            item.span(),
+            "#[uniffi::export] cannot be used on trait impls",
+        ));
+    }
+    if !item.generics.params.is_empty() {
+        return Err(syn::Error::new(
+            item.generics.span(),
+            "#[uniffi::export] cannot be used on generic impls",
+        ));
+    }
+    let self_ident = match item.self_ty.as_ref() {
+        syn::Type::Path(p) if p.qself.is_none() => p.path.segments.last().unwrap().ident.clone(),
+        _ => {
+            return Err(syn::Error::new(
+                item.self_ty.span(),
+                "#[uniffi::export] can only be used on impl blocks for `#[uniffi::object]` types",
+            ))
+        }
+    };
+    let mut scaffolding = Vec::new();
+    for impl_item in &item.items {
+        let mut method = match impl_item {
+            ImplItem::Method(m) => m.clone(),
+            _ => continue,
+        };
+        // The FFI functions live outside the impl block, so any mention of `Self` in
+        // the signature needs to be replaced with the name of the type.
+        ReplaceSelf(&self_ident).visit_signature_mut(&mut method.sig);
+        check_signature(&method.sig)?;
+        scaffolding.push(match method.sig.receiver() {
+            Some(receiver) => expand_method(&self_ident, &method.sig, receiver)?,
+            None => expand_constructor(&self_ident, &method.sig)?,
+        });
+    }
+    Ok(quote! {
+        #item
+
+        #(#scaffolding)*
+    })
+}
+
+fn expand_constructor(self_ident: &syn::Ident, sig: &Signature) -> syn::Result<TokenStream> {
+    // Constructors may return either `Self` or `Arc<Self>`.
+    let returns_arc = match convert_return_type(&sig.output)? {
+        Some(Type::Unresolved { name }) if self_ident == &name => false,
+        Some(Type::ArcObject { object_name }) if self_ident == &object_name => true,
+        _ => {
+            return Err(syn::Error::new(
+                sig.span(),
+                "exported associated functions must either take `self`, or return `Self` or `Arc<Self>` to be used as a constructor",
+            ))
+        }
+    };
+    let args = Arguments::from_inputs(sig.inputs.iter())?;
+    let cons = ConstructorMetadata {
+        crate_name: crate_name(),
+        self_name: self_ident.to_string(),
+        name: sig.ident.to_string(),
+        inputs: args.metadata()?,
+    };
+    let ffi_ident = format_ident!("{}", cons.ffi_symbol_name());
+    let ident = &sig.ident;
+    let arg_list_ffi_decl = args.ffi_decl();
+    let lifted_args = args.lifted();
+    let arc = if returns_arc {
+        quote! { _new }
+    } else {
+        quote! { std::sync::Arc::new(_new) }
+    };
+    let name = format!("{}.{}", cons.self_name, cons.name);
+    let checksum_fn = create_checksum_fn(
+        &cons.checksum_symbol_name(),
+        uniffi_meta::item_checksum(&cons),
+    );
+    let metadata_static = create_metadata_static(&Metadata::Constructor(cons));
+    Ok(quote! {
+        #[doc(hidden)]
+        #[no_mangle]
+        pub extern "C" fn #ffi_ident(
+            #(#arg_list_ffi_decl,)*
+            call_status: &mut uniffi::RustCallStatus
+        ) -> *const std::os::raw::c_void /* *const #self_ident */ {
+            uniffi::deps::log::debug!(#name);
+            uniffi::call_with_output(call_status, || {
+                let _new = #self_ident::#ident(#(#lifted_args),*);
+                <std::sync::Arc<#self_ident> as uniffi::FfiConverter>::lower(#arc)
+            })
+        }
+
+        #checksum_fn
+
+        #metadata_static
+    })
+}
+
+fn expand_method(
+    self_ident: &syn::Ident,
+    sig: &Signature,
+    receiver: &FnArg,
+) -> syn::Result<TokenStream> {
+    let takes_self_by_arc = match receiver {
+        FnArg::Receiver(r) if r.reference.is_some() && r.mutability.is_none() => false,
+        FnArg::Typed(t) => match convert_type(&t.ty) {
+            Ok(Type::ArcObject { object_name }) if self_ident == &object_name => true,
+            _ => {
+                return Err(syn::Error::new(
+                    t.span(),
+                    "exported methods must take `&self` or `self: Arc<Self>`",
+                ))
+            }
+        },
+        _ => {
+            return Err(syn::Error::new(
+                receiver.span(),
+                "exported methods must take `&self` or `self: Arc<Self>`",
+            ))
+        }
+    };
+    let args = Arguments::from_inputs(sig.inputs.iter().skip(1))?;
+    let meth = MethodMetadata {
+        crate_name: crate_name(),
+        self_name: self_ident.to_string(),
+        name: sig.ident.to_string(),
+        inputs: args.metadata()?,
+        return_type: convert_return_type(&sig.output)?,
+        takes_self_by_arc,
+    };
+    let ffi_ident = format_ident!("{}", meth.ffi_symbol_name());
+    let ident = &sig.ident;
+    let arg_list_ffi_decl = args.ffi_decl();
+    let lifted_args = args.lifted();
+    let obj = if takes_self_by_arc {
+        quote! { _obj }
+    } else {
+        quote! { &*_obj }
+    };
+    let return_ffi_type = return_ffi_type(&sig.output);
+    let call = lower_return_value(
+        &sig.output,
+        quote! { #self_ident::#ident(#obj, #(#lifted_args),*) },
+    );
+    let name = format!("{}.{}", meth.self_name, meth.name);
+    let checksum_fn = create_checksum_fn(
+        &meth.checksum_symbol_name(),
+        uniffi_meta::item_checksum(&meth),
+    );
+    let metadata_static = create_metadata_static(&Metadata::Method(meth));
+    Ok(quote! {
+        #[doc(hidden)]
+        #[no_mangle]
+        pub extern "C" fn #ffi_ident(
+            ptr: *const std::os::raw::c_void,
+            #(#arg_list_ffi_decl,)*
+            call_status: &mut uniffi::RustCallStatus
+        ) #return_ffi_type {
+            uniffi::deps::log::debug!(#name);
+            uniffi::call_with_output(call_status, || {
+                let _obj = <std::sync::Arc<#self_ident> as uniffi::FfiConverter>::try_lift(ptr)
+                    .unwrap_or_else(|err| panic!("Failed to convert arg '{}': {}", "ptr", err));
+                #call
+            })
+        }
+
+        #checksum_fn
+
+        #metadata_static
+    })
+}
+
+/// Reject the kinds of function that we don't know how to export.
+fn check_signature(sig: &Signature) -> syn::Result<()> {
+    if sig.asyncness.is_some() {
+        return Err(syn::Error::new(
+            sig.asyncness.span(),
+            "async functions cannot be exported with #[uniffi::export] yet",
+        ));
+    }
+    if !sig.generics.params.is_empty() {
+        return Err(syn::Error::new(
+            sig.generics.span(),
+            "generic functions cannot be exported with uniffi",
+        ));
+    }
+    Ok(())
+}
+
+/// The FFI return type for a function, as a `-> T` fragment, or nothing for functions returning `()`.
+fn return_ffi_type(output: &ReturnType) -> TokenStream {
+    match return_type_rs(output) {
+        Some(ty) => quote! { -> <#ty as uniffi::FfiConverter>::FfiType },
+        None => quote! {},
+    }
+}
+
+/// Wrap a call to the exported function so that its return value is lowered, if it has one.
+fn lower_return_value(output: &ReturnType, call: TokenStream) -> TokenStream {
+    match return_type_rs(output) {
+        Some(ty) => quote! { <#ty as uniffi::FfiConverter>::lower(#call) },
+        None => call,
+    }
+}
+
+/// The (non-receiver) arguments of an exported function.
+struct Arguments<'a> {
+    args: Vec<(&'a syn::Ident, &'a syn::Type)>,
+}
+
+impl<'a> Arguments<'a> {
+    fn from_inputs(inputs: impl Iterator<Item = &'a FnArg>) -> syn::Result<Self> {
+        let args = inputs
+            .map(|input| match input {
+                FnArg::Typed(t) => match t.pat.as_ref() {
+                    Pat::Ident(p) => Ok((&p.ident, t.ty.as_ref())),
+                    _ => Err(syn::Error::new(
+                        t.pat.span(),
+                        "arguments of exported functions must be plain identifiers",
+                    )),
+                },
+                FnArg::Receiver(r) => Err(syn::Error::new(r.span(), "unexpected `self` argument")),
+            })
+            .collect::<syn::Result<_>>()?;
+        Ok(Self { args })
+    }
+
+    fn metadata(&self) -> syn::Result<Vec<FnParamMetadata>> {
+        self.args
+            .iter()
+            .map(|(ident, ty)| {
+                Ok(FnParamMetadata {
+                    name: ident.to_string(),
+                    ty: convert_type(ty)?,
+                })
+            })
+            .collect()
+    }
+
+    /// The arguments as declared by the FFI function.
+    fn ffi_decl(&self) -> Vec<TokenStream> {
+        self.args
+            .iter()
+            .map(|(ident, ty)| quote! { #ident: <#ty as uniffi::FfiConverter>::FfiType })
+            .collect()
+    }
+
+    /// The arguments as passed to the Rust function.
+    fn lifted(&self) -> Vec<TokenStream> {
+        self.args
+            .iter()
+            .map(|(ident, ty)| {
+                let name = ident.to_string();
+                quote! {
+                    <#ty as uniffi::FfiConverter>::try_lift(#ident)
+                        .unwrap_or_else(|err| panic!("Failed to convert arg '{}': {}", #name, err))
+                }
+            })
+            .collect()
+    }
+}
+
+/// Replaces `Self` in a type with the name of the type being implemented.
+struct ReplaceSelf<'a>(&'a syn::Ident);
+
+impl VisitMut for ReplaceSelf<'_> {
+    fn visit_type_path_mut(&mut self, ty: &mut syn::TypePath) {
+        if ty.qself.is_none() && ty.path.is_ident("Self") {
+            ty.path = self.0.clone().into();
+        } else {
+            syn::visit_mut::visit_type_path_mut(self, ty);
+        }
+    }
+}
@@ -4,14 +4,66 @@
 
 //! Macros for `uniffi`.
 //!
-//! Currently this is just for easily generating integration tests, but maybe
-//! we'll put some other code-annotation helper macros in here at some point.
+//! This contains helpers for including the generated scaffolding and for easily generating
+//! integration tests, as well as attributes for defining a component's interface directly
+//! on Rust items rather than in the UDL file.
 
 use quote::{format_ident, quote};
 use std::env;
 use std::path::{Path, PathBuf};
 use syn::{bracketed, punctuated::Punctuated, LitStr, Token};
 
+mod export;
+mod object;
+mod record;
+mod util;
+
+/// Export a function, or the constructors and methods in an `impl` block, via the FFI.
+///
+/// This generates the same scaffolding as declaring the function in the UDL file would,
+/// and records metadata about it in the compiled library so that `uniffi-bindgen` can
+/// include it in the generated bindings.
+///
+/// An `impl` block must be for a type exported with `#[uniffi::object]`. Its methods must
+/// take `&self` or `self: Arc<Self>`, and any associated functions must return `Self` or
+/// `Arc<Self>`, in which case they are exported as constructors.
+#[proc_macro_attribute]
+pub fn export(
+    _attr: proc_macro::TokenStream,
+    input: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    let item = syn::parse_macro_input!(input as syn::Item);
+    export::expand_export(item)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Export a struct as a record, equivalent to declaring it as a `dictionary` in the UDL file.
+#[proc_macro_attribute]
+pub fn record(
+    _attr: proc_macro::TokenStream,
+    input: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    let item = syn::parse_macro_input!(input as syn::ItemStruct);
+    record::expand_record(item)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Export a struct as an object, equivalent to declaring it as an `interface` in the UDL file.
+///
+/// Use `#[uniffi::export]` on an `impl` block for the struct to export its constructors and methods.
+#[proc_macro_attribute]
+pub fn object(
+    _attr: proc_macro::TokenStream,
+    input: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    let item = syn::parse_macro_input!(input as syn::ItemStruct);
+    object::expand_object(item)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
 /// A macro to build testcases for a component's generated bindings.
 ///
 /// This macro provides some plumbing to write automated tests for the generated
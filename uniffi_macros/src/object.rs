@@ -0,0 +1,48 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! Implementation of the `#[uniffi::object]` attribute.
+//!
+//! Objects are handed out to foreign-language code as an `Arc<T>`, so all we need to
+//! generate for the struct itself is the function that frees one. Its constructors and
+//! methods are exported separately, via `#[uniffi::export]` on an `impl` block.
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{spanned::Spanned, ItemStruct};
+use uniffi_meta::{Metadata, ObjectMetadata};
+
+use crate::util::{crate_name, create_metadata_static};
+
+pub fn expand_object(item: ItemStruct) -> syn::Result<TokenStream> {
+    if !item.generics.params.is_empty() {
+        return Err(syn::Error::new(
+            item.generics.span(),
+            "generic structs cannot be exported as uniffi objects",
+        ));
+    }
+    let ident = &item.ident;
+    let object = ObjectMetadata {
+        crate_name: crate_name(),
+        name: ident.to_string(),
+    };
+    let ffi_free = format_ident!("{}", object.free_ffi_symbol_name());
+    let metadata_static = create_metadata_static(&Metadata::Object(object));
+    Ok(quote! {
+        #item
+
+        uniffi::deps::static_assertions::assert_impl_all!(#ident: Sync, Send);
+
+        #[doc(hidden)]
+        #[no_mangle]
+        pub extern "C" fn #ffi_free(ptr: *const std::os::raw::c_void, call_status: &mut uniffi::RustCallStatus) {
+            uniffi::call_with_output(call_status, || {
+                assert!(!ptr.is_null());
+                drop(unsafe { std::sync::Arc::from_raw(ptr as *const #ident) })
+            })
+        }
+
+        #metadata_static
+    })
+}
@@ -0,0 +1,71 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! Implementation of the `#[uniffi::record]` attribute.
+//!
+//! A record is passed over the FFI by serializing each of its fields in turn, exactly
+//! like a `dictionary` declared in the UDL. Since the struct is defined in the calling
+//! crate, we can implement the converter on the struct itself rather than on a unit-struct.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{spanned::Spanned, Fields, ItemStruct};
+use uniffi_meta::{FieldMetadata, Metadata, RecordMetadata};
+
+use crate::util::{convert_type, crate_name, create_metadata_static};
+
+pub fn expand_record(item: ItemStruct) -> syn::Result<TokenStream> {
+    if !item.generics.params.is_empty() {
+        return Err(syn::Error::new(
+            item.generics.span(),
+            "generic structs cannot be exported as uniffi records",
+        ));
+    }
+    let fields = match &item.fields {
+        Fields::Named(fields) => &fields.named,
+        _ => {
+            return Err(syn::Error::new(
+                item.fields.span(),
+                "uniffi records must have named fields",
+            ))
+        }
+    };
+    let ident = &item.ident;
+    let field_idents: Vec<_> = fields.iter().map(|f| f.ident.as_ref().unwrap()).collect();
+    let field_types: Vec<_> = fields.iter().map(|f| &f.ty).collect();
+    let metadata = Metadata::Record(RecordMetadata {
+        crate_name: crate_name(),
+        name: ident.to_string(),
+        fields: fields
+            .iter()
+            .map(|f| {
+                Ok(FieldMetadata {
+                    name: f.ident.as_ref().unwrap().to_string(),
+                    ty: convert_type(&f.ty)?,
+                })
+            })
+            .collect::<syn::Result<_>>()?,
+    });
+    let metadata_static = create_metadata_static(&metadata);
+    Ok(quote! {
+        #item
+
+        #[doc(hidden)]
+        impl uniffi::RustBufferFfiConverter for #ident {
+            type RustType = Self;
+
+            fn write(obj: Self, buf: &mut std::vec::Vec<u8>) {
+                #(<#field_types as uniffi::FfiConverter>::write(obj.#field_idents, buf);)*
+            }
+
+            fn try_read(buf: &mut &[u8]) -> uniffi::deps::anyhow::Result<Self> {
+                Ok(Self {
+                    #(#field_idents: <#field_types as uniffi::FfiConverter>::try_read(buf)?,)*
+                })
+            }
+        }
+
+        #metadata_static
+    })
+}
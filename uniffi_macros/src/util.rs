@@ -0,0 +1,158 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! Helpers shared by the `#[uniffi::export]`, `#[uniffi::record]` and `#[uniffi::object]` macros.
+
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use std::env;
+use syn::{spanned::Spanned, GenericArgument, PathArguments, ReturnType};
+use uniffi_meta::{Metadata, Type};
+
+/// The name of the crate whose items are being expanded.
+///
+/// Cargo tells the compiler this via the environment, and it's visible to procedural macros too.
+pub fn crate_name() -> String {
+    env::var("CARGO_CRATE_NAME")
+        .or_else(|_| env::var("CARGO_PKG_NAME").map(|nm| nm.replace('-', "_")))
+        .expect("Missing $CARGO_CRATE_NAME, cannot export items with uniffi")
+}
+
+/// Emit a `#[no_mangle]` static holding the serialized metadata for an item,
+/// so that `uniffi-bindgen` can find it in the compiled library.
+pub fn create_metadata_static(metadata: &Metadata) -> TokenStream {
+    let ident = format_ident!("{}", metadata.symbol_name());
+    let data = serde_json::to_vec(metadata).expect("Failed to serialize uniffi metadata");
+    let len = data.len();
+    let data = syn::LitByteStr::new(&data, Span::call_site());
+    quote! {
+        #[doc(hidden)]
+        #[no_mangle]
+        pub static #ident: [u8; #len] = *#data;
+    }
+}
+
+/// Create the function that returns the checksum of an exported item, which the
+/// bindings call to check that they match the library.
+pub fn create_checksum_fn(symbol_name: &str, checksum: u16) -> TokenStream {
+    let ident = format_ident!("{}", symbol_name);
+    quote! {
+        #[doc(hidden)]
+        #[no_mangle]
+        pub extern "C" fn #ident(call_status: &mut uniffi::RustCallStatus) -> u16 {
+            uniffi::call_with_output(call_status, || #checksum)
+        }
+    }
+}
+
+/// Convert the Rust syntax for a type into its metadata representation.
+///
+/// We can only see the type as written, so this recognizes the builtin types by name and
+/// records anything else as `Unresolved`, to be looked up when building the interface.
+pub fn convert_type(ty: &syn::Type) -> syn::Result<Type> {
+    let unsupported = || syn::Error::new(ty.span(), "this type is not supported by uniffi");
+    let segment = match ty {
+        syn::Type::Path(p) if p.qself.is_none() => match p.path.segments.last() {
+            Some(segment) => segment,
+            None => return Err(unsupported()),
+        },
+        syn::Type::Group(g) => return convert_type(&g.elem),
+        syn::Type::Paren(p) => return convert_type(&p.elem),
+        syn::Type::Tuple(t) => {
+            return Ok(Type::Tuple {
+                element_types: t
+                    .elems
+                    .iter()
+                    .map(convert_type)
+                    .collect::<syn::Result<_>>()?,
+            })
+        }
+        _ => return Err(unsupported()),
+    };
+    let type_args = match &segment.arguments {
+        PathArguments::None => vec![],
+        PathArguments::AngleBracketed(args) => args
+            .args
+            .iter()
+            .map(|arg| match arg {
+                GenericArgument::Type(t) => Ok(t),
+                _ => Err(unsupported()),
+            })
+            .collect::<syn::Result<Vec<_>>>()?,
+        PathArguments::Parenthesized(_) => return Err(unsupported()),
+    };
+    let name = segment.ident.to_string();
+    Ok(match (name.as_str(), type_args.as_slice()) {
+        ("u8", []) => Type::U8,
+        ("u16", []) => Type::U16,
+        ("u32", []) => Type::U32,
+        ("u64", []) => Type::U64,
+        ("u128", []) => Type::U128,
+        ("i8", []) => Type::I8,
+        ("i16", []) => Type::I16,
+        ("i32", []) => Type::I32,
+        ("i64", []) => Type::I64,
+        ("i128", []) => Type::I128,
+        ("f32", []) => Type::F32,
+        ("f64", []) => Type::F64,
+        ("bool", []) => Type::Bool,
+        ("String", []) => Type::String,
+        ("SystemTime", []) => Type::SystemTime,
+        ("Duration", []) => Type::Duration,
+        ("Option", [inner]) => Type::Option {
+            inner_type: Box::new(convert_type(inner)?),
+        },
+        ("Vec", [inner]) => Type::Vec {
+            inner_type: Box::new(convert_type(inner)?),
+        },
+        ("HashMap", [key, value]) => Type::HashMap {
+            key_type: Box::new(convert_type(key)?),
+            value_type: Box::new(convert_type(value)?),
+        },
+        ("HashSet", [inner]) => Type::HashSet {
+            inner_type: Box::new(convert_type(inner)?),
+        },
+        ("BTreeSet", [inner]) => Type::BTreeSet {
+            inner_type: Box::new(convert_type(inner)?),
+        },
+        ("BTreeMap", [key, value]) => Type::BTreeMap {
+            key_type: Box::new(convert_type(key)?),
+            value_type: Box::new(convert_type(value)?),
+        },
+        ("Arc", [inner]) => match convert_type(inner)? {
+            Type::Unresolved { name } => Type::ArcObject { object_name: name },
+            _ => {
+                return Err(syn::Error::new(
+                    inner.span(),
+                    "only `#[uniffi::object]` types can be passed in an `Arc`",
+                ))
+            }
+        },
+        (_, []) => Type::Unresolved { name },
+        _ => return Err(unsupported()),
+    })
+}
+
+/// Convert a function's return type into its metadata representation,
+/// mapping both a missing return type and `()` to `None`.
+pub fn convert_return_type(output: &ReturnType) -> syn::Result<Option<Type>> {
+    match output {
+        ReturnType::Default => Ok(None),
+        ReturnType::Type(_, ty) => match ty.as_ref() {
+            syn::Type::Tuple(t) if t.elems.is_empty() => Ok(None),
+            _ => convert_type(ty).map(Some),
+        },
+    }
+}
+
+/// Get the type of a function's return value, if it has one other than `()`.
+pub fn return_type_rs(output: &ReturnType) -> Option<&syn::Type> {
+    match output {
+        ReturnType::Default => None,
+        ReturnType::Type(_, ty) => match ty.as_ref() {
+            syn::Type::Tuple(t) if t.elems.is_empty() => None,
+            _ => Some(ty.as_ref()),
+        },
+    }
+}
@@ -0,0 +1,360 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! # Interface metadata for `uniffi`.
+//!
+//! This crate defines the metadata that the procedural macros in `uniffi_macros` record
+//! about each item they export, such as `#[uniffi::export]` functions and `#[uniffi::record]`
+//! structs. The macros serialize it into a `#[no_mangle]` static in the compiled library, from
+//! where `uniffi-bindgen` reads it back in order to add those items to a `ComponentInterface`.
+//!
+//! The names of the FFI functions generated for each item are also defined here, so that the
+//! scaffolding emitted by the macros and the bindings generated from the metadata are guaranteed
+//! to agree on them.
+//!
+//! The scaffolding generated from a UDL file records the UDL itself in the same way, so that
+//! the bindings for a component can be generated from nothing but its compiled library.
+
+use serde::{Deserialize, Serialize};
+use std::hash::{Hash, Hasher};
+
+/// Prefix of the names of the symbols that hold serialized metadata.
+///
+/// Each metadata item is stored in a static whose name starts with this prefix, and whose
+/// contents are the item serialized as JSON.
+pub const METADATA_SYMBOL_PREFIX: &str = "UNIFFI_META_";
+
+/// Compute a checksum of a value's `Hash` implementation that is stable across platforms and Rust versions.
+///
+/// The hashing algorithm is 64-bit FNV-1a. Rust's own hashers make no promises about their output
+/// being stable, and `Hash` implementations write `usize` and `isize` values (such as the length of a
+/// `Vec` or the discriminant of an enum) in the native width of the platform; we always hash those as
+/// 8 little-endian bytes, so that the checksum doesn't depend on where it was computed.
+pub fn checksum<T: Hash + ?Sized>(value: &T) -> u64 {
+    let mut hasher = ChecksumHasher::default();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// A shorter checksum, used for the checksums of individual API items that are exported from the library.
+pub fn item_checksum<T: Hash + ?Sized>(value: &T) -> u16 {
+    let checksum = checksum(value);
+    // Fold the 64-bit checksum down to 16 bits, so every bit of the full checksum contributes.
+    (checksum ^ (checksum >> 16) ^ (checksum >> 32) ^ (checksum >> 48)) as u16
+}
+
+/// The `Hasher` used by [`checksum`], implementing 64-bit FNV-1a.
+struct ChecksumHasher(u64);
+
+impl Default for ChecksumHasher {
+    fn default() -> Self {
+        // The FNV-1a 64-bit offset basis.
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl Hasher for ChecksumHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= u64::from(*byte);
+            // The FNV-1a 64-bit prime.
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+
+    fn write_usize(&mut self, i: usize) {
+        self.write(&(i as u64).to_le_bytes())
+    }
+
+    fn write_isize(&mut self, i: isize) {
+        self.write(&(i as i64).to_le_bytes())
+    }
+
+    fn write_u16(&mut self, i: u16) {
+        self.write(&i.to_le_bytes())
+    }
+
+    fn write_u32(&mut self, i: u32) {
+        self.write(&i.to_le_bytes())
+    }
+
+    fn write_u64(&mut self, i: u64) {
+        self.write(&i.to_le_bytes())
+    }
+
+    fn write_u128(&mut self, i: u128) {
+        self.write(&i.to_le_bytes())
+    }
+
+    fn write_i16(&mut self, i: i16) {
+        self.write(&i.to_le_bytes())
+    }
+
+    fn write_i32(&mut self, i: i32) {
+        self.write(&i.to_le_bytes())
+    }
+
+    fn write_i64(&mut self, i: i64) {
+        self.write(&i.to_le_bytes())
+    }
+
+    fn write_i128(&mut self, i: i128) {
+        self.write(&i.to_le_bytes())
+    }
+}
+
+/// A single item exported by the procedural macros, or the UDL file of a component.
+#[derive(Clone, Debug, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Metadata {
+    Udl(UdlMetadata),
+    Func(FnMetadata),
+    Constructor(ConstructorMetadata),
+    Method(MethodMetadata),
+    Record(RecordMetadata),
+    Object(ObjectMetadata),
+}
+
+impl Metadata {
+    /// The name of the crate in which this item is defined.
+    pub fn crate_name(&self) -> &str {
+        match self {
+            Metadata::Udl(m) => &m.crate_name,
+            Metadata::Func(m) => &m.crate_name,
+            Metadata::Constructor(m) => &m.crate_name,
+            Metadata::Method(m) => &m.crate_name,
+            Metadata::Record(m) => &m.crate_name,
+            Metadata::Object(m) => &m.crate_name,
+        }
+    }
+
+    /// The name of the static in which this item is stored in the compiled library.
+    pub fn symbol_name(&self) -> String {
+        let item = match self {
+            Metadata::Udl(m) => format!("UDL_{}", m.namespace),
+            Metadata::Func(m) => format!("FUNC_{}", m.name),
+            Metadata::Constructor(m) => format!("CONSTRUCTOR_{}_{}", m.self_name, m.name),
+            Metadata::Method(m) => format!("METHOD_{}_{}", m.self_name, m.name),
+            Metadata::Record(m) => format!("RECORD_{}", m.name),
+            Metadata::Object(m) => format!("OBJECT_{}", m.name),
+        };
+        format!("{}{}_{}", METADATA_SYMBOL_PREFIX, self.crate_name(), item).to_ascii_uppercase()
+    }
+}
+
+/// The UDL file of a component, embedded in the library by its scaffolding.
+#[derive(Clone, Debug, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UdlMetadata {
+    pub crate_name: String,
+    pub namespace: String,
+    /// The contents of the UDL file.
+    pub udl: String,
+    /// The contents of the crate's `uniffi.toml`, if it has one.
+    pub config: Option<String>,
+}
+
+/// A top-level function, exported with `#[uniffi::export]`.
+#[derive(Clone, Debug, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FnMetadata {
+    pub crate_name: String,
+    pub name: String,
+    pub inputs: Vec<FnParamMetadata>,
+    pub return_type: Option<Type>,
+}
+
+impl FnMetadata {
+    pub fn ffi_symbol_name(&self) -> String {
+        format!("uniffi_{}_fn_{}", self.crate_name, self.name)
+    }
+
+    pub fn checksum_symbol_name(&self) -> String {
+        format!("uniffi_{}_checksum_func_{}", self.crate_name, self.name)
+    }
+}
+
+/// An associated function returning `Self`, from an `#[uniffi::export]` impl block.
+#[derive(Clone, Debug, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConstructorMetadata {
+    pub crate_name: String,
+    pub self_name: String,
+    pub name: String,
+    pub inputs: Vec<FnParamMetadata>,
+}
+
+impl ConstructorMetadata {
+    pub fn ffi_symbol_name(&self) -> String {
+        format!(
+            "uniffi_{}_fn_constructor_{}_{}",
+            self.crate_name, self.self_name, self.name
+        )
+    }
+
+    pub fn checksum_symbol_name(&self) -> String {
+        format!(
+            "uniffi_{}_checksum_constructor_{}_{}",
+            self.crate_name, self.self_name, self.name
+        )
+    }
+}
+
+/// A method taking `&self` or `self: Arc<Self>`, from an `#[uniffi::export]` impl block.
+#[derive(Clone, Debug, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MethodMetadata {
+    pub crate_name: String,
+    pub self_name: String,
+    pub name: String,
+    pub inputs: Vec<FnParamMetadata>,
+    pub return_type: Option<Type>,
+    pub takes_self_by_arc: bool,
+}
+
+impl MethodMetadata {
+    pub fn ffi_symbol_name(&self) -> String {
+        format!(
+            "uniffi_{}_fn_method_{}_{}",
+            self.crate_name, self.self_name, self.name
+        )
+    }
+
+    pub fn checksum_symbol_name(&self) -> String {
+        format!(
+            "uniffi_{}_checksum_method_{}_{}",
+            self.crate_name, self.self_name, self.name
+        )
+    }
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FnParamMetadata {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub ty: Type,
+}
+
+/// A struct exported with `#[uniffi::record]`.
+#[derive(Clone, Debug, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecordMetadata {
+    pub crate_name: String,
+    pub name: String,
+    pub fields: Vec<FieldMetadata>,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FieldMetadata {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub ty: Type,
+}
+
+/// A struct exported with `#[uniffi::object]`.
+#[derive(Clone, Debug, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ObjectMetadata {
+    pub crate_name: String,
+    pub name: String,
+}
+
+impl ObjectMetadata {
+    pub fn free_ffi_symbol_name(&self) -> String {
+        format!("uniffi_{}_fn_free_{}", self.crate_name, self.name)
+    }
+}
+
+/// The type of a function argument, return value or record field.
+///
+/// The procedural macros only see the Rust syntax of a type, so any type they don't
+/// recognise is recorded as `Unresolved`, and is looked up by name when the metadata
+/// is added to a `ComponentInterface`.
+#[derive(Clone, Debug, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Type {
+    U8,
+    U16,
+    U32,
+    U64,
+    U128,
+    I8,
+    I16,
+    I32,
+    I64,
+    I128,
+    F32,
+    F64,
+    Bool,
+    String,
+    SystemTime,
+    Duration,
+    Option {
+        inner_type: Box<Type>,
+    },
+    Vec {
+        inner_type: Box<Type>,
+    },
+    HashMap {
+        key_type: Box<Type>,
+        value_type: Box<Type>,
+    },
+    HashSet {
+        inner_type: Box<Type>,
+    },
+    BTreeSet {
+        inner_type: Box<Type>,
+    },
+    BTreeMap {
+        key_type: Box<Type>,
+        value_type: Box<Type>,
+    },
+    Tuple {
+        element_types: Vec<Type>,
+    },
+    ArcObject {
+        object_name: String,
+    },
+    Unresolved {
+        name: String,
+    },
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_checksum_is_fnv1a() {
+        // Reference values for 64-bit FNV-1a.
+        let mut hasher = ChecksumHasher::default();
+        hasher.write(b"");
+        assert_eq!(hasher.finish(), 0xcbf2_9ce4_8422_2325);
+        hasher.write(b"a");
+        assert_eq!(hasher.finish(), 0xaf63_dc4c_8601_ec8c);
+        let mut hasher = ChecksumHasher::default();
+        hasher.write(b"foobar");
+        assert_eq!(hasher.finish(), 0x8594_4171_f739_67e8);
+    }
+
+    #[test]
+    fn test_checksum_is_stable() {
+        // These values must never change, or bindings will stop matching libraries
+        // built with an earlier version of the same API.
+        let meta = FnMetadata {
+            crate_name: "test".into(),
+            name: "add".into(),
+            inputs: vec![FnParamMetadata {
+                name: "a".into(),
+                ty: Type::U32,
+            }],
+            return_type: Some(Type::U32),
+        };
+        assert_eq!(checksum(&meta), 0xd89e_b6b9_837b_0566);
+        assert_eq!(item_checksum(&meta), 59450);
+        assert_ne!(
+            item_checksum(&meta),
+            item_checksum(&FnMetadata {
+                return_type: None,
+                ..meta
+            })
+        );
+    }
+}